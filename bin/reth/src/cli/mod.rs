@@ -6,8 +6,8 @@ use crate::{
         LogArgs,
     },
     commands::{
-        config_cmd, db, debug_cmd, dump_genesis, import, init_cmd, node, node::NoArgs, p2p,
-        recover, stage, test_vectors,
+        config_cmd, datadir, db, debug_cmd, dump_genesis, import, init_cmd, node, node::NoArgs,
+        p2p, prune, recover, stage, test_vectors,
     },
     core::cli::runner::CliRunner,
     version::{LONG_VERSION, SHORT_VERSION},
@@ -141,14 +141,16 @@ impl<Ext: clap::Args + fmt::Debug> Cli<Ext> {
 
         let runner = CliRunner::default();
         match self.command {
-            Commands::Node(command) => {
-                runner.run_command_until_exit(|ctx| command.execute(ctx, launcher))
-            }
+            Commands::Node(command) => runner
+                .with_max_blocking_threads(command.threads.max_blocking_threads)
+                .run_command_until_exit(|ctx| command.execute(ctx, launcher)),
             Commands::Init(command) => runner.run_blocking_until_ctrl_c(command.execute()),
             Commands::Import(command) => runner.run_blocking_until_ctrl_c(command.execute()),
             Commands::DumpGenesis(command) => runner.run_blocking_until_ctrl_c(command.execute()),
             Commands::Db(command) => runner.run_blocking_until_ctrl_c(command.execute()),
             Commands::Stage(command) => runner.run_blocking_until_ctrl_c(command.execute()),
+            Commands::Prune(command) => runner.run_blocking_until_ctrl_c(command.execute()),
+            Commands::Datadir(command) => runner.run_blocking_until_ctrl_c(command.execute()),
             Commands::P2P(command) => runner.run_until_ctrl_c(command.execute()),
             Commands::TestVectors(command) => runner.run_until_ctrl_c(command.execute()),
             Commands::Config(command) => runner.run_until_ctrl_c(command.execute()),
@@ -187,6 +189,12 @@ pub enum Commands<Ext: clap::Args + fmt::Debug = NoArgs> {
     /// Manipulate individual stages.
     #[command(name = "stage")]
     Stage(stage::Command),
+    /// Prune data from the database on demand.
+    #[command(name = "prune")]
+    Prune(prune::PruneCommand),
+    /// Manage the data directory.
+    #[command(name = "datadir")]
+    Datadir(datadir::Command),
     /// P2P Debugging utilities
     #[command(name = "p2p")]
     P2P(p2p::Command),
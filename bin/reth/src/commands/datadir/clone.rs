@@ -0,0 +1,240 @@
+//! Command that clones a data directory while the node keeps running.
+
+use crate::{
+    args::{
+        utils::{chain_help, genesis_value_parser, SUPPORTED_CHAINS},
+        DatabaseArgs,
+    },
+    dirs::{DataDirPath, MaybePlatformPath},
+};
+use clap::Parser;
+use reth_db::open_db_read_only;
+use reth_primitives::{fs, static_file::SegmentRangeInclusive, ChainSpec, StaticFileSegment};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
+};
+use tracing::*;
+
+/// The name of the MDBX data file.
+const MDBX_DATA_FILE: &str = "mdbx.dat";
+
+/// `reth datadir clone` command
+///
+/// Copies the database with a consistent MDBX snapshot and copies the static files into the
+/// destination, so a new replica can be provisioned without stopping the source node.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The path to the data dir for all reth files and subdirectories.
+    ///
+    /// Defaults to the OS-specific data directory:
+    ///
+    /// - Linux: `$XDG_DATA_HOME/reth/` or `$HOME/.local/share/reth/`
+    /// - Windows: `{FOLDERID_RoamingAppData}/reth/`
+    /// - macOS: `$HOME/Library/Application Support/reth/`
+    #[arg(long, value_name = "DATA_DIR", verbatim_doc_comment, default_value_t)]
+    datadir: MaybePlatformPath<DataDirPath>,
+
+    /// The chain this node is running.
+    ///
+    /// Possible values are either a built-in chain or the path to a chain specification file.
+    #[arg(
+        long,
+        value_name = "CHAIN_OR_PATH",
+        long_help = chain_help(),
+        default_value = SUPPORTED_CHAINS[0],
+        value_parser = genesis_value_parser
+    )]
+    chain: Arc<ChainSpec>,
+
+    #[command(flatten)]
+    db: DatabaseArgs,
+
+    /// The data directory to create. It must not exist or be empty.
+    #[arg(long, value_name = "PATH")]
+    dest: PathBuf,
+
+    /// Hardlink static files instead of copying them. The destination must be on the same
+    /// filesystem as the source.
+    ///
+    /// The files of the two highest block ranges of each segment are always copied, since an
+    /// unwind of the source truncates them in place, which would also truncate a hardlinked
+    /// clone. Unwinding the source any further corrupts the clone.
+    #[arg(long)]
+    hardlink: bool,
+
+    /// Omit free pages from the database copy. This makes the copy smaller, but slower to create.
+    #[arg(long)]
+    compact: bool,
+}
+
+impl Command {
+    /// Execute `datadir clone` command
+    pub async fn execute(self) -> eyre::Result<()> {
+        let source = self.datadir.unwrap_or_chain_default(self.chain.chain);
+        let dest = MaybePlatformPath::<DataDirPath>::from(self.dest.clone())
+            .unwrap_or_chain_default(self.chain.chain);
+
+        if dest.data_dir_path().exists() && fs::read_dir(dest.data_dir_path())?.next().is_some() {
+            eyre::bail!("Destination {} is not empty", dest.data_dir_path().display())
+        }
+
+        // The database is copied first. Static files are copied afterwards, so they are at least
+        // as recent as the database snapshot. Static file data that is ahead of the database is
+        // unwound on startup, the same way as after an unexpected shutdown.
+        let start = Instant::now();
+        let db = open_db_read_only(&source.db_path(), self.db.database_args())?;
+        fs::create_dir_all(dest.db_path())?;
+        info!(target: "reth::cli", compact = self.compact, "Copying database");
+        db.copy(dest.db_path().join(MDBX_DATA_FILE), self.compact)?;
+        drop(db);
+        info!(target: "reth::cli", elapsed = ?start.elapsed(), "Copied database");
+
+        let start = Instant::now();
+        let (linked, copied) = clone_static_files(
+            &source.static_files_path(),
+            &dest.static_files_path(),
+            self.hardlink,
+        )?;
+        info!(target: "reth::cli", linked, copied, elapsed = ?start.elapsed(), "Cloned static files");
+
+        // The node key is deliberately not copied, so the replica gets its own network identity.
+        let config_path = source.config_path();
+        if config_path.exists() {
+            std::fs::copy(&config_path, dest.config_path())?;
+        }
+
+        info!(target: "reth::cli", dest = %dest.data_dir_path().display(), "Data directory cloned");
+        Ok(())
+    }
+}
+
+/// The number of the highest block ranges of each segment that are always copied, even if
+/// hardlinking is enabled.
+///
+/// The running node appends to the files of the highest range, and an unwind truncates the files
+/// of the highest remaining range in place after deleting the ones above it.
+const MUTABLE_RANGES_PER_SEGMENT: usize = 2;
+
+/// Clones all static files from `source` into `dest`, returning the number of hardlinked and
+/// copied files.
+///
+/// All files are copied, unless `hardlink` is set. In that case, only the files of the
+/// [MUTABLE_RANGES_PER_SEGMENT] highest block ranges of each segment are copied, and all other
+/// files are hardlinked.
+fn clone_static_files(source: &Path, dest: &Path, hardlink: bool) -> eyre::Result<(usize, usize)> {
+    fs::create_dir_all(dest)?;
+
+    let mut files = Vec::new();
+    let mut segment_ranges = HashMap::<StaticFileSegment, Vec<SegmentRangeInclusive>>::new();
+    for entry in fs::read_dir(source)? {
+        let path = entry?.path();
+        // Data files have no extension, while their offsets and configuration files share the
+        // same stem.
+        let Some((segment, range)) = path
+            .file_stem()
+            .and_then(|stem| StaticFileSegment::parse_filename(&stem.to_string_lossy()))
+        else {
+            continue
+        };
+        let ranges = segment_ranges.entry(segment).or_default();
+        if !ranges.contains(&range) {
+            ranges.push(range);
+        }
+        files.push((path, segment, range));
+    }
+
+    // Keep only the highest ranges of each segment, which are mutable.
+    for ranges in segment_ranges.values_mut() {
+        ranges.sort_unstable_by_key(|range| std::cmp::Reverse(range.end()));
+        ranges.truncate(MUTABLE_RANGES_PER_SEGMENT);
+    }
+
+    // Copy the configuration and offsets files of mutable static files before their data files.
+    // Data is only appended, so the copied data file is never behind its offsets and is healed to
+    // the offsets when it's opened.
+    files.sort_by_key(|(path, _, _)| path.extension().is_none());
+
+    let (mut linked, mut copied) = (0, 0);
+    for (path, segment, range) in files {
+        let target = dest.join(path.file_name().expect("static file has a file name"));
+        let mutable = segment_ranges.get(&segment).is_some_and(|ranges| ranges.contains(&range));
+        if !hardlink || mutable {
+            std::fs::copy(&path, &target)?;
+            copied += 1;
+        } else {
+            std::fs::hard_link(&path, &target)?;
+            linked += 1;
+        }
+    }
+
+    Ok((linked, copied))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER_FILES: [&str; 6] = [
+        "static_file_headers_0_499999",
+        "static_file_headers_0_499999.off",
+        "static_file_headers_500000_999999",
+        "static_file_headers_500000_999999.off",
+        "static_file_headers_1000000_1499999",
+        "static_file_headers_1000000_1499999.off",
+    ];
+
+    fn create_source() -> tempfile::TempDir {
+        let source = tempfile::tempdir().unwrap();
+        for name in HEADER_FILES.iter().chain(&["static_file_receipts_0_499999", "unrelated"]) {
+            std::fs::write(source.path().join(name), name).unwrap();
+        }
+        source
+    }
+
+    #[test]
+    fn clones_static_files() {
+        let source = create_source();
+        let dest = tempfile::tempdir().unwrap();
+
+        let (linked, copied) =
+            clone_static_files(source.path(), &dest.path().join("static_files"), false).unwrap();
+        assert_eq!((linked, copied), (0, 7));
+        assert!(!dest.path().join("static_files/unrelated").exists());
+        assert_eq!(
+            std::fs::read_to_string(dest.path().join("static_files/static_file_headers_0_499999"))
+                .unwrap(),
+            "static_file_headers_0_499999"
+        );
+    }
+
+    #[test]
+    fn hardlinked_clone_survives_unwind_across_file_boundary() {
+        let source = create_source();
+        let dest = tempfile::tempdir().unwrap();
+        let dest = dest.path().join("static_files");
+
+        let (linked, copied) = clone_static_files(source.path(), &dest, true).unwrap();
+        assert_eq!((linked, copied), (2, 5));
+
+        // Unwind the source into the previous file: the highest file is deleted and the one below
+        // it is truncated in place.
+        for name in &HEADER_FILES[4..] {
+            std::fs::remove_file(source.path().join(name)).unwrap();
+        }
+        for name in &HEADER_FILES[2..4] {
+            std::fs::OpenOptions::new()
+                .write(true)
+                .open(source.path().join(name))
+                .unwrap()
+                .set_len(1)
+                .unwrap();
+        }
+
+        for name in HEADER_FILES {
+            assert_eq!(std::fs::read_to_string(dest.join(name)).unwrap(), name);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! `reth datadir` command.
+
+use clap::{Parser, Subcommand};
+
+mod clone;
+
+/// `reth datadir` command
+#[derive(Debug, Parser)]
+pub struct Command {
+    #[command(subcommand)]
+    command: Subcommands,
+}
+
+/// `reth datadir` subcommands
+#[derive(Subcommand, Debug)]
+pub enum Subcommands {
+    /// Clone the data directory into a new directory, e.g. to provision a replica.
+    Clone(clone::Command),
+}
+
+impl Command {
+    /// Execute `datadir` command
+    pub async fn execute(self) -> eyre::Result<()> {
+        match self.command {
+            Subcommands::Clone(command) => command.execute().await,
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::utils::DbTool;
+use clap::Parser;
+use reth_db::database::Database;
+use reth_interfaces::provider::ProviderError;
+use reth_primitives::{logs_bloom, BlockHashOrNumber, BlockNumber};
+use reth_provider::{
+    providers::DEFAULT_MAX_READ_SNAPSHOT_AGE, BlockNumReader, HeaderProvider, ReceiptProvider,
+};
+use tracing::{info, warn};
+
+/// The number of blocks checked per chunk of the scan.
+const CHUNK_SIZE: u64 = 10_000;
+
+/// The arguments for the `reth db check-blooms` command
+///
+/// Receipts are stored without their logs bloom, which is re-derived from the logs whenever it's
+/// needed. This command recomputes the bloom of every block in the range from its stored receipts
+/// and reports all blocks whose header bloom doesn't match.
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The first block to check.
+    #[arg(long, default_value_t = 0)]
+    from: BlockNumber,
+
+    /// The last block to check.
+    ///
+    /// Defaults to the highest block in the database.
+    #[arg(long)]
+    to: Option<BlockNumber>,
+}
+
+impl Command {
+    /// Execute `db check-blooms` command
+    pub fn execute<DB: Database>(self, tool: &DbTool<DB>) -> eyre::Result<()> {
+        let to = match self.to {
+            Some(to) => to,
+            None => tool.provider_factory.provider()?.last_block_number()?,
+        };
+
+        // scan in chunks, so the check doesn't keep a single read transaction open for the whole
+        // range
+        let mut snapshots = tool.provider_factory.read_snapshots(DEFAULT_MAX_READ_SNAPSHOT_AGE);
+        let mut mismatched = Vec::new();
+        let mut missing_receipts = 0;
+        snapshots.for_each_chunk(self.from..=to, CHUNK_SIZE, |provider, chunk| {
+            for number in chunk {
+                let header = provider.sealed_header(number)?.ok_or_else(|| {
+                    ProviderError::HeaderNotFound(BlockHashOrNumber::Number(number))
+                })?;
+
+                // receipts can be missing if they were pruned
+                let Some(receipts) = provider.receipts_by_block(number.into())? else {
+                    missing_receipts += 1;
+                    continue
+                };
+
+                let bloom = logs_bloom(receipts.iter().flat_map(|receipt| &receipt.logs));
+                if bloom != header.logs_bloom {
+                    warn!(target: "reth::cli", number, hash = ?header.hash(), "Logs bloom mismatch");
+                    mismatched.push(number);
+                }
+
+                if number > self.from && number % 100_000 == 0 {
+                    info!(target: "reth::cli", number, to, "Checking logs blooms");
+                }
+            }
+            Ok(())
+        })?;
+
+        info!(
+            target: "reth::cli",
+            from = self.from,
+            to,
+            mismatched = mismatched.len(),
+            missing_receipts,
+            "Finished checking logs blooms"
+        );
+
+        if !mismatched.is_empty() {
+            eyre::bail!(
+                "{} blocks have a logs bloom that doesn't match their receipts: {mismatched:?}",
+                mismatched.len()
+            )
+        }
+
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use reth_db::{
     transaction::{DbTx, DbTxMut},
     TableViewer, Tables,
 };
-use reth_primitives::{static_file::find_fixed_range, StaticFileSegment};
+use reth_primitives::StaticFileSegment;
 use reth_provider::ProviderFactory;
 
 /// The arguments for the `reth db clear` command
@@ -29,8 +29,10 @@ impl Command {
 
                 if let Some(segment_static_files) = static_files.get(&segment) {
                     for (block_range, _) in segment_static_files {
-                        static_file_provider
-                            .delete_jar(segment, find_fixed_range(block_range.start()))?;
+                        static_file_provider.delete_jar(
+                            segment,
+                            static_file_provider.find_fixed_range(block_range.start()),
+                        )?;
                     }
                 }
             }
@@ -1,11 +1,19 @@
+use super::get::table_key;
 use crate::{
     args::DatabaseArgs,
     dirs::{DataDirPath, PlatformPath},
     utils::DbTool,
 };
 use clap::Parser;
+use itertools::{EitherOrBoth, Itertools};
 use reth_db::{
-    cursor::DbCursorRO, database::Database, open_db_read_only, table::Table, transaction::DbTx,
+    codecs::CompactU256,
+    cursor::DbCursorRO,
+    database::Database,
+    open_db_read_only,
+    static_file::{HeaderMask, ReceiptMask, StaticFileCursor, TransactionMask},
+    table::Table,
+    transaction::DbTx,
     AccountChangeSets, AccountsHistory, AccountsTrie, BlockBodyIndices, BlockOmmers,
     BlockWithdrawals, Bytecodes, CanonicalHeaders, DatabaseEnv, HashedAccounts, HashedStorages,
     HeaderNumbers, HeaderTerminalDifficulties, Headers, PlainAccountState, PlainStorageState,
@@ -13,12 +21,19 @@ use reth_db::{
     StoragesHistory, StoragesTrie, Tables, TransactionBlocks, TransactionHashNumbers,
     TransactionSenders, Transactions, VersionHistory,
 };
+use reth_interfaces::provider::ProviderResult;
+use reth_primitives::{
+    BlockHash, Header, Receipt, StaticFileSegment, TransactionSignedNoHash, U256,
+};
+use reth_provider::providers::StaticFileProvider;
 use std::{
     collections::HashMap,
     fmt::Debug,
     fs::{self, File},
     hash::Hash,
     io::Write,
+    iter,
+    ops::{Bound, RangeInclusive},
     path::{Path, PathBuf},
 };
 use tracing::info;
@@ -38,6 +53,20 @@ pub struct Command {
     #[arg(long, verbatim_doc_comment)]
     table: Option<Tables>,
 
+    /// The static file segment to diff instead of the database tables.
+    #[arg(long, verbatim_doc_comment, conflicts_with = "table")]
+    segment: Option<StaticFileSegment>,
+
+    /// The first key to diff, as JSON, e.g. a block number or a quoted hash. For static file
+    /// segments, the first block or transaction number. Requires `--table` or `--segment`.
+    #[arg(long, value_name = "KEY", verbatim_doc_comment)]
+    from: Option<String>,
+
+    /// The last key to diff, inclusive. Must not be less than `--from`. Requires `--table` or
+    /// `--segment`.
+    #[arg(long, value_name = "KEY", verbatim_doc_comment)]
+    to: Option<String>,
+
     /// The output directory for the diff report.
     #[arg(long, verbatim_doc_comment)]
     output: PlatformPath<PathBuf>,
@@ -57,7 +86,40 @@ impl Command {
     ///
     /// The discrepancies and extra elements, along with a brief summary of the diff results are
     /// then written to a file in the output directory.
+    ///
+    /// With `--segment`, the rows of a static file segment are compared instead, using the
+    /// `static_files/` folder of both datadirs. `--from` and `--to` limit the diff to a key range.
     pub fn execute(self, tool: &DbTool<DatabaseEnv>) -> eyre::Result<()> {
+        if (self.from.is_some() || self.to.is_some()) &&
+            self.table.is_none() &&
+            self.segment.is_none()
+        {
+            eyre::bail!("--from and --to require --table or --segment")
+        }
+
+        if let Some(segment) = self.segment {
+            let second_static_files_path: PathBuf =
+                self.secondary_datadir.join("static_files").into();
+            let second_static_file_provider = StaticFileProvider::new(second_static_files_path)?;
+            let range: (Option<u64>, Option<u64>) = (
+                self.from.as_deref().map(str::parse).transpose()?,
+                self.to.as_deref().map(str::parse).transpose()?,
+            );
+            if let (Some(from), Some(to)) = range {
+                if from > to {
+                    eyre::bail!("--from {from} is greater than --to {to}")
+                }
+            }
+
+            return find_static_file_diffs(
+                segment,
+                &tool.provider_factory.static_file_provider(),
+                &second_static_file_provider,
+                range,
+                self.output,
+            )
+        }
+
         // open second db
         let second_db_path: PathBuf = self.secondary_datadir.join("db").into();
         let second_db = open_db_read_only(&second_db_path, self.second_db.database_args())?;
@@ -67,6 +129,7 @@ impl Command {
             None => Tables::ALL,
         };
 
+        let range = (self.from.as_deref(), self.to.as_deref());
         for table in tables {
             let primary_tx = tool.provider_factory.db_ref().tx()?;
             let secondary_tx = second_db.tx()?;
@@ -74,79 +137,94 @@ impl Command {
             let output_dir = self.output.clone();
             match table {
                 Tables::CanonicalHeaders => {
-                    find_diffs::<CanonicalHeaders>(primary_tx, secondary_tx, output_dir)?
-                }
-                Tables::HeaderTerminalDifficulties => {
-                    find_diffs::<HeaderTerminalDifficulties>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<CanonicalHeaders>(primary_tx, secondary_tx, output_dir, range)?
                 }
+                Tables::HeaderTerminalDifficulties => find_diffs::<HeaderTerminalDifficulties>(
+                    primary_tx,
+                    secondary_tx,
+                    output_dir,
+                    range,
+                )?,
                 Tables::HeaderNumbers => {
-                    find_diffs::<HeaderNumbers>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<HeaderNumbers>(primary_tx, secondary_tx, output_dir, range)?
+                }
+                Tables::Headers => {
+                    find_diffs::<Headers>(primary_tx, secondary_tx, output_dir, range)?
                 }
-                Tables::Headers => find_diffs::<Headers>(primary_tx, secondary_tx, output_dir)?,
                 Tables::BlockBodyIndices => {
-                    find_diffs::<BlockBodyIndices>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<BlockBodyIndices>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::BlockOmmers => {
-                    find_diffs::<BlockOmmers>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<BlockOmmers>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::BlockWithdrawals => {
-                    find_diffs::<BlockWithdrawals>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<BlockWithdrawals>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::TransactionBlocks => {
-                    find_diffs::<TransactionBlocks>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<TransactionBlocks>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::Transactions => {
-                    find_diffs::<Transactions>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<Transactions>(primary_tx, secondary_tx, output_dir, range)?
                 }
-                Tables::TransactionHashNumbers => {
-                    find_diffs::<TransactionHashNumbers>(primary_tx, secondary_tx, output_dir)?
+                Tables::TransactionHashNumbers => find_diffs::<TransactionHashNumbers>(
+                    primary_tx,
+                    secondary_tx,
+                    output_dir,
+                    range,
+                )?,
+                Tables::Receipts => {
+                    find_diffs::<Receipts>(primary_tx, secondary_tx, output_dir, range)?
                 }
-                Tables::Receipts => find_diffs::<Receipts>(primary_tx, secondary_tx, output_dir)?,
                 Tables::PlainAccountState => {
-                    find_diffs::<PlainAccountState>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<PlainAccountState>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::PlainStorageState => {
-                    find_diffs::<PlainStorageState>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<PlainStorageState>(primary_tx, secondary_tx, output_dir, range)?
+                }
+                Tables::Bytecodes => {
+                    find_diffs::<Bytecodes>(primary_tx, secondary_tx, output_dir, range)?
                 }
-                Tables::Bytecodes => find_diffs::<Bytecodes>(primary_tx, secondary_tx, output_dir)?,
                 Tables::AccountsHistory => {
-                    find_diffs::<AccountsHistory>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<AccountsHistory>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::StoragesHistory => {
-                    find_diffs::<StoragesHistory>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<StoragesHistory>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::AccountChangeSets => {
-                    find_diffs::<AccountChangeSets>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<AccountChangeSets>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::StorageChangeSets => {
-                    find_diffs::<StorageChangeSets>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<StorageChangeSets>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::HashedAccounts => {
-                    find_diffs::<HashedAccounts>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<HashedAccounts>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::HashedStorages => {
-                    find_diffs::<HashedStorages>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<HashedStorages>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::AccountsTrie => {
-                    find_diffs::<AccountsTrie>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<AccountsTrie>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::StoragesTrie => {
-                    find_diffs::<StoragesTrie>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<StoragesTrie>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::TransactionSenders => {
-                    find_diffs::<TransactionSenders>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<TransactionSenders>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::StageCheckpoints => {
-                    find_diffs::<StageCheckpoints>(primary_tx, secondary_tx, output_dir)?
-                }
-                Tables::StageCheckpointProgresses => {
-                    find_diffs::<StageCheckpointProgresses>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<StageCheckpoints>(primary_tx, secondary_tx, output_dir, range)?
                 }
+                Tables::StageCheckpointProgresses => find_diffs::<StageCheckpointProgresses>(
+                    primary_tx,
+                    secondary_tx,
+                    output_dir,
+                    range,
+                )?,
                 Tables::PruneCheckpoints => {
-                    find_diffs::<PruneCheckpoints>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<PruneCheckpoints>(primary_tx, secondary_tx, output_dir, range)?
                 }
                 Tables::VersionHistory => {
-                    find_diffs::<VersionHistory>(primary_tx, secondary_tx, output_dir)?
+                    find_diffs::<VersionHistory>(primary_tx, secondary_tx, output_dir, range)?
                 }
             };
         }
@@ -155,11 +233,12 @@ impl Command {
     }
 }
 
-/// Find diffs for a table, then analyzing the result
+/// Find diffs for a table within an optional key range, then analyzing the result
 fn find_diffs<T: Table>(
     primary_tx: impl DbTx,
     secondary_tx: impl DbTx,
     output_dir: impl AsRef<Path>,
+    range: (Option<&str>, Option<&str>),
 ) -> eyre::Result<()>
 where
     T::Key: Hash,
@@ -168,7 +247,7 @@ where
     let table = T::TABLE;
 
     info!("Analyzing table {table}...");
-    let result = find_diffs_advanced::<T>(&primary_tx, &secondary_tx)?;
+    let result = find_diffs_advanced::<T>(&primary_tx, &secondary_tx, range)?;
     info!("Done analyzing table {table}!");
 
     // Pretty info summary header: newline then header
@@ -242,20 +321,33 @@ where
 fn find_diffs_advanced<T: Table>(
     primary_tx: &impl DbTx,
     secondary_tx: &impl DbTx,
+    (from, to): (Option<&str>, Option<&str>),
 ) -> eyre::Result<TableDiffResult<T>>
 where
     T::Value: PartialEq,
     T::Key: Hash,
 {
+    let from = from.map(table_key::<T>).transpose()?;
+    let to = to.map(table_key::<T>).transpose()?;
+    if let (Some(from), Some(to)) = (&from, &to) {
+        if from > to {
+            eyre::bail!("--from {from:?} is greater than --to {to:?}")
+        }
+    }
+    let bounds = (
+        from.map_or(Bound::Unbounded, Bound::Included),
+        to.map_or(Bound::Unbounded, Bound::Included),
+    );
+
     // initialize the zipped walker
     let mut primary_zip_cursor =
         primary_tx.cursor_read::<T>().expect("Was not able to obtain a cursor.");
-    let primary_walker = primary_zip_cursor.walk(None)?;
+    let primary_walker = primary_zip_cursor.walk_range(bounds.clone())?;
 
     let mut secondary_zip_cursor =
         secondary_tx.cursor_read::<T>().expect("Was not able to obtain a cursor.");
-    let secondary_walker = secondary_zip_cursor.walk(None)?;
-    let zipped_cursor = primary_walker.zip(secondary_walker);
+    let secondary_walker = secondary_zip_cursor.walk_range(bounds)?;
+    let zipped_cursor = primary_walker.zip_longest(secondary_walker);
 
     // initialize the cursors for seeking when we are cross checking elements
     let mut primary_cursor =
@@ -269,38 +361,211 @@ where
     // this loop will walk both tables, cross-checking for the element in the other table.
     // it basically just loops through both tables at the same time. if the keys are different, it
     // will check each key in the other table. if the keys are the same, it will compare the
-    // values
-    for (primary_entry, secondary_entry) in zipped_cursor {
-        let (primary_key, primary_value) = primary_entry?;
-        let (secondary_key, secondary_value) = secondary_entry?;
-
-        if primary_key != secondary_key {
-            // if the keys are different, we need to check if the key is in the other table
-            let crossed_secondary =
-                secondary_cursor.seek_exact(primary_key.clone())?.map(|(_, value)| value);
-            result.try_push_discrepancy(
-                primary_key.clone(),
-                Some(primary_value),
-                crossed_secondary,
-            );
-
-            // now do the same for the primary table
-            let crossed_primary =
-                primary_cursor.seek_exact(secondary_key.clone())?.map(|(_, value)| value);
-            result.try_push_discrepancy(
-                secondary_key.clone(),
-                crossed_primary,
-                Some(secondary_value),
-            );
-        } else {
-            // the keys are the same, so we need to compare the values
-            result.try_push_discrepancy(primary_key, Some(primary_value), Some(secondary_value));
+    // values. once one of the tables is exhausted, the remaining elements of the other one are
+    // cross-checked as well.
+    for entries in zipped_cursor {
+        match entries {
+            EitherOrBoth::Both(primary_entry, secondary_entry) => {
+                let (primary_key, primary_value) = primary_entry?;
+                let (secondary_key, secondary_value) = secondary_entry?;
+
+                if primary_key != secondary_key {
+                    // if the keys are different, we need to check if the key is in the other
+                    // table
+                    let crossed_secondary =
+                        secondary_cursor.seek_exact(primary_key.clone())?.map(|(_, value)| value);
+                    result.try_push_discrepancy(
+                        primary_key.clone(),
+                        Some(primary_value),
+                        crossed_secondary,
+                    );
+
+                    // now do the same for the primary table
+                    let crossed_primary =
+                        primary_cursor.seek_exact(secondary_key.clone())?.map(|(_, value)| value);
+                    result.try_push_discrepancy(
+                        secondary_key.clone(),
+                        crossed_primary,
+                        Some(secondary_value),
+                    );
+                } else {
+                    // the keys are the same, so we need to compare the values
+                    result.try_push_discrepancy(
+                        primary_key,
+                        Some(primary_value),
+                        Some(secondary_value),
+                    );
+                }
+            }
+            EitherOrBoth::Left(primary_entry) => {
+                let (primary_key, primary_value) = primary_entry?;
+                let crossed_secondary =
+                    secondary_cursor.seek_exact(primary_key.clone())?.map(|(_, value)| value);
+                result.try_push_discrepancy(primary_key, Some(primary_value), crossed_secondary);
+            }
+            EitherOrBoth::Right(secondary_entry) => {
+                let (secondary_key, secondary_value) = secondary_entry?;
+                let crossed_primary =
+                    primary_cursor.seek_exact(secondary_key.clone())?.map(|(_, value)| value);
+                result.try_push_discrepancy(secondary_key, crossed_primary, Some(secondary_value));
+            }
         }
     }
 
     Ok(result)
 }
 
+/// Find diffs for a static file segment between two static file directories, writing them to a
+/// file in the output directory as they're found.
+///
+/// Rows are compared by block number for headers and by transaction number otherwise. The range
+/// defaults to all rows of both directories.
+fn find_static_file_diffs(
+    segment: StaticFileSegment,
+    primary: &StaticFileProvider,
+    secondary: &StaticFileProvider,
+    (from, to): (Option<u64>, Option<u64>),
+    output_dir: impl AsRef<Path>,
+) -> eyre::Result<()> {
+    let Some(end) =
+        to.or_else(|| highest_row(primary, segment).max(highest_row(secondary, segment)))
+    else {
+        info!("No static files found for segment {segment}");
+        return Ok(())
+    };
+    let range = from.unwrap_or_default()..=end;
+
+    fs::create_dir_all(output_dir.as_ref())?;
+    let file_name = output_dir.as_ref().join(format!("{segment}.txt"));
+    let mut file = File::create(&file_name)?;
+    writeln!(file, "Diff results for static file segment {segment} in range {range:?}")?;
+
+    info!("Analyzing static file segment {segment} in range {range:?}...");
+    let (discrepancies, extra_elements) =
+        diff_static_file_segment(segment, primary, secondary, range, &mut file)?;
+
+    writeln!(file, "Found {discrepancies} discrepancies in static file segment {segment}")?;
+    writeln!(file, "Found {extra_elements} extra elements in static file segment {segment}")?;
+    info!("Found {discrepancies} discrepancies in static file segment {segment}");
+    info!("Found {extra_elements} extra elements in static file segment {segment}");
+    info!("Done writing diff results for {segment} to {}", file_name.display());
+
+    Ok(())
+}
+
+/// Returns the highest block number of a headers segment, or the highest transaction number of
+/// any other segment.
+fn highest_row(provider: &StaticFileProvider, segment: StaticFileSegment) -> Option<u64> {
+    match segment {
+        StaticFileSegment::Headers => provider.get_highest_static_file_block(segment),
+        StaticFileSegment::Transactions | StaticFileSegment::Receipts => {
+            provider.get_highest_static_file_tx(segment)
+        }
+    }
+}
+
+/// Compares the rows of a static file segment in `range`, writing every difference to `out`.
+///
+/// Returns the number of discrepancies and extra elements.
+fn diff_static_file_segment(
+    segment: StaticFileSegment,
+    primary: &StaticFileProvider,
+    secondary: &StaticFileProvider,
+    range: RangeInclusive<u64>,
+    out: &mut impl Write,
+) -> eyre::Result<(usize, usize)> {
+    match segment {
+        StaticFileSegment::Headers => {
+            diff_static_file_rows(segment, primary, secondary, range, out, |cursor, number| {
+                let Some(header) = cursor.get_one::<HeaderMask<Header>>(number.into())? else {
+                    return Ok(None)
+                };
+                let Some((td, hash)) =
+                    cursor.get_two::<HeaderMask<CompactU256, BlockHash>>(number.into())?
+                else {
+                    return Ok(None)
+                };
+                Ok(Some((header, U256::from(td), hash)))
+            })
+        }
+        StaticFileSegment::Transactions => {
+            diff_static_file_rows(segment, primary, secondary, range, out, |cursor, number| {
+                cursor.get_one::<TransactionMask<TransactionSignedNoHash>>(number.into())
+            })
+        }
+        StaticFileSegment::Receipts => {
+            diff_static_file_rows(segment, primary, secondary, range, out, |cursor, number| {
+                cursor.get_one::<ReceiptMask<Receipt>>(number.into())
+            })
+        }
+    }
+}
+
+/// Walks the rows of a static file segment in `range` in both directories side by side, writing
+/// every difference to `out`.
+///
+/// Returns the number of discrepancies and extra elements.
+fn diff_static_file_rows<V, F>(
+    segment: StaticFileSegment,
+    primary: &StaticFileProvider,
+    secondary: &StaticFileProvider,
+    range: RangeInclusive<u64>,
+    out: &mut impl Write,
+    get_fn: F,
+) -> eyre::Result<(usize, usize)>
+where
+    V: PartialEq + Debug + 'static,
+    F: Fn(&mut StaticFileCursor<'_>, u64) -> ProviderResult<Option<V>> + Copy + 'static,
+{
+    let primary_rows = static_file_rows(segment, primary, &range, get_fn)?;
+    let secondary_rows = static_file_rows(segment, secondary, &range, get_fn)?;
+
+    let mut discrepancies = 0;
+    let mut extra_elements = 0;
+    // rows of a segment are contiguous, so the n-th row of both walkers has the same number
+    for (number, rows) in range.zip(primary_rows.zip_longest(secondary_rows)) {
+        match rows {
+            EitherOrBoth::Both(first, second) => {
+                let (first, second) = (first?, second?);
+                if first != second {
+                    discrepancies += 1;
+                    writeln!(out, "Discrepancy at {number}: {first:?} != {second:?}")?;
+                }
+            }
+            EitherOrBoth::Left(first) => {
+                extra_elements += 1;
+                writeln!(out, "Extra element at {number} in first: {:?}", first?)?;
+            }
+            EitherOrBoth::Right(second) => {
+                extra_elements += 1;
+                writeln!(out, "Extra element at {number} in second: {:?}", second?)?;
+            }
+        }
+    }
+
+    Ok((discrepancies, extra_elements))
+}
+
+/// Returns an iterator over the rows of a static file segment from the start of `range` up to the
+/// end of `range` or the highest row of the segment, whichever comes first.
+fn static_file_rows<'a, V, F>(
+    segment: StaticFileSegment,
+    provider: &'a StaticFileProvider,
+    range: &RangeInclusive<u64>,
+    get_fn: F,
+) -> ProviderResult<Box<dyn Iterator<Item = ProviderResult<V>> + 'a>>
+where
+    V: Debug + 'a,
+    F: Fn(&mut StaticFileCursor<'_>, u64) -> ProviderResult<Option<V>> + 'a,
+{
+    let Some(highest) = highest_row(provider, segment) else { return Ok(Box::new(iter::empty())) };
+    let rows = *range.start()..highest.min(*range.end()).saturating_add(1);
+    if rows.is_empty() {
+        return Ok(Box::new(iter::empty()))
+    }
+    Ok(Box::new(provider.fetch_range_iter(segment, rows, get_fn)?))
+}
+
 /// Includes a table element between two databases with the same key, but different values
 #[derive(Debug)]
 struct TableDiffElement<T: Table> {
@@ -416,3 +681,128 @@ impl<T: Table> ExtraTableElement<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_db::{
+        init_db, mdbx::DatabaseArguments, models::client_version::ClientVersion,
+        transaction::DbTxMut,
+    };
+    use reth_primitives::B256;
+
+    fn create_db(
+        hashes: impl IntoIterator<Item = (u64, B256)>,
+    ) -> (tempfile::TempDir, DatabaseEnv) {
+        let dir = tempfile::tempdir().unwrap();
+        let db = init_db(dir.path(), DatabaseArguments::new(ClientVersion::default())).unwrap();
+        let tx = db.tx_mut().unwrap();
+        for (number, hash) in hashes {
+            tx.put::<CanonicalHeaders>(number, hash).unwrap();
+        }
+        tx.commit().unwrap();
+        (dir, db)
+    }
+
+    fn create_static_files(
+        headers: impl IntoIterator<Item = Header>,
+    ) -> (tempfile::TempDir, StaticFileProvider) {
+        let dir = tempfile::tempdir().unwrap();
+        let provider = StaticFileProvider::new(dir.path()).unwrap();
+        {
+            let mut writer = provider.latest_writer(StaticFileSegment::Headers).unwrap();
+            for header in headers {
+                let hash = header.hash_slow();
+                writer.append_header(header, U256::ZERO, hash).unwrap();
+            }
+            writer.commit().unwrap();
+        }
+        (dir, provider)
+    }
+
+    #[test]
+    fn table_diff_checks_the_tail_of_the_longer_table() {
+        let (_primary_dir, primary) =
+            create_db((0..6).map(|number| (number, B256::with_last_byte(number as u8))));
+        let (_secondary_dir, secondary) = create_db((0..3).map(|number| {
+            (number, B256::with_last_byte(if number == 1 { 0xff } else { number as u8 }))
+        }));
+
+        let result = find_diffs_advanced::<CanonicalHeaders>(
+            &primary.tx().unwrap(),
+            &secondary.tx().unwrap(),
+            (None, None),
+        )
+        .unwrap();
+        assert_eq!(result.discrepancies.keys().copied().sorted().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(
+            result.extra_elements.keys().copied().sorted().collect::<Vec<_>>(),
+            vec![3, 4, 5]
+        );
+        assert!(result
+            .extra_elements
+            .values()
+            .all(|element| matches!(element, ExtraTableElement::First { .. })));
+
+        // the other way around, the tail is in the second table
+        let result = find_diffs_advanced::<CanonicalHeaders>(
+            &secondary.tx().unwrap(),
+            &primary.tx().unwrap(),
+            (Some("2"), Some("4")),
+        )
+        .unwrap();
+        assert!(result.discrepancies.is_empty());
+        assert_eq!(result.extra_elements.keys().copied().sorted().collect::<Vec<_>>(), vec![3, 4]);
+        assert!(result
+            .extra_elements
+            .values()
+            .all(|element| matches!(element, ExtraTableElement::Second { .. })));
+
+        assert!(find_diffs_advanced::<CanonicalHeaders>(
+            &primary.tx().unwrap(),
+            &secondary.tx().unwrap(),
+            (Some("4"), Some("2")),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn segment_diff_checks_the_tail_of_the_longer_segment() {
+        let headers = (0..10).map(|number| Header { number, ..Default::default() });
+        let (_primary_dir, primary) = create_static_files(headers.clone());
+        let (_secondary_dir, secondary) = create_static_files(headers.take(6).map(|mut header| {
+            if header.number == 2 {
+                header.gas_used = 1;
+            }
+            header
+        }));
+
+        let diff = |primary, secondary, range| {
+            let mut out = Vec::new();
+            let counts = diff_static_file_segment(
+                StaticFileSegment::Headers,
+                primary,
+                secondary,
+                range,
+                &mut out,
+            )
+            .unwrap();
+            (counts, String::from_utf8(out).unwrap())
+        };
+
+        let ((discrepancies, extra_elements), out) = diff(&primary, &secondary, 0..=9);
+        assert_eq!((discrepancies, extra_elements), (1, 4));
+        assert!(out.contains("Discrepancy at 2:"));
+        for number in 6..10 {
+            assert!(out.contains(&format!("Extra element at {number} in first:")));
+        }
+
+        let ((discrepancies, extra_elements), out) = diff(&secondary, &primary, 3..=7);
+        assert_eq!((discrepancies, extra_elements), (0, 2));
+        assert!(out.contains("Extra element at 6 in second:"));
+        assert!(out.contains("Extra element at 7 in second:"));
+
+        // ranges past the end of both segments have nothing to compare
+        assert_eq!(diff(&primary, &secondary, 20..=30).0, (0, 0));
+    }
+}
@@ -0,0 +1,52 @@
+use crate::utils::DbTool;
+use clap::Parser;
+use comfy_table::{Cell, Row, Table as ComfyTable};
+use reth_db::database::Database;
+use reth_primitives::{format_ether, BlockNumber};
+use reth_provider::{beneficiary_fees, BlockNumReader};
+
+/// The arguments for the `reth db fee-report` command
+///
+/// Aggregates the number of blocks, gas used and priority fees per block beneficiary, which can be
+/// used to audit the fee flows to block producers.
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The first block of the report.
+    #[arg(long, default_value_t = 0)]
+    from: BlockNumber,
+
+    /// The last block of the report.
+    ///
+    /// Defaults to the highest block in the database.
+    #[arg(long)]
+    to: Option<BlockNumber>,
+}
+
+impl Command {
+    /// Execute `db fee-report` command
+    pub fn execute<DB: Database>(self, tool: &DbTool<DB>) -> eyre::Result<()> {
+        let to = match self.to {
+            Some(to) => to,
+            None => tool.provider_factory.last_block_number()?,
+        };
+
+        let mut fees = beneficiary_fees(&tool.provider_factory, self.from..=to)?;
+        fees.sort_unstable_by(|a, b| b.fees.cmp(&a.fees));
+
+        let mut table = ComfyTable::new();
+        table.load_preset(comfy_table::presets::ASCII_MARKDOWN);
+        table.set_header(["Beneficiary", "Blocks", "Gas Used", "Priority Fees (ETH)"]);
+        for entry in fees {
+            let mut row = Row::new();
+            row.add_cell(Cell::new(entry.beneficiary))
+                .add_cell(Cell::new(entry.blocks))
+                .add_cell(Cell::new(entry.gas_used))
+                .add_cell(Cell::new(format_ether(entry.fees)));
+            table.add_row(row);
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::utils::DbTool;
+use clap::Parser;
+use comfy_table::{Cell, Row, Table as ComfyTable};
+use human_bytes::human_bytes;
+use reth_db::DatabaseEnv;
+
+#[derive(Parser, Debug)]
+/// The arguments for the `reth db info` command
+pub struct Command;
+
+impl Command {
+    /// Execute `db info` command
+    pub fn execute(self, tool: &DbTool<DatabaseEnv>) -> eyre::Result<()> {
+        let env = tool.provider_factory.db_ref();
+        let info = env.info()?;
+        let stat = env.stat()?;
+        let geometry = info.geometry();
+        let page_size = stat.page_size() as usize;
+
+        let mut table = ComfyTable::new();
+        table.load_preset(comfy_table::presets::ASCII_MARKDOWN);
+        table.set_header(["Property", "Value"]);
+
+        let rows = [
+            ("Page Size", human_bytes(page_size as f64)),
+            ("Current Size", human_bytes(geometry.current() as f64)),
+            ("Minimum Size", human_bytes(geometry.min() as f64)),
+            ("Maximum Size", human_bytes(geometry.max() as f64)),
+            ("Growth Step", human_bytes(geometry.growth_step() as f64)),
+            ("Shrink Threshold", human_bytes(geometry.shrink_threshold() as f64)),
+            ("Map Size", human_bytes(info.map_size() as f64)),
+            ("Used Size", human_bytes((info.last_pgno() + 1) as f64 * page_size as f64)),
+            ("Freelist Size", human_bytes((env.freelist()? * page_size) as f64)),
+            ("Readers", format!("{} / {}", info.num_readers(), info.max_readers())),
+            ("Last Transaction ID", info.last_txnid().to_string()),
+        ];
+        for (property, value) in rows {
+            let mut row = Row::new();
+            row.add_cell(Cell::new(property)).add_cell(Cell::new(value));
+            table.add_row(row);
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
+}
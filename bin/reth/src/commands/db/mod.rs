@@ -20,10 +20,14 @@ use std::{
     sync::Arc,
 };
 
+mod check_blooms;
 mod clear;
 mod diff;
+mod fee_report;
 mod get;
+mod info;
 mod list;
+mod static_file;
 mod static_files;
 mod stats;
 /// DB List TUI
@@ -67,12 +71,19 @@ pub struct Command {
 pub enum Subcommands {
     /// Lists all the tables, their entry count and their size
     Stats(stats::Command),
+    /// Shows the current geometry and usage of the database environment
+    Info(info::Command),
     /// Lists the contents of a table
     List(list::Command),
-    /// Create a diff between two database tables or two entire databases.
+    /// Create a diff between two database tables, two entire databases or two static file
+    /// segments.
     Diff(diff::Command),
     /// Gets the content of a table for the given key
     Get(get::Command),
+    /// Verifies the logs bloom of stored headers against the bloom derived from their receipts
+    CheckBlooms(check_blooms::Command),
+    /// Aggregates the blocks, gas used and priority fees per block beneficiary in a block range
+    FeeReport(fee_report::Command),
     /// Deletes all database entries
     Drop {
         /// Bypasses the interactive confirmation and drops the database directly
@@ -83,6 +94,9 @@ pub enum Subcommands {
     Clear(clear::Command),
     /// Creates static files from database tables
     CreateStaticFiles(static_files::Command),
+    /// Exports, imports and maintains static files
+    #[command(name = "static-file")]
+    StaticFile(static_file::Command),
     /// Lists current and local database versions
     Version,
     /// Returns the full database path
@@ -116,6 +130,11 @@ impl Command {
                     command.execute(data_dir, &tool)?;
                 });
             }
+            Subcommands::Info(command) => {
+                db_ro_exec!(self.chain, &db_path, db_args, static_files_path, tool, {
+                    command.execute(&tool)?;
+                });
+            }
             Subcommands::List(command) => {
                 db_ro_exec!(self.chain, &db_path, db_args, static_files_path, tool, {
                     command.execute(&tool)?;
@@ -131,6 +150,16 @@ impl Command {
                     command.execute(&tool)?;
                 });
             }
+            Subcommands::FeeReport(command) => {
+                db_ro_exec!(self.chain, &db_path, db_args, static_files_path, tool, {
+                    command.execute(&tool)?;
+                });
+            }
+            Subcommands::CheckBlooms(command) => {
+                db_ro_exec!(self.chain, &db_path, db_args, static_files_path, tool, {
+                    command.execute(&tool)?;
+                });
+            }
             Subcommands::Drop { force } => {
                 if !force {
                     // Ask for confirmation
@@ -164,6 +193,9 @@ impl Command {
             Subcommands::CreateStaticFiles(command) => {
                 command.execute(data_dir, self.db.database_args(), self.chain.clone())?;
             }
+            Subcommands::StaticFile(command) => {
+                command.execute(data_dir, db_args, self.chain.clone())?;
+            }
             Subcommands::Version => {
                 let local_db_version = match get_db_version(&db_path) {
                     Ok(version) => Some(version),
@@ -199,4 +231,70 @@ mod tests {
         let cmd = Command::try_parse_from(["reth", "stats", "--datadir", &path]).unwrap();
         assert_eq!(cmd.datadir.as_ref(), Some(Path::new(&path)));
     }
+
+    #[test]
+    fn parse_diff_range() {
+        let cmd = Command::try_parse_from([
+            "reth",
+            "diff",
+            "--secondary-datadir",
+            "other",
+            "--output",
+            "diff",
+            "--segment",
+            "headers",
+            "--from",
+            "100",
+            "--to",
+            "200",
+        ])
+        .unwrap();
+        assert!(matches!(cmd.command, Subcommands::Diff(_)));
+
+        assert!(Command::try_parse_from([
+            "reth",
+            "diff",
+            "--secondary-datadir",
+            "other",
+            "--output",
+            "diff",
+            "--segment",
+            "headers",
+            "--table",
+            "Headers",
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn parse_static_file_commands() {
+        let cmd = Command::try_parse_from([
+            "reth",
+            "static-file",
+            "export",
+            "--range",
+            "0..=499999",
+            "--output",
+            "snapshot.tar",
+        ])
+        .unwrap();
+        assert!(matches!(cmd.command, Subcommands::StaticFile(_)));
+
+        let cmd =
+            Command::try_parse_from(["reth", "static-file", "import", "snapshot.tar"]).unwrap();
+        assert!(matches!(cmd.command, Subcommands::StaticFile(_)));
+
+        let cmd = Command::try_parse_from(["reth", "static-file", "verify"]).unwrap();
+        assert!(matches!(cmd.command, Subcommands::StaticFile(_)));
+
+        let cmd = Command::try_parse_from([
+            "reth",
+            "static-file",
+            "compact",
+            "--blocks-per-file",
+            "1000000",
+        ])
+        .unwrap();
+        assert!(matches!(cmd.command, Subcommands::StaticFile(_)));
+    }
 }
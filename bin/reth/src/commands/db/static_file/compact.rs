@@ -0,0 +1,80 @@
+use clap::Parser;
+use reth_db::{mdbx::DatabaseArguments, open_db_read_only, StorageLock};
+use reth_node_core::dirs::{ChainPath, DataDirPath};
+use reth_primitives::{fs, ChainSpec};
+use reth_provider::{providers::StaticFileProvider, BlockReader, ProviderFactory};
+use std::sync::Arc;
+use tracing::{error, info};
+
+/// The arguments for the `reth db static-file compact` command
+///
+/// Rewrites all static files into a new directory, packing them densely, and replaces the
+/// existing static files with them. This can also change the number of blocks stored in each
+/// static file. The node must not be running, which is enforced with the lock of the data
+/// directory.
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// Number of blocks stored in each rewritten static file. Defaults to the number of blocks
+    /// of the existing static files.
+    #[arg(long, value_name = "BLOCKS", value_parser = clap::value_parser!(u64).range(1..))]
+    blocks_per_file: Option<u64>,
+
+    /// Keep the existing static files in a backup directory instead of deleting them.
+    #[arg(long)]
+    keep_backup: bool,
+}
+
+impl Command {
+    /// Execute `db static-file compact` command
+    pub fn execute(
+        self,
+        data_dir: ChainPath<DataDirPath>,
+        db_args: DatabaseArguments,
+        chain: Arc<ChainSpec>,
+    ) -> eyre::Result<()> {
+        let _lock = StorageLock::try_acquire(&data_dir.data_dir_path())?;
+        let static_files_path = data_dir.static_files_path();
+
+        let db = open_db_read_only(&data_dir.db_path(), db_args)?;
+        let provider_factory = ProviderFactory::new(db, chain, static_files_path.clone())?;
+        let static_file_provider = provider_factory.static_file_provider();
+        let blocks_per_file =
+            self.blocks_per_file.unwrap_or_else(|| static_file_provider.blocks_per_file());
+
+        let compacted_path = static_files_path.with_file_name("static_files_compacted");
+        let backup_path = static_files_path.with_file_name("static_files_backup");
+        if compacted_path.exists() {
+            // left over from an interrupted run
+            fs::remove_dir_all(&compacted_path)?;
+        }
+        if backup_path.exists() {
+            eyre::bail!("backup of static files already exists at {}", backup_path.display())
+        }
+
+        info!(target: "reth::cli", blocks_per_file, path = ?compacted_path, "Compacting static files");
+        {
+            let compacted =
+                StaticFileProvider::new(&compacted_path)?.with_blocks_per_file(blocks_per_file)?;
+            let provider = provider_factory.provider()?;
+            static_file_provider.copy_to(&compacted, |block| provider.block_body_indices(block))?;
+        }
+        drop((static_file_provider, provider_factory));
+
+        fs::rename(&static_files_path, &backup_path)?;
+        if let Err(err) = fs::rename(&compacted_path, &static_files_path) {
+            // Put the previous static files back, so the node can still start
+            if let Err(rollback_err) = fs::rename(&backup_path, &static_files_path) {
+                error!(target: "reth::cli", %rollback_err, path = ?backup_path, "Failed to restore previous static files");
+            }
+            return Err(err.into())
+        }
+        if self.keep_backup {
+            info!(target: "reth::cli", path = ?backup_path, "Kept previous static files");
+        } else {
+            fs::remove_dir_all(&backup_path)?;
+        }
+
+        info!(target: "reth::cli", "Compacted static files");
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use super::manifest::{Manifest, MANIFEST_FILE_NAME};
+use crate::utils::DbTool;
+use clap::Parser;
+use reth_db::database::Database;
+use reth_primitives::{BlockNumber, StaticFileSegment};
+use std::{fs::File, ops::RangeInclusive, path::PathBuf};
+use tracing::info;
+
+/// The arguments for the `reth db static-file export` command
+///
+/// Packages the static files holding the requested blocks into a tar archive, together with a
+/// manifest of the chain, block ranges and checksums. Static files are always exported as a
+/// whole, so the archive may contain more blocks than requested. The highest static file of each
+/// segment is never exported, since a running node may still be appending to it.
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The blocks to export, as `<FROM>..=<TO>` or `<FROM>..`. Defaults to all blocks.
+    #[arg(long, value_name = "RANGE", value_parser = parse_block_range)]
+    range: Option<RangeInclusive<BlockNumber>>,
+
+    /// The segments to export. Defaults to all segments.
+    #[arg(long, value_delimiter = ',')]
+    segments: Vec<StaticFileSegment>,
+
+    /// The path of the archive to write.
+    #[arg(long, short, value_name = "FILE")]
+    output: PathBuf,
+}
+
+impl Command {
+    /// Execute `db static-file export` command
+    pub fn execute<DB: Database>(self, tool: &DbTool<DB>) -> eyre::Result<()> {
+        let segments = if self.segments.is_empty() {
+            vec![
+                StaticFileSegment::Headers,
+                StaticFileSegment::Transactions,
+                StaticFileSegment::Receipts,
+            ]
+        } else {
+            self.segments
+        };
+
+        let static_file_provider = tool.provider_factory.static_file_provider();
+        let entries = static_file_provider
+            .snapshot_entries(&segments, self.range.unwrap_or(0..=BlockNumber::MAX))?;
+        if entries.is_empty() {
+            eyre::bail!("no static files hold blocks in the requested range")
+        }
+
+        let manifest = serde_json::to_vec_pretty(&Manifest::new(
+            &tool.chain,
+            static_file_provider.blocks_per_file(),
+            &entries,
+        ))?;
+
+        info!(target: "reth::cli", static_files = entries.len(), output = ?self.output, "Exporting static files");
+        let mut archive = tar::Builder::new(File::create(&self.output)?);
+
+        let mut header = tar::Header::new_gnu();
+        header.set_size(manifest.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        archive.append_data(&mut header, MANIFEST_FILE_NAME, manifest.as_slice())?;
+
+        for file in entries.iter().flat_map(|entry| &entry.files) {
+            let name = file.path.file_name().expect("static file paths have a file name");
+            archive.append_path_with_name(&file.path, name)?;
+        }
+        archive.into_inner()?.sync_all()?;
+
+        info!(target: "reth::cli", output = ?self.output, "Exported static files");
+        Ok(())
+    }
+}
+
+/// Parses a block range of the form `<FROM>..=<TO>` or `<FROM>..`.
+fn parse_block_range(value: &str) -> Result<RangeInclusive<BlockNumber>, String> {
+    let (from, to) = value
+        .split_once("..")
+        .ok_or_else(|| format!("expected `<FROM>..=<TO>` or `<FROM>..`, got `{value}`"))?;
+    let from = from.parse::<BlockNumber>().map_err(|err| format!("invalid start block: {err}"))?;
+    let to = if to.is_empty() {
+        BlockNumber::MAX
+    } else {
+        let to = to
+            .strip_prefix('=')
+            .ok_or_else(|| format!("block ranges are inclusive, expected `{from}..={to}`"))?;
+        to.parse::<BlockNumber>().map_err(|err| format!("invalid end block: {err}"))?
+    };
+
+    if from > to {
+        return Err(format!("start block {from} is greater than end block {to}"))
+    }
+    Ok(from..=to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_block_ranges() {
+        assert_eq!(parse_block_range("0..=499999"), Ok(0..=499_999));
+        assert_eq!(parse_block_range("500000.."), Ok(500_000..=BlockNumber::MAX));
+        assert!(parse_block_range("0..10").is_err());
+        assert!(parse_block_range("10..=5").is_err());
+        assert!(parse_block_range("10").is_err());
+    }
+}
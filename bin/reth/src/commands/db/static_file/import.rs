@@ -0,0 +1,107 @@
+use super::manifest::{Manifest, MANIFEST_FILE_NAME};
+use clap::Parser;
+use reth_db::{database::Database, mdbx::DatabaseArguments, open_db_read_only, StorageLock};
+use reth_node_core::dirs::{ChainPath, DataDirPath};
+use reth_primitives::{fs, stage::StageId, ChainSpec, StaticFileSegment};
+use reth_provider::{ProviderFactory, StageCheckpointReader};
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tracing::info;
+
+/// The arguments for the `reth db static-file import` command
+///
+/// Imports a snapshot archive written by `reth db static-file export`. The manifest is validated
+/// against the chain before any static file is extracted, and every file is checked against its
+/// checksum and every static file for continuity with the existing static files before it's
+/// moved into the static files directory.
+///
+/// Static files don't hold the database side of the imported blocks, like their block body
+/// indices, header numbers and stage checkpoints. The database has to be restored to at least the
+/// height of the snapshot first, so the import is refused if the snapshot is ahead of it. The node
+/// must not be running.
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The path of the archive to import.
+    archive: PathBuf,
+}
+
+impl Command {
+    /// Execute `db static-file import` command
+    pub fn execute(
+        self,
+        data_dir: ChainPath<DataDirPath>,
+        db_args: DatabaseArguments,
+        chain: Arc<ChainSpec>,
+    ) -> eyre::Result<()> {
+        let _lock = StorageLock::try_acquire(&data_dir.data_dir_path())?;
+        let static_files_path = data_dir.static_files_path();
+
+        let mut archive = tar::Archive::new(File::open(&self.archive)?);
+        let mut archive_entries = archive.entries()?;
+
+        let manifest: Manifest = match archive_entries.next() {
+            Some(entry) => {
+                let entry = entry?;
+                if entry.path()? != Path::new(MANIFEST_FILE_NAME) {
+                    eyre::bail!("archive doesn't start with a snapshot manifest")
+                }
+                serde_json::from_reader(entry)?
+            }
+            None => eyre::bail!("archive is empty"),
+        };
+        manifest.validate(&chain)?;
+
+        let db = open_db_read_only(&data_dir.db_path(), db_args)?;
+        let provider_factory = ProviderFactory::new(db, chain, static_files_path.clone())?
+            .with_static_files_blocks_per_file(manifest.blocks_per_file)?;
+        ensure_database_covers_snapshot(&provider_factory, &manifest)?;
+
+        let import_path = static_files_path.with_file_name("static_files_import");
+        if import_path.exists() {
+            // left over from an interrupted run
+            fs::remove_dir_all(&import_path)?;
+        }
+        fs::create_dir_all(&import_path)?;
+
+        info!(target: "reth::cli", archive = ?self.archive, static_files = manifest.static_files.len(), "Extracting static files");
+        for entry in archive_entries {
+            entry?.unpack_in(&import_path)?;
+        }
+
+        provider_factory
+            .static_file_provider()
+            .import_snapshot(&import_path, &manifest.entries(&import_path)?)?;
+        fs::remove_dir_all(&import_path)?;
+
+        info!(target: "reth::cli", "Imported static files");
+        Ok(())
+    }
+}
+
+/// Ensures that the database holds the stage checkpoints of all blocks in the snapshot.
+///
+/// The static files of each segment are written by a single stage, whose checkpoint implies that
+/// the database tables written alongside them are populated up to it.
+fn ensure_database_covers_snapshot<DB: Database>(
+    provider_factory: &ProviderFactory<DB>,
+    manifest: &Manifest,
+) -> eyre::Result<()> {
+    let provider = provider_factory.provider()?;
+    for (segment, stage) in [
+        (StaticFileSegment::Headers, StageId::Headers),
+        (StaticFileSegment::Transactions, StageId::Bodies),
+        (StaticFileSegment::Receipts, StageId::Execution),
+    ] {
+        let Some(highest) = manifest.highest_block(segment) else { continue };
+        let checkpoint = provider.get_stage_checkpoint(stage)?.unwrap_or_default().block_number;
+        if highest > checkpoint {
+            eyre::bail!(
+                "the snapshot holds {segment} up to block {highest}, but the {stage} stage of the database is only at block {checkpoint}; restore the database to at least block {highest} before importing"
+            )
+        }
+    }
+    Ok(())
+}
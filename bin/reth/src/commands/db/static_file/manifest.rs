@@ -0,0 +1,133 @@
+//! Manifest of static file snapshots.
+//!
+//! A snapshot is a tar archive holding a set of static files and a manifest describing them. The
+//! manifest is the first entry of the archive, so it can be validated before anything else is
+//! extracted.
+
+use reth_primitives::{
+    static_file::SegmentRangeInclusive, BlockNumber, ChainSpec, StaticFileSegment, B256,
+};
+use reth_provider::providers::{StaticFileSnapshotEntry, StaticFileSnapshotFile};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Name of the manifest inside a snapshot archive.
+pub(crate) const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// Manifest of a static file snapshot.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Manifest {
+    /// Chain id of the chain the static files belong to.
+    chain_id: u64,
+    /// Genesis hash of the chain the static files belong to.
+    genesis_hash: B256,
+    /// Number of blocks stored in each static file.
+    pub(crate) blocks_per_file: u64,
+    /// Static files of the snapshot, ordered by segment and block range.
+    pub(crate) static_files: Vec<ManifestEntry>,
+}
+
+/// A static file of a snapshot.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ManifestEntry {
+    segment: StaticFileSegment,
+    block_range: SegmentRangeInclusive,
+    tx_range: Option<SegmentRangeInclusive>,
+    /// The files that make up the static file.
+    files: Vec<ManifestFile>,
+}
+
+/// A file of a static file of a snapshot.
+#[derive(Debug, Serialize, Deserialize)]
+struct ManifestFile {
+    /// Name of the file inside the archive.
+    name: String,
+    /// SHA-256 checksum of the file.
+    checksum: B256,
+}
+
+impl Manifest {
+    /// Creates the manifest of a snapshot holding the given static files.
+    pub(crate) fn new(
+        chain: &ChainSpec,
+        blocks_per_file: u64,
+        entries: &[StaticFileSnapshotEntry],
+    ) -> Self {
+        Self {
+            chain_id: chain.chain.id(),
+            genesis_hash: chain.genesis_hash(),
+            blocks_per_file,
+            static_files: entries
+                .iter()
+                .map(|entry| ManifestEntry {
+                    segment: entry.segment,
+                    block_range: entry.block_range,
+                    tx_range: entry.tx_range,
+                    files: entry
+                        .files
+                        .iter()
+                        .filter_map(|file| {
+                            Some(ManifestFile {
+                                name: file.path.file_name()?.to_string_lossy().into_owned(),
+                                checksum: file.checksum,
+                            })
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Checks that the snapshot belongs to the given chain.
+    pub(crate) fn validate(&self, chain: &ChainSpec) -> eyre::Result<()> {
+        if self.chain_id != chain.chain.id() || self.genesis_hash != chain.genesis_hash() {
+            eyre::bail!(
+                "snapshot belongs to chain {} with genesis {}, expected chain {} with genesis {}",
+                self.chain_id,
+                self.genesis_hash,
+                chain.chain.id(),
+                chain.genesis_hash()
+            )
+        }
+        Ok(())
+    }
+
+    /// Returns the highest block of `segment` in the snapshot, if it holds any static file of it.
+    pub(crate) fn highest_block(&self, segment: StaticFileSegment) -> Option<BlockNumber> {
+        self.static_files
+            .iter()
+            .filter(|entry| entry.segment == segment)
+            .map(|entry| entry.block_range.end())
+            .max()
+    }
+
+    /// Returns the static files of the snapshot, with their files located in `dir`.
+    pub(crate) fn entries(&self, dir: &Path) -> eyre::Result<Vec<StaticFileSnapshotEntry>> {
+        self.static_files
+            .iter()
+            .map(|entry| {
+                let files = entry
+                    .files
+                    .iter()
+                    .map(|ManifestFile { name, checksum }| {
+                        // Only plain file names, so that files can't be placed outside of `dir`
+                        if Path::new(name)
+                            .file_name()
+                            .map_or(true, |file_name| file_name != name.as_str())
+                        {
+                            eyre::bail!("invalid file name in snapshot manifest: {name}")
+                        }
+                        Ok(StaticFileSnapshotFile { path: dir.join(name), checksum: *checksum })
+                    })
+                    .collect::<eyre::Result<_>>()?;
+
+                Ok(StaticFileSnapshotEntry {
+                    segment: entry.segment,
+                    block_range: entry.block_range,
+                    tx_range: entry.tx_range,
+                    files,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,70 @@
+//! `reth db static-file` command
+//!
+//! Maintenance of the static files of a data directory.
+
+use crate::utils::DbTool;
+use clap::{Parser, Subcommand};
+use reth_db::{mdbx::DatabaseArguments, open_db_read_only, DatabaseEnv};
+use reth_node_core::dirs::{ChainPath, DataDirPath};
+use reth_primitives::ChainSpec;
+use reth_provider::ProviderFactory;
+use std::sync::Arc;
+
+mod compact;
+mod export;
+mod import;
+mod manifest;
+mod verify;
+
+/// `reth db static-file` command
+#[derive(Debug, Parser)]
+pub struct Command {
+    #[command(subcommand)]
+    command: Subcommands,
+}
+
+/// `reth db static-file` subcommands
+#[derive(Subcommand, Debug)]
+pub enum Subcommands {
+    /// Rewrites all static files into densely packed files
+    Compact(compact::Command),
+    /// Packages static files into an archive with a manifest, to bootstrap other nodes
+    Export(export::Command),
+    /// Imports static files from an archive written by `export`
+    Import(import::Command),
+    /// Verifies static files against the checksums recorded when they were completed
+    Verify(verify::Command),
+}
+
+impl Command {
+    /// Execute `db static-file` command
+    pub fn execute(
+        self,
+        data_dir: ChainPath<DataDirPath>,
+        db_args: DatabaseArguments,
+        chain: Arc<ChainSpec>,
+    ) -> eyre::Result<()> {
+        match self.command {
+            Subcommands::Compact(command) => command.execute(data_dir, db_args, chain),
+            Subcommands::Export(command) => {
+                command.execute(&Self::read_only_tool(data_dir, db_args, chain)?)
+            }
+            Subcommands::Import(command) => command.execute(data_dir, db_args, chain),
+            Subcommands::Verify(command) => {
+                command.execute(&Self::read_only_tool(data_dir, db_args, chain)?)
+            }
+        }
+    }
+
+    /// Opens the database read-only, for commands that don't modify the data directory.
+    fn read_only_tool(
+        data_dir: ChainPath<DataDirPath>,
+        db_args: DatabaseArguments,
+        chain: Arc<ChainSpec>,
+    ) -> eyre::Result<DbTool<DatabaseEnv>> {
+        let db = open_db_read_only(&data_dir.db_path(), db_args)?;
+        let provider_factory =
+            ProviderFactory::new(db, chain.clone(), data_dir.static_files_path())?;
+        DbTool::new(provider_factory, chain)
+    }
+}
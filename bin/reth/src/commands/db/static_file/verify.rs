@@ -0,0 +1,52 @@
+use crate::utils::DbTool;
+use clap::Parser;
+use reth_db::database::Database;
+use reth_provider::providers::{StaticFileChecksum, StaticFileChecksumStatus};
+use tracing::{info, warn};
+
+/// The arguments for the `reth db static-file verify` command
+///
+/// A checksum of the data file is recorded in the header of every static file once its block
+/// range is complete. This command recomputes the checksums and reports all static files that
+/// don't match, e.g. because of disk faults or partial copies.
+#[derive(Parser, Debug)]
+pub struct Command;
+
+impl Command {
+    /// Execute `db static-file verify` command
+    pub fn execute<DB: Database>(self, tool: &DbTool<DB>) -> eyre::Result<()> {
+        let static_files = tool.provider_factory.static_file_provider().verify_checksums()?;
+
+        let mut valid = 0;
+        let mut missing = 0;
+        let mut mismatched = Vec::new();
+        for StaticFileChecksum { segment, block_range, status } in static_files {
+            match status {
+                StaticFileChecksumStatus::Valid => valid += 1,
+                StaticFileChecksumStatus::Missing => missing += 1,
+                StaticFileChecksumStatus::Mismatch { expected, got } => {
+                    warn!(target: "reth::cli", %segment, %block_range, ?expected, ?got, "Static file checksum mismatch");
+                    mismatched.push(format!("{segment} {block_range}"));
+                }
+            }
+        }
+
+        info!(
+            target: "reth::cli",
+            valid,
+            missing,
+            mismatched = mismatched.len(),
+            "Finished verifying static files"
+        );
+
+        if !mismatched.is_empty() {
+            eyre::bail!(
+                "{} static files don't match their checksum: {}",
+                mismatched.len(),
+                mismatched.join(", ")
+            )
+        }
+
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use human_bytes::human_bytes;
 use itertools::Itertools;
 use reth_db::{database::Database, mdbx, static_file::iter_static_files, DatabaseEnv, Tables};
 use reth_node_core::dirs::{ChainPath, DataDirPath};
-use reth_primitives::static_file::{find_fixed_range, SegmentRangeInclusive};
+use reth_primitives::static_file::SegmentRangeInclusive;
 use reth_provider::providers::StaticFileProvider;
 
 #[derive(Parser, Debug)]
@@ -166,7 +166,7 @@ impl Command {
             ) = (0, 0, 0, 0, 0, 0);
 
             for (block_range, tx_range) in &ranges {
-                let fixed_block_range = find_fixed_range(block_range.start());
+                let fixed_block_range = static_file_provider.find_fixed_range(block_range.start());
                 let jar_provider = static_file_provider
                     .get_segment_provider(segment, || Some(fixed_block_range), None)?
                     .ok_or_else(|| {
@@ -0,0 +1,86 @@
+//! Command for benchmarking the cryptographic backends on the host.
+
+use clap::Parser;
+use reth_node_core::crypto::{accelerated_cpu_features, KECCAK_BACKEND};
+use reth_primitives::{keccak256, recover_signer_unchecked, sign_message, B256};
+use std::{hint::black_box, time::Instant};
+use tracing::info;
+
+/// `reth debug crypto-bench` command
+///
+/// Measures the throughput of the keccak256 backend reth was built with and of secp256k1
+/// signature recovery, e.g. to compare builds with and without the `asm-keccak` feature on the
+/// same host.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The number of keccak256 hashes of 32 bytes and signature recoveries to run.
+    #[arg(long, default_value_t = 100_000)]
+    iterations: usize,
+
+    /// The number of bytes hashed to measure keccak256 throughput.
+    #[arg(long, default_value_t = 256 * 1024 * 1024)]
+    bytes: usize,
+}
+
+impl Command {
+    /// Execute `debug crypto-bench` command
+    pub fn execute(self) -> eyre::Result<()> {
+        info!(
+            target: "reth::cli",
+            keccak = KECCAK_BACKEND,
+            cpu_features = ?accelerated_cpu_features(),
+            "Benchmarking cryptographic backends"
+        );
+
+        // keccak256 of trie node sized inputs
+        let start = Instant::now();
+        let mut hash = B256::ZERO;
+        for _ in 0..self.iterations {
+            hash = keccak256(black_box(hash));
+        }
+        let elapsed = start.elapsed();
+        info!(
+            target: "reth::cli",
+            backend = KECCAK_BACKEND,
+            hashes_per_sec = self.iterations as f64 / elapsed.as_secs_f64(),
+            ?elapsed,
+            "keccak256 of 32 bytes"
+        );
+
+        // keccak256 throughput of large inputs
+        let data = vec![0xab; self.bytes];
+        let start = Instant::now();
+        black_box(keccak256(black_box(&data)));
+        let elapsed = start.elapsed();
+        info!(
+            target: "reth::cli",
+            backend = KECCAK_BACKEND,
+            mb_per_sec = self.bytes as f64 / (1024.0 * 1024.0) / elapsed.as_secs_f64(),
+            ?elapsed,
+            "keccak256 throughput"
+        );
+
+        // secp256k1 public key recovery, as done by sender recovery
+        let secret = B256::with_last_byte(1);
+        let message = keccak256("reth");
+        let signature = sign_message(secret, message)?;
+        let mut sig = [0u8; 65];
+        sig[..32].copy_from_slice(&signature.r.to_be_bytes::<32>());
+        sig[32..64].copy_from_slice(&signature.s.to_be_bytes::<32>());
+        sig[64] = signature.odd_y_parity as u8;
+
+        let start = Instant::now();
+        for _ in 0..self.iterations {
+            black_box(recover_signer_unchecked(black_box(&sig), &message.0)?);
+        }
+        let elapsed = start.elapsed();
+        info!(
+            target: "reth::cli",
+            recoveries_per_sec = self.iterations as f64 / elapsed.as_secs_f64(),
+            ?elapsed,
+            "secp256k1 signature recovery"
+        );
+
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@
 use crate::core::cli::runner::CliContext;
 use clap::{Parser, Subcommand};
 mod build_block;
+mod crypto_bench;
 mod execution;
 mod in_memory_merkle;
 mod merkle;
@@ -28,6 +29,8 @@ pub enum Subcommands {
     BuildBlock(build_block::Command),
     /// Debug engine API by replaying stored messages.
     ReplayEngine(replay_engine::Command),
+    /// Benchmark keccak hashing and secp256k1 signature recovery on this host.
+    CryptoBench(crypto_bench::Command),
 }
 
 impl Command {
@@ -39,6 +42,7 @@ impl Command {
             Subcommands::InMemoryMerkle(command) => command.execute(ctx).await,
             Subcommands::BuildBlock(command) => command.execute(ctx).await,
             Subcommands::ReplayEngine(command) => command.execute(ctx).await,
+            Subcommands::CryptoBench(command) => command.execute(),
         }
     }
 }
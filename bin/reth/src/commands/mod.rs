@@ -1,6 +1,7 @@
 //! This contains all of the `reth` commands
 
 pub mod config_cmd;
+pub mod datadir;
 pub mod db;
 pub mod debug_cmd;
 pub mod dump_genesis;
@@ -9,6 +10,7 @@ pub mod init_cmd;
 
 pub mod node;
 pub mod p2p;
+pub mod prune;
 pub mod recover;
 pub mod stage;
 pub mod test_vectors;
@@ -3,16 +3,16 @@
 use crate::{
     args::{
         utils::{chain_help, genesis_value_parser, parse_socket_address, SUPPORTED_CHAINS},
-        DatabaseArgs, DebugArgs, DevArgs, NetworkArgs, PayloadBuilderArgs, PruningArgs,
-        RpcServerArgs, TxPoolArgs,
+        DatabaseArgs, DebugArgs, DevArgs, DiskArgs, NetworkArgs, PayloadBuilderArgs, PruningArgs,
+        RpcServerArgs, StaticFilesArgs, ThreadPoolArgs, TxPoolArgs,
     },
     core::cli::runner::CliContext,
     dirs::{DataDirPath, MaybePlatformPath},
 };
 use clap::{value_parser, Args, Parser};
-use reth_db::{init_db, DatabaseEnv};
+use reth_db::{init_db, DatabaseEnv, StorageLock};
 use reth_node_builder::{InitState, NodeBuilder, WithLaunchContext};
-use reth_node_core::{node_config::NodeConfig, version};
+use reth_node_core::{crypto, node_config::NodeConfig, version};
 use reth_primitives::ChainSpec;
 use std::{ffi::OsString, fmt, future::Future, net::SocketAddr, path::PathBuf, sync::Arc};
 
@@ -108,6 +108,18 @@ pub struct NodeCommand<Ext: clap::Args + fmt::Debug = NoArgs> {
     #[command(flatten)]
     pub pruning: PruningArgs,
 
+    /// All thread pool related arguments with --threads prefix
+    #[command(flatten)]
+    pub threads: ThreadPoolArgs,
+
+    /// All static files related arguments with --static-files prefix
+    #[command(flatten)]
+    pub static_files: StaticFilesArgs,
+
+    /// All disk space monitor related arguments with --disk prefix
+    #[command(flatten)]
+    pub disk: DiskArgs,
+
     /// Additional cli arguments
     #[command(flatten, next_help_heading = "Extension")]
     pub ext: Ext,
@@ -140,6 +152,7 @@ impl<Ext: clap::Args + fmt::Debug> NodeCommand<Ext> {
         Fut: Future<Output = eyre::Result<()>>,
     {
         tracing::info!(target: "reth::cli", version = ?version::SHORT_VERSION, "Starting reth");
+        crypto::log_crypto_backends();
 
         let Self {
             datadir,
@@ -156,6 +169,9 @@ impl<Ext: clap::Args + fmt::Debug> NodeCommand<Ext> {
             db,
             dev,
             pruning,
+            threads,
+            static_files,
+            disk,
             ext,
         } = self;
 
@@ -173,6 +189,9 @@ impl<Ext: clap::Args + fmt::Debug> NodeCommand<Ext> {
             db,
             dev,
             pruning,
+            threads,
+            static_files,
+            disk,
         };
 
         // Register the prometheus recorder before creating the database,
@@ -182,6 +201,9 @@ impl<Ext: clap::Args + fmt::Debug> NodeCommand<Ext> {
         let data_dir = datadir.unwrap_or_chain_default(node_config.chain.chain);
         let db_path = data_dir.db_path();
 
+        // held until the node exits, so offline tools refuse to modify the data directory
+        let _storage_lock = StorageLock::try_acquire(&data_dir.data_dir_path())?;
+
         tracing::info!(target: "reth::cli", path = ?db_path, "Opening database");
         let database = Arc::new(init_db(db_path.clone(), self.db.database_args())?.with_metrics());
 
@@ -359,6 +381,19 @@ mod tests {
         assert_eq!(cmd.network.port, 30305);
     }
 
+    #[test]
+    fn parse_thread_pool_args() {
+        let cmd = NodeCommand::<NoArgs>::parse_from([
+            "reth",
+            "--threads.rayon",
+            "8",
+            "--threads.blocking",
+            "32",
+        ]);
+        assert_eq!(cmd.threads.rayon_threads(), 8);
+        assert_eq!(cmd.threads.max_blocking_threads, Some(32));
+    }
+
     #[test]
     fn parse_with_unused_ports() {
         let cmd = NodeCommand::<NoArgs>::parse_from(["reth", "--with-unused-ports"]);
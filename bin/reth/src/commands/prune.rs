@@ -0,0 +1,201 @@
+//! Command that runs the pruner once against the database.
+
+use crate::{
+    args::{
+        utils::{chain_help, genesis_value_parser, SUPPORTED_CHAINS},
+        DatabaseArgs,
+    },
+    dirs::{DataDirPath, MaybePlatformPath},
+};
+use clap::{
+    builder::{PossibleValuesParser, TypedValueParser},
+    Parser, ValueEnum,
+};
+use reth_db::{init_db, StorageLock};
+use reth_primitives::{BlockNumber, ChainSpec, PruneMode, PruneModes, PruneSegment};
+use reth_provider::{BlockNumReader, ProviderFactory};
+use reth_prune::{PrunerBuilder, PrunerEvent};
+use std::{collections::BTreeMap, fs, sync::Arc, time::Instant};
+use tracing::*;
+
+/// `reth prune` command
+#[derive(Debug, Parser)]
+pub struct PruneCommand {
+    /// The path to the data dir for all reth files and subdirectories.
+    ///
+    /// Defaults to the OS-specific data directory:
+    ///
+    /// - Linux: `$XDG_DATA_HOME/reth/` or `$HOME/.local/share/reth/`
+    /// - Windows: `{FOLDERID_RoamingAppData}/reth/`
+    /// - macOS: `$HOME/Library/Application Support/reth/`
+    #[arg(long, value_name = "DATA_DIR", verbatim_doc_comment, default_value_t)]
+    datadir: MaybePlatformPath<DataDirPath>,
+
+    /// The chain this node is running.
+    ///
+    /// Possible values are either a built-in chain or the path to a chain specification file.
+    #[arg(
+        long,
+        value_name = "CHAIN_OR_PATH",
+        long_help = chain_help(),
+        default_value = SUPPORTED_CHAINS[0],
+        value_parser = genesis_value_parser
+    )]
+    chain: Arc<ChainSpec>,
+
+    #[command(flatten)]
+    db: DatabaseArgs,
+
+    /// Comma separated list of segments to prune.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        required = true,
+        value_parser = PossibleValuesParser::new(
+            PRUNABLE_SEGMENTS.map(|segment| segment.to_possible_value().expect("not skipped"))
+        )
+        .map(|segment| PruneSegment::from_str(&segment, false).expect("possible value"))
+    )]
+    segments: Vec<PruneSegment>,
+
+    /// Prune all data of the segments below this block.
+    #[arg(long, value_name = "BLOCK")]
+    before: BlockNumber,
+
+    /// Maximum number of entries that are deleted in a single database transaction.
+    #[arg(long, default_value_t = 100_000)]
+    batch_size: usize,
+}
+
+/// The segments that can be pruned with the `reth prune` command.
+///
+/// Contract logs are pruned by address filters, and headers and transactions only by moving them
+/// to static files, so they can't be pruned below a block.
+const PRUNABLE_SEGMENTS: [PruneSegment; 5] = [
+    PruneSegment::SenderRecovery,
+    PruneSegment::TransactionLookup,
+    PruneSegment::Receipts,
+    PruneSegment::AccountHistory,
+    PruneSegment::StorageHistory,
+];
+
+impl PruneCommand {
+    /// Execute `prune` command
+    pub async fn execute(self) -> eyre::Result<()> {
+        let data_dir = self.datadir.unwrap_or_chain_default(self.chain.chain);
+        // The pruner writes to the database, which must not happen while a node is running on it
+        let _lock = StorageLock::try_acquire(&data_dir.data_dir_path())?;
+        let db_path = data_dir.db_path();
+        fs::create_dir_all(&db_path)?;
+        let db = Arc::new(init_db(db_path, self.db.database_args())?);
+        let provider_factory =
+            ProviderFactory::new(db, self.chain.clone(), data_dir.static_files_path())?;
+
+        let tip = provider_factory.provider()?.best_block_number()?;
+        let segments = self.prune_modes();
+        info!(target: "reth::cli", tip, before = self.before, segments = ?self.segments, "Starting pruner");
+
+        let start = Instant::now();
+        let mut total_pruned = BTreeMap::new();
+        loop {
+            // The pruner scales the delete limit by the number of blocks since its previous run,
+            // so a fresh pruner is created for every batch. There's no need for a timeout, as
+            // nothing else is waiting on the database.
+            let mut pruner = PrunerBuilder { timeout: None, ..Default::default() }
+                .segments(segments.clone())
+                .prune_delete_limit(self.batch_size)
+                .build(provider_factory.clone());
+            let mut events = pruner.events().into_inner();
+            let progress = pruner.run(tip)?;
+
+            while let Ok(event) = events.try_recv() {
+                if let PrunerEvent::Finished { stats, .. } = event {
+                    for (segment, (_, pruned)) in stats {
+                        *total_pruned.entry(segment).or_insert(0) += pruned;
+                    }
+                }
+            }
+            info!(target: "reth::cli", pruned = ?total_pruned, elapsed = ?start.elapsed(), "Pruned batch");
+
+            if progress.is_finished() {
+                break
+            }
+        }
+
+        info!(target: "reth::cli", pruned = ?total_pruned, elapsed = ?start.elapsed(), "Pruning finished");
+
+        Ok(())
+    }
+
+    /// Returns the [PruneModes] that prune the configured segments below the configured block.
+    fn prune_modes(&self) -> PruneModes {
+        let mode = Some(PruneMode::Before(self.before));
+        let mut modes = PruneModes::none();
+        for segment in &self.segments {
+            match segment {
+                PruneSegment::SenderRecovery => modes.sender_recovery = mode,
+                PruneSegment::TransactionLookup => modes.transaction_lookup = mode,
+                PruneSegment::Receipts => modes.receipts = mode,
+                PruneSegment::AccountHistory => modes.account_history = mode,
+                PruneSegment::StorageHistory => modes.storage_history = mode,
+                PruneSegment::ContractLogs | PruneSegment::Headers | PruneSegment::Transactions => {
+                    unreachable!("rejected by the parser")
+                }
+            }
+        }
+        modes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_segments() {
+        let cmd = PruneCommand::parse_from([
+            "reth",
+            "--segments",
+            "receipts,txlookup",
+            "--before",
+            "1000",
+        ]);
+        assert_eq!(cmd.segments, vec![PruneSegment::Receipts, PruneSegment::TransactionLookup]);
+
+        let modes = cmd.prune_modes();
+        assert_eq!(modes.receipts, Some(PruneMode::Before(1000)));
+        assert_eq!(modes.transaction_lookup, Some(PruneMode::Before(1000)));
+        assert_eq!(modes.sender_recovery, None);
+
+        // segments that can't be pruned below a block are rejected
+        for segment in ["contract-logs", "headers", "transactions"] {
+            assert!(PruneCommand::try_parse_from([
+                "reth",
+                "--segments",
+                segment,
+                "--before",
+                "1000"
+            ])
+            .is_err());
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn prune_fails_while_node_is_running() {
+        let dir = tempfile::tempdir().unwrap();
+        let _lock = StorageLock::try_acquire(dir.path()).unwrap();
+
+        let cmd = PruneCommand::parse_from([
+            "reth",
+            "--datadir",
+            dir.path().to_str().unwrap(),
+            "--segments",
+            "receipts",
+            "--before",
+            "1000",
+        ]);
+        assert!(cmd.execute().await.is_err());
+        assert!(!dir.path().join("db").exists());
+    }
+}
@@ -12,9 +12,7 @@ use clap::Parser;
 use itertools::Itertools;
 use reth_db::{open_db, static_file::iter_static_files, tables, transaction::DbTxMut, DatabaseEnv};
 use reth_node_core::init::{insert_genesis_header, insert_genesis_history, insert_genesis_state};
-use reth_primitives::{
-    fs, stage::StageId, static_file::find_fixed_range, ChainSpec, StaticFileSegment,
-};
+use reth_primitives::{fs, stage::StageId, ChainSpec, StaticFileSegment};
 use reth_provider::{providers::StaticFileWriter, ProviderFactory};
 use std::sync::Arc;
 
@@ -82,8 +80,10 @@ impl Command {
                     .sorted_by_key(|(block_range, _)| block_range.start())
                     .rev()
                 {
-                    static_file_provider
-                        .delete_jar(static_file_segment, find_fixed_range(block_range.start()))?;
+                    static_file_provider.delete_jar(
+                        static_file_segment,
+                        static_file_provider.find_fixed_range(block_range.start()),
+                    )?;
                 }
             }
         }
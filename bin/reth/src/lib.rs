@@ -15,6 +15,8 @@
 //!   calls to the logging component is made.
 //! - `min-debug-logs`: Disables all logs below `debug` level.
 //! - `min-trace-logs`: Disables all logs below `trace` level.
+//! - `disable-p2p`: Never starts the devp2p networking stack, as if `--disable-p2p` was always
+//!   passed. Useful for nodes that are driven exclusively via the engine API.
 //! - `optimism`: Enables [OP-Stack](https://stack.optimism.io/) support for the node. Note that
 //!   this breaks compatibility with the Ethereum mainnet as a new deposit transaction type is
 //!   introduced as well as gas cost changes.
@@ -360,9 +360,14 @@ where
 
         let provider = self.externals.provider_factory.provider()?;
 
+        let parent_header = self
+            .externals
+            .canonical_header_by_hash(&provider, block.parent_hash)?
+            .ok_or_else(|| BlockchainTreeError::CanonicalChain { block_hash: block.parent_hash })?;
+
         // Validate that the block is post merge
         let parent_td = provider
-            .header_td(&block.parent_hash)?
+            .header_td_by_number(parent_header.number)?
             .ok_or_else(|| BlockchainTreeError::CanonicalChain { block_hash: block.parent_hash })?;
 
         // Pass the parent total difficulty to short-circuit unnecessary calculations.
@@ -379,11 +384,6 @@ where
             .into())
         }
 
-        let parent_header = provider
-            .header(&block.parent_hash)?
-            .ok_or_else(|| BlockchainTreeError::CanonicalChain { block_hash: block.parent_hash })?
-            .seal(block.parent_hash);
-
         let canonical_chain = self.canonical_chain();
 
         let block_attachment = if block.parent_hash == canonical_chain.tip().hash {
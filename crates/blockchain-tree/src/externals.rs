@@ -3,9 +3,11 @@
 use reth_db::{
     cursor::DbCursorRO, database::Database, static_file::HeaderMask, tables, transaction::DbTx,
 };
-use reth_interfaces::{consensus::Consensus, RethResult};
-use reth_primitives::{BlockHash, BlockNumber, StaticFileSegment};
-use reth_provider::{ProviderFactory, StatsReader};
+use reth_interfaces::{consensus::Consensus, provider::ProviderResult, RethResult};
+use reth_primitives::{BlockHash, BlockNumber, SealedHeader, StaticFileSegment};
+use reth_provider::{
+    BlockNumReader, DatabaseProviderRO, HeaderProvider, ProviderFactory, StatsReader,
+};
 use std::{collections::BTreeMap, sync::Arc};
 
 /// A container for external components.
@@ -39,6 +41,32 @@ impl<DB, EVM> TreeExternals<DB, EVM> {
 }
 
 impl<DB: Database, EVM> TreeExternals<DB, EVM> {
+    /// Returns the canonical header with the given hash, which is validated against by the
+    /// consensus engine when a block extends the canonical chain.
+    ///
+    /// The block number is looked up in the canonical header cache of the provider factory, which
+    /// is shared with the blockchain provider. Since the cache is only updated once a new
+    /// canonical head is announced, the hash of the header is verified.
+    pub(crate) fn canonical_header_by_hash(
+        &self,
+        provider: &DatabaseProviderRO<DB>,
+        hash: BlockHash,
+    ) -> ProviderResult<Option<SealedHeader>> {
+        let number = self
+            .provider_factory
+            .header_cache()
+            .number_or_fetch(hash, |hash| provider.block_number(hash))?;
+        if let Some(number) = number {
+            if let Some(header) = provider.sealed_header(number)? {
+                if header.hash() == hash {
+                    return Ok(Some(header))
+                }
+            }
+        }
+
+        Ok(provider.header(&hash)?.map(|header| header.seal(hash)))
+    }
+
     /// Fetches the latest canonical block hashes by walking backwards from the head.
     ///
     /// Returns the hashes sorted by increasing block numbers
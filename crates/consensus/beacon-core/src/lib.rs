@@ -11,10 +11,34 @@
 use reth_consensus_common::validation;
 use reth_interfaces::consensus::{Consensus, ConsensusError};
 use reth_primitives::{
-    constants::MAXIMUM_EXTRA_DATA_SIZE, Chain, ChainSpec, Hardfork, Header, SealedBlock,
-    SealedHeader, EMPTY_OMMER_ROOT_HASH, U256,
+    constants::{ALLOWED_FUTURE_BLOCK_TIME_SECONDS, MAXIMUM_EXTRA_DATA_SIZE},
+    Chain, ChainSpec, Hardfork, Header, SealedBlock, SealedHeader, EMPTY_OMMER_ROOT_HASH, U256,
 };
 use std::{sync::Arc, time::SystemTime};
+
+pub use reth_consensus_common::validation::HeaderChecks;
+
+/// Validation profile of [BeaconConsensus], selecting the header checks that apply to the chain.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConsensusMode {
+    /// Validates headers according to the Ethereum rules.
+    #[default]
+    Ethereum,
+    /// Validates headers as laid out by Hyperliquid, where the withdrawals root and the blob
+    /// fields are zero-filled regardless of the active hardforks and carry no information.
+    Hyperliquid,
+}
+
+impl ConsensusMode {
+    /// Returns the header checks performed in this mode.
+    pub const fn header_checks(&self) -> HeaderChecks {
+        match self {
+            Self::Ethereum => HeaderChecks::all(),
+            Self::Hyperliquid => HeaderChecks { withdrawals_root: false, blob_fields: false },
+        }
+    }
+}
+
 /// Ethereum beacon consensus
 ///
 /// This consensus engine does basic checks as outlined in the execution specs.
@@ -22,18 +46,60 @@ use std::{sync::Arc, time::SystemTime};
 pub struct BeaconConsensus {
     /// Configuration
     chain_spec: Arc<ChainSpec>,
+    /// Header checks that depend on the header layout of the chain.
+    header_checks: HeaderChecks,
+    /// How far pre-merge block timestamps may be ahead of the local clock, in seconds. If
+    /// `None`, block timestamps aren't checked against the local clock.
+    allowed_future_block_time: Option<u64>,
 }
 
 impl BeaconConsensus {
     /// Create a new instance of [BeaconConsensus]
     pub fn new(chain_spec: Arc<ChainSpec>) -> Self {
-        Self { chain_spec }
+        Self {
+            chain_spec,
+            header_checks: HeaderChecks::all(),
+            allowed_future_block_time: Some(ALLOWED_FUTURE_BLOCK_TIME_SECONDS),
+        }
+    }
+
+    /// Sets the validation profile, replacing any previously configured header checks.
+    pub fn with_mode(mut self, mode: ConsensusMode) -> Self {
+        self.header_checks = mode.header_checks();
+        self
+    }
+
+    /// Sets the header checks, replacing all checks of a previously configured [ConsensusMode].
+    ///
+    /// To change a single check of a mode, start from [ConsensusMode::header_checks].
+    pub fn with_header_checks(mut self, header_checks: HeaderChecks) -> Self {
+        self.header_checks = header_checks;
+        self
+    }
+
+    /// Sets how far pre-merge block timestamps may be ahead of the local clock, in seconds.
+    ///
+    /// Defaults to [ALLOWED_FUTURE_BLOCK_TIME_SECONDS].
+    pub fn with_allowed_future_block_time(mut self, seconds: u64) -> Self {
+        self.allowed_future_block_time = Some(seconds);
+        self
+    }
+
+    /// Disables the check of pre-merge block timestamps against the local clock, for
+    /// deterministic replay of blocks.
+    pub fn without_future_block_time_check(mut self) -> Self {
+        self.allowed_future_block_time = None;
+        self
     }
 }
 
 impl Consensus for BeaconConsensus {
     fn validate_header(&self, header: &SealedHeader) -> Result<(), ConsensusError> {
-        validation::validate_header_standalone(header, &self.chain_spec)?;
+        validation::validate_header_standalone_with_checks(
+            header,
+            &self.chain_spec,
+            self.header_checks,
+        )?;
         Ok(())
     }
 
@@ -42,8 +108,12 @@ impl Consensus for BeaconConsensus {
         header: &SealedHeader,
         parent: &SealedHeader,
     ) -> Result<(), ConsensusError> {
-        header.validate_against_parent(parent, &self.chain_spec).map_err(ConsensusError::from)?;
-        Ok(())
+        validation::validate_header_against_parent_with_checks(
+            header,
+            parent,
+            &self.chain_spec,
+            self.header_checks,
+        )
     }
 
     fn validate_header_with_total_difficulty(
@@ -84,14 +154,16 @@ impl Consensus for BeaconConsensus {
             // low priority as syncing is done in reverse order
 
             // Check if timestamp is in the future. Clock can drift but this can be consensus issue.
-            let present_timestamp =
-                SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_secs();
-
-            if header.exceeds_allowed_future_timestamp(present_timestamp) {
-                return Err(ConsensusError::TimestampIsInFuture {
-                    timestamp: header.timestamp,
-                    present_timestamp,
-                })
+            if let Some(allowed_future_block_time) = self.allowed_future_block_time {
+                let present_timestamp =
+                    SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_secs();
+
+                if header.timestamp > present_timestamp.saturating_add(allowed_future_block_time) {
+                    return Err(ConsensusError::TimestampIsInFuture {
+                        timestamp: header.timestamp,
+                        present_timestamp,
+                    })
+                }
             }
 
             // Goerli exception:
@@ -106,7 +178,11 @@ impl Consensus for BeaconConsensus {
     }
 
     fn validate_block(&self, block: &SealedBlock) -> Result<(), ConsensusError> {
-        validation::validate_block_standalone(block, &self.chain_spec)
+        validation::validate_block_standalone_with_checks(
+            block,
+            &self.chain_spec,
+            self.header_checks,
+        )
     }
 }
 
@@ -121,3 +197,113 @@ fn validate_header_extradata(header: &Header) -> Result<(), ConsensusError> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{ChainSpecBuilder, HeaderValidationError};
+
+    /// Returns a parent and a child header after cancun, where the child has no blob gas fields.
+    fn headers_without_blob_fields() -> (SealedHeader, SealedHeader) {
+        let parent = Header {
+            number: 1,
+            timestamp: 1_000,
+            gas_limit: 30_000_000,
+            gas_used: 15_000_000,
+            base_fee_per_gas: Some(1_000_000_000),
+            ..Default::default()
+        }
+        .seal_slow();
+        let child = Header {
+            number: 2,
+            parent_hash: parent.hash(),
+            timestamp: 1_012,
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(1_000_000_000),
+            ..Default::default()
+        }
+        .seal_slow();
+        (parent, child)
+    }
+
+    fn consensus() -> BeaconConsensus {
+        BeaconConsensus::new(Arc::new(ChainSpecBuilder::mainnet().cancun_activated().build()))
+    }
+
+    #[test]
+    fn ethereum_mode_validates_blob_fields_against_parent() {
+        let (parent, child) = headers_without_blob_fields();
+        assert_eq!(
+            consensus()
+                .with_mode(ConsensusMode::Ethereum)
+                .validate_header_against_parent(&child, &parent),
+            Err(ConsensusError::HeaderValidationError(HeaderValidationError::BlobGasUsedMissing))
+        );
+    }
+
+    #[test]
+    fn hyperliquid_mode_skips_blob_fields_against_parent() {
+        let (parent, child) = headers_without_blob_fields();
+        let consensus = consensus().with_mode(ConsensusMode::Hyperliquid);
+        assert_eq!(consensus.validate_header_against_parent(&child, &parent), Ok(()));
+
+        // The remaining checks against the parent still apply
+        let unrelated = Header { number: 3, ..child.unseal() }.seal_slow();
+        assert!(consensus.validate_header_against_parent(&unrelated, &parent).is_err());
+    }
+
+    #[test]
+    fn header_checks_replace_mode_checks() {
+        let (parent, child) = headers_without_blob_fields();
+        let hyperliquid = ConsensusMode::Hyperliquid.header_checks();
+
+        let consensus = consensus()
+            .with_mode(ConsensusMode::Hyperliquid)
+            .with_header_checks(HeaderChecks { blob_fields: true, ..hyperliquid });
+        assert!(consensus.validate_header_against_parent(&child, &parent).is_err());
+
+        let consensus =
+            consensus().with_mode(ConsensusMode::Ethereum).with_header_checks(HeaderChecks::none());
+        assert_eq!(consensus.validate_header_against_parent(&child, &parent), Ok(()));
+    }
+
+    #[test]
+    fn future_block_time_check_is_configurable() {
+        let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_secs();
+        // pre-merge header, since the timestamps of post-merge headers aren't checked
+        let header =
+            |timestamp| Header { timestamp, difficulty: U256::from(1), ..Default::default() };
+        let consensus = || BeaconConsensus::new(Arc::new(ChainSpecBuilder::mainnet().build()));
+
+        let ahead = header(now + 100);
+        assert!(matches!(
+            consensus().validate_header_with_total_difficulty(&ahead, U256::ZERO),
+            Err(ConsensusError::TimestampIsInFuture { timestamp, .. }) if timestamp == now + 100
+        ));
+        assert_eq!(
+            consensus()
+                .with_allowed_future_block_time(1_000)
+                .validate_header_with_total_difficulty(&ahead, U256::ZERO),
+            Ok(())
+        );
+        assert!(consensus()
+            .with_allowed_future_block_time(0)
+            .validate_header_with_total_difficulty(&header(now + 10), U256::ZERO)
+            .is_err());
+
+        // the threshold doesn't overflow
+        let far_ahead = header(u64::MAX);
+        assert_eq!(
+            consensus()
+                .with_allowed_future_block_time(u64::MAX)
+                .validate_header_with_total_difficulty(&far_ahead, U256::ZERO),
+            Ok(())
+        );
+        assert_eq!(
+            consensus()
+                .without_future_block_time_check()
+                .validate_header_with_total_difficulty(&far_ahead, U256::ZERO),
+            Ok(())
+        );
+    }
+}
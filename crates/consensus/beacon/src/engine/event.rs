@@ -16,6 +16,15 @@ pub enum BeaconConsensusEngineEvent {
     LiveSyncProgress(ConsensusEngineLiveSyncProgress),
     /// A block was added to the fork chain.
     ForkBlockAdded(Arc<SealedBlock>),
+    /// A block was rejected because it failed consensus or execution validation.
+    InvalidBlock {
+        /// The rejected block.
+        block: Arc<SealedBlock>,
+        /// The reason the block was rejected.
+        reason: String,
+        /// The hash of the transaction that caused the block to be rejected, if known.
+        offending_transaction: Option<B256>,
+    },
 }
 
 /// Progress of the consensus engine during live sync.
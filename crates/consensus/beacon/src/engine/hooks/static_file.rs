@@ -23,6 +23,9 @@ pub struct StaticFileHook<DB> {
     state: StaticFileProducerState<DB>,
     /// The type that can spawn the static_file_producer task.
     task_spawner: Box<dyn TaskSpawner>,
+    /// If set, blocks that are at least this many blocks behind the tip are moved to static files
+    /// even if they're not finalized yet.
+    tip_distance: Option<u64>,
 }
 
 impl<DB: Database + 'static> StaticFileHook<DB> {
@@ -31,7 +34,20 @@ impl<DB: Database + 'static> StaticFileHook<DB> {
         static_file_producer: StaticFileProducer<DB>,
         task_spawner: Box<dyn TaskSpawner>,
     ) -> Self {
-        Self { state: StaticFileProducerState::Idle(Some(static_file_producer)), task_spawner }
+        Self {
+            state: StaticFileProducerState::Idle(Some(static_file_producer)),
+            task_spawner,
+            tip_distance: None,
+        }
+    }
+
+    /// Also moves blocks that are at least `tip_distance` blocks behind the tip to static files,
+    /// instead of only finalized blocks.
+    ///
+    /// This keeps the database small on nodes that don't receive finalized blocks.
+    pub fn with_tip_distance(mut self, tip_distance: Option<u64>) -> Self {
+        self.tip_distance = tip_distance;
+        self
     }
 
     /// Advances the static_file_producer state.
@@ -82,7 +98,7 @@ impl<DB: Database + 'static> StaticFileHook<DB> {
     /// If static_file_producer is already running, do nothing.
     fn try_spawn_static_file_producer(
         &mut self,
-        finalized_block_number: BlockNumber,
+        target_block_number: BlockNumber,
     ) -> RethResult<Option<EngineHookEvent>> {
         Ok(match &mut self.state {
             StaticFileProducerState::Idle(static_file_producer) => {
@@ -99,9 +115,9 @@ impl<DB: Database + 'static> StaticFileHook<DB> {
 
                 let targets =
                     locked_static_file_producer.get_static_file_targets(HighestStaticFiles {
-                        headers: Some(finalized_block_number),
-                        receipts: Some(finalized_block_number),
-                        transactions: Some(finalized_block_number),
+                        headers: Some(target_block_number),
+                        receipts: Some(target_block_number),
+                        transactions: Some(target_block_number),
                     })?;
 
                 // Check if the moving data to static files has been requested.
@@ -137,13 +153,18 @@ impl<DB: Database + 'static> EngineHook for StaticFileHook<DB> {
         cx: &mut Context<'_>,
         ctx: EngineContext,
     ) -> Poll<RethResult<EngineHookEvent>> {
-        let Some(finalized_block_number) = ctx.finalized_block_number else {
+        // Move blocks up to the finalized block, or up to the configured distance from the tip if
+        // it's further ahead.
+        let target_block_number = ctx
+            .finalized_block_number
+            .max(self.tip_distance.and_then(|distance| ctx.tip_block_number.checked_sub(distance)));
+        let Some(target_block_number) = target_block_number else {
             trace!(target: "consensus::engine::hooks::static_file", ?ctx, "Finalized block number is not available");
             return Poll::Pending
         };
 
         // Try to spawn a static_file_producer
-        match self.try_spawn_static_file_producer(finalized_block_number)? {
+        match self.try_spawn_static_file_producer(target_block_number)? {
             Some(EngineHookEvent::NotReady) => return Poll::Pending,
             Some(event) => return Poll::Ready(Ok(event)),
             None => (),
@@ -0,0 +1,228 @@
+//! A mock consensus client that drives the engine from a list of fixtures.
+
+use crate::{BeaconConsensusEngineHandle, BeaconForkChoiceUpdateError, BeaconOnNewPayloadError};
+use reth_node_api::EngineTypes;
+use reth_rpc_types::engine::{
+    CancunPayloadFields, ExecutionPayload, ForkchoiceState, PayloadStatus, PayloadStatusEnum,
+};
+use tracing::trace;
+
+/// The status the engine is expected to respond with to a [MockClStep].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpectedStatus {
+    /// The payload or forkchoice state is valid.
+    Valid,
+    /// The payload or forkchoice state is invalid.
+    Invalid,
+    /// The engine is syncing.
+    Syncing,
+    /// The payload was accepted, but not validated yet.
+    Accepted,
+    /// Any status is accepted.
+    Any,
+}
+
+impl ExpectedStatus {
+    /// Returns true if the given status matches the expectation.
+    pub fn matches(&self, status: &PayloadStatusEnum) -> bool {
+        matches!(
+            (self, status),
+            (Self::Any, _) |
+                (Self::Valid, PayloadStatusEnum::Valid) |
+                (Self::Invalid, PayloadStatusEnum::Invalid { .. }) |
+                (Self::Syncing, PayloadStatusEnum::Syncing) |
+                (Self::Accepted, PayloadStatusEnum::Accepted)
+        )
+    }
+}
+
+/// A single message the [MockConsensusClient] sends to the engine.
+#[derive(Debug, Clone)]
+pub enum MockClStep {
+    /// Send an `engine_newPayload` message.
+    NewPayload {
+        /// The payload to send.
+        payload: ExecutionPayload,
+        /// The cancun fields of the payload, if any.
+        cancun_fields: Option<CancunPayloadFields>,
+        /// The status the engine is expected to respond with.
+        expected: ExpectedStatus,
+    },
+    /// Send an `engine_forkchoiceUpdated` message without payload attributes.
+    ForkchoiceUpdated {
+        /// The forkchoice state to send.
+        state: ForkchoiceState,
+        /// The status the engine is expected to respond with.
+        expected: ExpectedStatus,
+    },
+}
+
+impl MockClStep {
+    /// Creates a new payload step without cancun fields.
+    pub fn new_payload(payload: impl Into<ExecutionPayload>, expected: ExpectedStatus) -> Self {
+        Self::NewPayload { payload: payload.into(), cancun_fields: None, expected }
+    }
+
+    /// Creates a forkchoice update step.
+    pub fn forkchoice_updated(state: ForkchoiceState, expected: ExpectedStatus) -> Self {
+        Self::ForkchoiceUpdated { state, expected }
+    }
+}
+
+/// Errors returned by the [MockConsensusClient].
+#[derive(Debug, thiserror::Error)]
+pub enum MockClError {
+    /// The engine failed to process a new payload message.
+    #[error("step {step}: {error}")]
+    NewPayload {
+        /// The index of the failed step.
+        step: usize,
+        /// The error returned by the engine.
+        #[source]
+        error: BeaconOnNewPayloadError,
+    },
+    /// The engine failed to process a forkchoice update.
+    #[error("step {step}: {error}")]
+    ForkchoiceUpdated {
+        /// The index of the failed step.
+        step: usize,
+        /// The error returned by the engine.
+        #[source]
+        error: BeaconForkChoiceUpdateError,
+    },
+    /// The engine responded with an unexpected status.
+    #[error("step {step}: expected {expected:?} status, got {status:?}")]
+    UnexpectedStatus {
+        /// The index of the failed step.
+        step: usize,
+        /// The expected status.
+        expected: ExpectedStatus,
+        /// The status returned by the engine.
+        status: PayloadStatus,
+    },
+}
+
+/// A consensus client that replays [MockClStep] fixtures against a running consensus engine.
+///
+/// This exercises the same engine paths as a real consensus client, so node level tests can
+/// cover payload validation and forkchoice handling, including invalid payloads, without an
+/// external consensus layer.
+#[derive(Debug)]
+pub struct MockConsensusClient<Engine: EngineTypes> {
+    /// The handle to the consensus engine.
+    handle: BeaconConsensusEngineHandle<Engine>,
+    /// The steps to replay, in order.
+    steps: Vec<MockClStep>,
+    /// Whether steps answered with `SYNCING` are resent until the engine responds otherwise.
+    retry_on_syncing: bool,
+}
+
+impl<Engine: EngineTypes> MockConsensusClient<Engine> {
+    /// Creates a new [MockConsensusClient] that replays the given steps.
+    pub fn new(handle: BeaconConsensusEngineHandle<Engine>, steps: Vec<MockClStep>) -> Self {
+        Self { handle, steps, retry_on_syncing: false }
+    }
+
+    /// Resend steps that the engine answered with `SYNCING`, unless `SYNCING` is expected.
+    pub fn with_retry_on_syncing(mut self) -> Self {
+        self.retry_on_syncing = true;
+        self
+    }
+
+    /// Sends all steps to the engine in order and returns the received statuses.
+    ///
+    /// Returns an error on the first step the engine fails to process or responds to with an
+    /// unexpected status.
+    pub async fn run(self) -> Result<Vec<PayloadStatus>, MockClError> {
+        let mut statuses = Vec::with_capacity(self.steps.len());
+        for (step, message) in self.steps.into_iter().enumerate() {
+            let (status, expected) = loop {
+                let (status, expected) = match &message {
+                    MockClStep::NewPayload { payload, cancun_fields, expected } => {
+                        let status = self
+                            .handle
+                            .new_payload(payload.clone(), cancun_fields.clone())
+                            .await
+                            .map_err(|error| MockClError::NewPayload { step, error })?;
+                        (status, *expected)
+                    }
+                    MockClStep::ForkchoiceUpdated { state, expected } => {
+                        let updated = self
+                            .handle
+                            .fork_choice_updated(*state, None)
+                            .await
+                            .map_err(|error| MockClError::ForkchoiceUpdated { step, error })?;
+                        (updated.payload_status, *expected)
+                    }
+                };
+
+                if self.retry_on_syncing &&
+                    status.is_syncing() &&
+                    expected != ExpectedStatus::Syncing
+                {
+                    trace!(target: "consensus::engine::mock_cl", step, "Engine is syncing, retrying");
+                    continue
+                }
+                break (status, expected)
+            };
+
+            if !expected.matches(&status.status) {
+                return Err(MockClError::UnexpectedStatus { step, expected, status })
+            }
+            statuses.push(status);
+        }
+        Ok(statuses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{spawn_consensus_engine, TestConsensusEngineBuilder};
+    use assert_matches::assert_matches;
+    use reth_interfaces::test_utils::generators::{self, random_block};
+    use reth_primitives::{stage::StageCheckpoint, ChainSpecBuilder, MAINNET};
+    use reth_rpc_types_compat::engine::payload::try_block_to_payload_v1;
+    use reth_stages::ExecOutput;
+    use std::{collections::VecDeque, sync::Arc};
+
+    #[tokio::test]
+    async fn replays_fixtures() {
+        let mut rng = generators::rng();
+        let chain_spec = Arc::new(
+            ChainSpecBuilder::default()
+                .chain(MAINNET.chain)
+                .genesis(MAINNET.genesis.clone())
+                .paris_activated()
+                .build(),
+        );
+
+        let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec)
+            .with_pipeline_exec_outputs(VecDeque::from([Ok(ExecOutput {
+                checkpoint: StageCheckpoint::new(0),
+                done: true,
+            })]))
+            .build();
+        let _engine_rx = spawn_consensus_engine(consensus_engine);
+
+        let genesis = random_block(&mut rng, 0, None, None, Some(0));
+        let block1 = random_block(&mut rng, 1, None, None, Some(0));
+
+        // a genesis payload is invalid and an unknown parent makes the engine sync
+        let steps = vec![
+            MockClStep::new_payload(try_block_to_payload_v1(genesis), ExpectedStatus::Invalid),
+            MockClStep::new_payload(
+                try_block_to_payload_v1(block1.clone()),
+                ExpectedStatus::Syncing,
+            ),
+        ];
+        let statuses =
+            MockConsensusClient::new(env.engine_handle().clone(), steps).run().await.unwrap();
+        assert_eq!(statuses.len(), 2);
+
+        let steps =
+            vec![MockClStep::new_payload(try_block_to_payload_v1(block1), ExpectedStatus::Valid)];
+        let res = MockConsensusClient::new(env.engine_handle().clone(), steps).run().await;
+        assert_matches!(res, Err(MockClError::UnexpectedStatus { step: 0, .. }));
+    }
+}
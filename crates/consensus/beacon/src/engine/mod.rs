@@ -45,7 +45,7 @@ use std::{
 use tokio::sync::{
     mpsc,
     mpsc::{UnboundedReceiver, UnboundedSender},
-    oneshot,
+    oneshot, watch,
 };
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use tracing::*;
@@ -85,6 +85,10 @@ pub mod hooks;
 #[cfg(test)]
 pub mod test_utils;
 
+/// A mock consensus client for driving the engine from fixtures in tests.
+#[cfg(any(test, feature = "test-utils"))]
+pub mod mock_cl;
+
 /// The maximum number of invalid headers that can be tracked by the engine.
 const MAX_INVALID_HEADERS: u32 = 512u32;
 
@@ -326,6 +330,16 @@ where
         Ok((this, handle))
     }
 
+    /// Don't insert new blocks while the signal is `true`, e.g. while the disk is about to run
+    /// full.
+    ///
+    /// While paused, forkchoice updates are answered with `SYNCING`, new payloads are only
+    /// buffered and no pipeline runs are started. Engine hooks like the pruner keep running.
+    pub fn with_ingest_pause_signal(mut self, pause_signal: watch::Receiver<bool>) -> Self {
+        self.sync.set_pause_signal(pause_signal);
+        self
+    }
+
     /// Called to resolve chain forks and ensure that the Execution layer is working with the latest
     /// valid chain.
     ///
@@ -372,6 +386,16 @@ where
             return Ok(OnForkChoiceUpdated::syncing())
         }
 
+        if self.sync.is_paused() {
+            // Making a block canonical commits it to the database
+            warn!(
+                target: "consensus::engine",
+                head_block_hash = ?state.head_block_hash,
+                "Block ingest is paused, skipping forkchoice update"
+            );
+            return Ok(OnForkChoiceUpdated::syncing())
+        }
+
         let start = Instant::now();
         let make_canonical_result = self.blockchain.make_canonical(&state.head_block_hash);
         let elapsed = self.record_make_canonical_latency(start, &make_canonical_result);
@@ -1113,9 +1137,9 @@ where
             return Ok(status)
         }
 
-        let res = if self.sync.is_pipeline_idle() {
+        let res = if self.sync.is_pipeline_idle() && !self.sync.is_paused() {
             // we can only insert new payloads if the pipeline is _not_ running, because it holds
-            // exclusive access to the database
+            // exclusive access to the database, and if block ingest isn't paused
             self.try_insert_new_payload(block)
         } else {
             self.try_buffer_payload(block)
@@ -1277,7 +1301,7 @@ where
     ///
     /// In this scenario we buffer the payload in the tree if the payload is valid, once the
     /// pipeline is finished, the tree is then able to also use the buffered payloads to commit to a
-    /// (newer) canonical chain.
+    /// (newer) canonical chain. The same applies while block ingest is paused.
     ///
     /// This will return `SYNCING` if the block was buffered successfully, and an error if an error
     /// occurred while buffering the block.
@@ -1359,6 +1383,8 @@ where
             // all of these occurred if the payload is invalid
             let parent_hash = block.parent_hash;
 
+            self.notify_invalid_block(&block, &error);
+
             // keep track of the invalid header
             self.invalid_headers.insert(block.header);
 
@@ -1371,6 +1397,15 @@ where
         }
     }
 
+    /// Notifies all listeners that the given block was rejected as invalid.
+    fn notify_invalid_block(&self, block: &SealedBlock, error: &InsertBlockErrorKind) {
+        self.listeners.notify(BeaconConsensusEngineEvent::InvalidBlock {
+            block: Arc::new(block.clone()),
+            reason: error.to_string(),
+            offending_transaction: error.offending_transaction(),
+        });
+    }
+
     /// Attempt to restore the tree with the given block hash.
     ///
     /// This is invoked after a full pipeline to update the tree with the most recent canonical
@@ -1469,6 +1504,7 @@ where
                     let (block, err) = err.split();
                     warn!(target: "consensus::engine", invalid_number=?block.number, invalid_hash=?block.hash(), %err, "Marking block as invalid");
 
+                    self.notify_invalid_block(&block, &err);
                     self.invalid_headers.insert(block.header);
                 }
             }
@@ -2561,6 +2597,71 @@ mod tests {
             assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
         }
 
+        #[tokio::test]
+        async fn payload_buffered_while_ingest_paused() {
+            let mut rng = generators::rng();
+            let chain_spec = Arc::new(
+                ChainSpecBuilder::default()
+                    .chain(MAINNET.chain)
+                    .genesis(MAINNET.genesis.clone())
+                    .paris_activated()
+                    .build(),
+            );
+
+            let (consensus_engine, env) = TestConsensusEngineBuilder::new(chain_spec.clone())
+                .with_pipeline_exec_outputs(VecDeque::from([Ok(ExecOutput {
+                    checkpoint: StageCheckpoint::new(0),
+                    done: true,
+                })]))
+                .build();
+            let (pause_tx, pause_rx) = tokio::sync::watch::channel(true);
+            let consensus_engine = consensus_engine.with_ingest_pause_signal(pause_rx);
+
+            let genesis = random_block(&mut rng, 0, None, None, Some(0));
+            let block1 = random_block(&mut rng, 1, Some(genesis.hash()), None, Some(0));
+            let block2 = random_block(&mut rng, 2, Some(block1.hash()), None, Some(0));
+
+            let (_static_dir, static_dir_path) = create_test_static_files_dir();
+            insert_blocks(
+                ProviderFactory::new(env.db.as_ref(), chain_spec.clone(), static_dir_path)
+                    .expect("create provider factory with static_files"),
+                [&genesis, &block1].into_iter(),
+            );
+
+            let mut engine_rx = spawn_consensus_engine(consensus_engine);
+            let forkchoice = ForkchoiceState {
+                head_block_hash: block1.hash(),
+                finalized_block_hash: block1.hash(),
+                ..Default::default()
+            };
+
+            // nothing is committed while paused
+            let res = env.send_forkchoice_updated(forkchoice).await;
+            let expected_result = PayloadStatus::from_status(PayloadStatusEnum::Syncing);
+            assert_matches!(res, Ok(ForkchoiceUpdated { payload_status, .. }) => assert_eq!(payload_status, expected_result));
+
+            let res = env.send_new_payload(try_block_to_payload_v1(block2.clone()), None).await;
+            let expected_result = PayloadStatus::from_status(PayloadStatusEnum::Syncing);
+            assert_matches!(res, Ok(result) => assert_eq!(result, expected_result));
+
+            // blocks are inserted again once ingest is resumed
+            pause_tx.send_replace(false);
+
+            let res = env.send_forkchoice_updated(forkchoice).await;
+            let expected_result = PayloadStatus::from_status(PayloadStatusEnum::Valid)
+                .with_latest_valid_hash(block1.hash());
+            assert_matches!(res, Ok(ForkchoiceUpdated { payload_status, .. }) => assert_eq!(payload_status, expected_result));
+
+            let result = env
+                .send_new_payload_retry_on_syncing(try_block_to_payload_v1(block2.clone()), None)
+                .await
+                .unwrap();
+            let expected_result = PayloadStatus::from_status(PayloadStatusEnum::Valid)
+                .with_latest_valid_hash(block2.hash());
+            assert_eq!(result, expected_result);
+            assert_matches!(engine_rx.try_recv(), Err(TryRecvError::Empty));
+        }
+
         #[tokio::test]
         async fn simple_validate_block() {
             let mut rng = generators::rng();
@@ -21,7 +21,7 @@ use std::{
     sync::Arc,
     task::{ready, Context, Poll},
 };
-use tokio::sync::{mpsc::UnboundedSender, oneshot};
+use tokio::sync::{mpsc::UnboundedSender, oneshot, watch};
 use tracing::trace;
 
 /// Manages syncing under the control of the engine.
@@ -59,6 +59,9 @@ where
     /// Max block after which the consensus engine would terminate the sync. Used for debugging
     /// purposes.
     max_block: Option<BlockNumber>,
+    /// If set, no pipeline runs are started and no downloaded blocks are emitted while the signal
+    /// is `true`.
+    pause_signal: Option<watch::Receiver<bool>>,
     /// Engine sync metrics.
     metrics: EngineSyncMetrics,
 }
@@ -92,10 +95,24 @@ where
             run_pipeline_continuously,
             listeners,
             max_block,
+            pause_signal: None,
             metrics: EngineSyncMetrics::default(),
         }
     }
 
+    /// Sets the signal that pauses syncing while it's `true`.
+    ///
+    /// A pipeline run that is already in progress is not interrupted, and blocks keep being
+    /// downloaded and buffered while paused.
+    pub(crate) fn set_pause_signal(&mut self, pause_signal: watch::Receiver<bool>) {
+        self.pause_signal = Some(pause_signal);
+    }
+
+    /// Returns `true` if syncing is paused.
+    pub(crate) fn is_paused(&self) -> bool {
+        self.pause_signal.as_ref().is_some_and(|paused| *paused.borrow())
+    }
+
     /// Sets the metrics for the active downloads
     fn update_block_download_metrics(&self) {
         self.metrics.active_block_downloads.set(self.inflight_full_block_requests.len() as f64);
@@ -269,7 +286,13 @@ where
 
     /// This will spawn the pipeline if it is idle and a target is set or if the pipeline is set to
     /// run continuously.
+    ///
+    /// If syncing is paused, the pending target is kept until syncing is resumed.
     fn try_spawn_pipeline(&mut self) -> Option<EngineSyncEvent> {
+        if self.is_paused() {
+            return None
+        }
+
         match &mut self.pipeline_state {
             PipelineState::Idle(pipeline) => {
                 let target = self.pending_pipeline_target.take();
@@ -343,7 +366,10 @@ where
 
         self.update_block_download_metrics();
 
-        // drain an element of the block buffer if there are any
+        // drain an element of the block buffer if there are any, unless inserting blocks is paused
+        if self.is_paused() {
+            return Poll::Pending
+        }
         if let Some(block) = self.range_buffered_blocks.pop() {
             // peek ahead and pop duplicates
             while let Some(peek) = self.range_buffered_blocks.peek_mut() {
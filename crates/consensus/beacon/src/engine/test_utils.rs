@@ -70,6 +70,11 @@ impl<DB> TestEnv<DB> {
         Self { db, tip_rx, engine_handle }
     }
 
+    /// Returns the handle to the spawned consensus engine.
+    pub fn engine_handle(&self) -> &BeaconConsensusEngineHandle<EthEngineTypes> {
+        &self.engine_handle
+    }
+
     pub async fn send_new_payload<T: Into<ExecutionPayload>>(
         &self,
         payload: T,
@@ -435,6 +440,7 @@ where
             self.base_config.chain_spec.prune_delete_limit,
             config.max_reorg_depth() as usize,
             None,
+            None,
         );
 
         let mut hooks = EngineHooks::new();
@@ -10,10 +10,51 @@ use reth_primitives::{
 use reth_provider::{AccountReader, HeaderProvider, WithdrawalsProvider};
 use std::collections::{hash_map::Entry, HashMap};
 
+/// Header and body checks whose applicability depends on the header layout of the chain.
+///
+/// All checks are enabled by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeaderChecks {
+    /// Whether the presence of the withdrawals root is validated, and that it matches the
+    /// withdrawals of the block (EIP-4895).
+    pub withdrawals_root: bool,
+    /// Whether the presence and validity of the blob gas fields and the parent beacon block root
+    /// are validated, and that the blob gas used matches the blob transactions of the block
+    /// (EIP-4844, EIP-4788).
+    pub blob_fields: bool,
+}
+
+impl HeaderChecks {
+    /// Enables all checks.
+    pub const fn all() -> Self {
+        Self { withdrawals_root: true, blob_fields: true }
+    }
+
+    /// Disables all checks.
+    pub const fn none() -> Self {
+        Self { withdrawals_root: false, blob_fields: false }
+    }
+}
+
+impl Default for HeaderChecks {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
 /// Validate header standalone
 pub fn validate_header_standalone(
     header: &SealedHeader,
     chain_spec: &ChainSpec,
+) -> Result<(), ConsensusError> {
+    validate_header_standalone_with_checks(header, chain_spec, HeaderChecks::all())
+}
+
+/// Validate header standalone, skipping the disabled [HeaderChecks].
+pub fn validate_header_standalone_with_checks(
+    header: &SealedHeader,
+    chain_spec: &ChainSpec,
+    checks: HeaderChecks,
 ) -> Result<(), ConsensusError> {
     // Gas used needs to be less than gas limit. Gas used is going to be checked after execution.
     if header.gas_used > header.gas_limit {
@@ -30,31 +71,50 @@ pub fn validate_header_standalone(
         return Err(ConsensusError::BaseFeeMissing)
     }
 
-    let wd_root_missing = header.withdrawals_root.is_none() && !chain_spec.is_optimism();
+    if checks.withdrawals_root {
+        let wd_root_missing = header.withdrawals_root.is_none() && !chain_spec.is_optimism();
 
-    // EIP-4895: Beacon chain push withdrawals as operations
-    if chain_spec.is_shanghai_active_at_timestamp(header.timestamp) && wd_root_missing {
-        return Err(ConsensusError::WithdrawalsRootMissing)
-    } else if !chain_spec.is_shanghai_active_at_timestamp(header.timestamp) &&
-        header.withdrawals_root.is_some()
-    {
-        return Err(ConsensusError::WithdrawalsRootUnexpected)
+        // EIP-4895: Beacon chain push withdrawals as operations
+        if chain_spec.is_shanghai_active_at_timestamp(header.timestamp) && wd_root_missing {
+            return Err(ConsensusError::WithdrawalsRootMissing)
+        } else if !chain_spec.is_shanghai_active_at_timestamp(header.timestamp) &&
+            header.withdrawals_root.is_some()
+        {
+            return Err(ConsensusError::WithdrawalsRootUnexpected)
+        }
     }
 
-    // Ensures that EIP-4844 fields are valid once cancun is active.
-    if chain_spec.is_cancun_active_at_timestamp(header.timestamp) {
-        validate_4844_header_standalone(header)?;
-    } else if header.blob_gas_used.is_some() {
-        return Err(ConsensusError::BlobGasUsedUnexpected)
-    } else if header.excess_blob_gas.is_some() {
-        return Err(ConsensusError::ExcessBlobGasUnexpected)
-    } else if header.parent_beacon_block_root.is_some() {
-        return Err(ConsensusError::ParentBeaconBlockRootUnexpected)
+    if checks.blob_fields {
+        // Ensures that EIP-4844 fields are valid once cancun is active.
+        if chain_spec.is_cancun_active_at_timestamp(header.timestamp) {
+            validate_4844_header_standalone(header)?;
+        } else if header.blob_gas_used.is_some() {
+            return Err(ConsensusError::BlobGasUsedUnexpected)
+        } else if header.excess_blob_gas.is_some() {
+            return Err(ConsensusError::ExcessBlobGasUnexpected)
+        } else if header.parent_beacon_block_root.is_some() {
+            return Err(ConsensusError::ParentBeaconBlockRootUnexpected)
+        }
     }
 
     Ok(())
 }
 
+/// Validate header against its parent, skipping the disabled [HeaderChecks].
+pub fn validate_header_against_parent_with_checks(
+    header: &SealedHeader,
+    parent: &SealedHeader,
+    chain_spec: &ChainSpec,
+    checks: HeaderChecks,
+) -> Result<(), ConsensusError> {
+    if checks.blob_fields {
+        header.validate_against_parent(parent, chain_spec)?;
+    } else {
+        header.validate_against_parent_without_blob_fields(parent, chain_spec)?;
+    }
+    Ok(())
+}
+
 /// Validate a transaction with regard to a block header.
 ///
 /// The only parameter from the header that affects the transaction is `base_fee`.
@@ -206,6 +266,15 @@ pub fn validate_all_transaction_regarding_block_and_nonces<
 pub fn validate_block_standalone(
     block: &SealedBlock,
     chain_spec: &ChainSpec,
+) -> Result<(), ConsensusError> {
+    validate_block_standalone_with_checks(block, chain_spec, HeaderChecks::all())
+}
+
+/// Validate a block without regard for state, skipping the disabled [HeaderChecks].
+pub fn validate_block_standalone_with_checks(
+    block: &SealedBlock,
+    chain_spec: &ChainSpec,
+    checks: HeaderChecks,
 ) -> Result<(), ConsensusError> {
     // Check ommers hash
     let ommers_hash = reth_primitives::proofs::calculate_ommers_root(&block.ommers);
@@ -221,7 +290,7 @@ pub fn validate_block_standalone(
     }
 
     // EIP-4895: Beacon chain push withdrawals as operations
-    if chain_spec.is_shanghai_active_at_timestamp(block.timestamp) {
+    if checks.withdrawals_root && chain_spec.is_shanghai_active_at_timestamp(block.timestamp) {
         let withdrawals =
             block.withdrawals.as_ref().ok_or(ConsensusError::BodyWithdrawalsMissing)?;
         let withdrawals_root = reth_primitives::proofs::calculate_withdrawals_root(withdrawals);
@@ -235,7 +304,7 @@ pub fn validate_block_standalone(
     }
 
     // EIP-4844: Shard Blob Transactions
-    if chain_spec.is_cancun_active_at_timestamp(block.timestamp) {
+    if checks.blob_fields && chain_spec.is_cancun_active_at_timestamp(block.timestamp) {
         // Check that the blob gas used in the header matches the sum of the blob gas used by each
         // blob tx
         let header_blob_gas_used = block.blob_gas_used.ok_or(ConsensusError::BlobGasUsedMissing)?;
@@ -642,6 +711,31 @@ mod tests {
         assert_eq!(validate_header_standalone(&header, &chain_spec), Ok(()));
     }
 
+    #[test]
+    fn skipped_header_checks() {
+        let chain_spec = ChainSpecBuilder::mainnet().cancun_activated().build();
+
+        // withdrawals root and blob fields are missing
+        let header = Header { base_fee_per_gas: Some(1337u64), ..Default::default() }.seal_slow();
+
+        assert_eq!(
+            validate_header_standalone(&header, &chain_spec),
+            Err(ConsensusError::WithdrawalsRootMissing)
+        );
+        assert_eq!(
+            validate_header_standalone_with_checks(
+                &header,
+                &chain_spec,
+                HeaderChecks { withdrawals_root: false, ..HeaderChecks::all() }
+            ),
+            Err(ConsensusError::BlobGasUsedMissing)
+        );
+        assert_eq!(
+            validate_header_standalone_with_checks(&header, &chain_spec, HeaderChecks::none()),
+            Ok(())
+        );
+    }
+
     #[test]
     fn cancun_block_incorrect_blob_gas_used() {
         let chain_spec = ChainSpecBuilder::mainnet().cancun_activated().build();
@@ -6,7 +6,7 @@ use crate::{
     provider::ProviderError,
     RethError,
 };
-use reth_primitives::{BlockHash, BlockNumber, SealedBlock};
+use reth_primitives::{BlockHash, BlockNumber, SealedBlock, B256};
 
 /// Various error cases that can occur when a block violates tree assumptions.
 #[derive(Debug, Clone, Copy, thiserror::Error, Eq, PartialEq)]
@@ -329,6 +329,17 @@ impl InsertBlockErrorKind {
             _ => None,
         }
     }
+
+    /// Returns the hash of the transaction that caused the error, if the error was caused by a
+    /// specific transaction.
+    pub fn offending_transaction(&self) -> Option<B256> {
+        match self {
+            InsertBlockErrorKind::Execution(BlockExecutionError::Validation(
+                BlockValidationError::EVM { hash, .. },
+            )) => Some(*hash),
+            _ => None,
+        }
+    }
 }
 
 // This is a convenience impl to convert from crate::Error to InsertBlockErrorKind
@@ -119,3 +119,19 @@ pub enum LogLevel {
     /// Enables logging for extra debug-level messages.
     Extra,
 }
+
+/// Database sync mode, i.e. the durability guarantees of committed write transactions.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum SyncMode {
+    /// Data and metadata are flushed to disk on every commit. Survives a system crash at any time.
+    #[default]
+    Durable,
+    /// Metadata is not flushed on commit. A system crash may undo the last committed transaction,
+    /// but the database stays consistent.
+    NoMetaSync,
+    /// Nothing is flushed on commit, the OS flushes the data in the background. A system crash
+    /// may undo the transactions committed since the last flush, but the database stays
+    /// consistent.
+    SafeNoSync,
+}
@@ -86,6 +86,16 @@ pub enum ProviderError {
     /// Block body wrong transaction count.
     #[error("stored block indices does not match transaction count")]
     BlockBodyTransactionCount,
+    /// The number of receipts of a block doesn't match its number of transactions.
+    #[error("block {block} has {transactions} transactions, but {receipts} receipts")]
+    BlockReceiptsMismatch {
+        /// The block number.
+        block: BlockNumber,
+        /// The number of transactions of the block.
+        transactions: usize,
+        /// The number of receipts of the block.
+        receipts: usize,
+    },
     /// Thrown when the cache service task dropped.
     #[error("cache service task stopped")]
     CacheServiceUnavailable,
@@ -128,6 +138,19 @@ pub enum ProviderError {
     /// Trying to insert data from an unexpected block number.
     #[error("trying to append data to {0} as block #{1} but expected block #{2}")]
     UnexpectedStaticFileBlockNumber(StaticFileSegment, BlockNumber, BlockNumber),
+    /// Static files on disk store a different number of blocks per file than configured.
+    #[error(
+        "configured {configured} blocks per static file, but existing static files store {on_disk}"
+    )]
+    StaticFileBlocksPerFileMismatch {
+        /// The configured number of blocks per static file.
+        configured: u64,
+        /// The number of blocks per static file of existing static files.
+        on_disk: u64,
+    },
+    /// A snapshot of static files can't be imported.
+    #[error("invalid static file snapshot: {0}")]
+    InvalidStaticFileSnapshot(String),
     /// Error encountered when the block number conversion from U256 to u64 causes an overflow.
     #[error("failed to convert block number U256 to u64: {0}")]
     BlockNumberOverflow(U256),
@@ -5,6 +5,9 @@ pub mod bodies;
 /// A downloader implementation that spawns a downloader to a task
 pub mod task;
 
+/// A downloader implementation that does nothing
+pub mod noop;
+
 mod queue;
 mod request;
 
@@ -0,0 +1,32 @@
+use futures::Stream;
+use reth_interfaces::p2p::{
+    bodies::downloader::{BodyDownloader, BodyDownloaderResult},
+    error::DownloadResult,
+};
+use reth_primitives::BlockNumber;
+use std::{
+    ops::RangeInclusive,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A [BodyDownloader] implementation that does nothing.
+///
+/// Used by pipelines that only unwind.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct NoopBodiesDownloader;
+
+impl BodyDownloader for NoopBodiesDownloader {
+    fn set_download_range(&mut self, _: RangeInclusive<BlockNumber>) -> DownloadResult<()> {
+        Ok(())
+    }
+}
+
+impl Stream for NoopBodiesDownloader {
+    type Item = BodyDownloaderResult;
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        panic!("NoopBodiesDownloader shouldn't be polled.")
+    }
+}
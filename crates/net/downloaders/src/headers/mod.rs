@@ -4,5 +4,8 @@ pub mod reverse_headers;
 /// A downloader implementation that spawns a downloader to a task
 pub mod task;
 
+/// A downloader implementation that does nothing
+pub mod noop;
+
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_utils;
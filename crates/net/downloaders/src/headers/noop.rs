@@ -0,0 +1,33 @@
+use futures::Stream;
+use reth_interfaces::p2p::headers::{
+    downloader::{HeaderDownloader, SyncTarget},
+    error::HeadersDownloaderError,
+};
+use reth_primitives::SealedHeader;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A [HeaderDownloader] implementation that does nothing.
+///
+/// Used by pipelines that only unwind.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct NoopHeaderDownloader;
+
+impl HeaderDownloader for NoopHeaderDownloader {
+    fn update_local_head(&mut self, _: SealedHeader) {}
+
+    fn update_sync_target(&mut self, _: SyncTarget) {}
+
+    fn set_batch_size(&mut self, _: usize) {}
+}
+
+impl Stream for NoopHeaderDownloader {
+    type Item = Result<Vec<SealedHeader>, HeadersDownloaderError>;
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        panic!("NoopHeaderDownloader shouldn't be polled.")
+    }
+}
@@ -0,0 +1,216 @@
+//! A stand-in for the [`NetworkManager`](crate::NetworkManager) of a node without devp2p.
+
+use crate::{
+    discovery::DiscoveryEvent, manager::NetworkEvent, network::NetworkHandleMessage,
+    peers::PeersHandle, FetchClient, NetworkConfig, NetworkHandle,
+};
+use futures::{Future, StreamExt};
+use parking_lot::Mutex;
+use reth_net_common::bandwidth_meter::BandwidthMeter;
+use reth_primitives::pk2id;
+use reth_rpc_types::{EthProtocolInfo, NetworkStatus};
+use secp256k1::SECP256K1;
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+use tokio::sync::mpsc::{self, UnboundedSender};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tracing::trace;
+
+/// Serves the [`NetworkHandle`] of a node with the devp2p networking stack disabled.
+///
+/// Unlike the [`NetworkManager`](crate::NetworkManager), this doesn't bind a listener, start
+/// discovery or track any peers. It answers the requests of the handle like a network without any
+/// peers would: there are no peers to report, nothing is sent anywhere and all requests of its
+/// [`FetchClient`]s fail.
+///
+/// This is an endless future that needs to be polled for the handle to be functional.
+#[derive(Debug)]
+#[must_use = "The DisabledNetwork does nothing unless polled"]
+pub struct DisabledNetwork {
+    /// The handle served by this type.
+    handle: NetworkHandle,
+    /// Receiver half of the command channel of the handle.
+    from_handle_rx: UnboundedReceiverStream<NetworkHandleMessage>,
+    /// The status reported to the handle.
+    status: NetworkStatus,
+    /// Network event listeners, kept so that their streams stay open.
+    event_listeners: Vec<UnboundedSender<NetworkEvent>>,
+    /// Discovery event listeners, kept so that their streams stay open.
+    discovery_listeners: Vec<UnboundedSender<DiscoveryEvent>>,
+}
+
+impl DisabledNetwork {
+    /// Creates the stand-in for the network of the given config.
+    pub fn new<C>(config: NetworkConfig<C>) -> Self {
+        let NetworkConfig {
+            secret_key,
+            listener_addr,
+            chain_spec,
+            network_mode,
+            status,
+            hello_message,
+            tx_gossip_disabled,
+            #[cfg(feature = "optimism")]
+                optimism_network_config: crate::config::OptimismNetworkConfig { sequencer_endpoint },
+            ..
+        } = config;
+
+        // nothing listens for the commands of the peers handle and the fetch client requests, so
+        // they are dropped right away
+        let (peers_tx, _) = mpsc::unbounded_channel();
+        let (to_manager_tx, from_handle_rx) = mpsc::unbounded_channel();
+
+        let handle = NetworkHandle::new(
+            Arc::new(AtomicUsize::new(0)),
+            Arc::new(Mutex::new(listener_addr)),
+            to_manager_tx,
+            secret_key,
+            pk2id(&secret_key.public_key(SECP256K1)),
+            PeersHandle::new(peers_tx),
+            network_mode,
+            BandwidthMeter::default(),
+            Arc::new(AtomicU64::new(chain_spec.chain.id())),
+            tx_gossip_disabled,
+            #[cfg(feature = "optimism")]
+            sequencer_endpoint,
+            None,
+        );
+
+        let status = NetworkStatus {
+            client_version: hello_message.client_version,
+            protocol_version: hello_message.protocol_version as u64,
+            eth_protocol_info: EthProtocolInfo {
+                difficulty: status.total_difficulty,
+                head: status.blockhash,
+                network: status.chain.id(),
+                genesis: status.genesis,
+                config: Default::default(),
+            },
+        };
+
+        Self {
+            handle,
+            from_handle_rx: UnboundedReceiverStream::new(from_handle_rx),
+            status,
+            event_listeners: Vec::new(),
+            discovery_listeners: Vec::new(),
+        }
+    }
+
+    /// Returns the [`NetworkHandle`] served by this type.
+    pub fn handle(&self) -> &NetworkHandle {
+        &self.handle
+    }
+
+    /// Returns a [`FetchClient`] whose requests all fail, since there are no peers to send them
+    /// to.
+    fn fetch_client(&self) -> FetchClient {
+        let (request_tx, _) = mpsc::unbounded_channel();
+        FetchClient {
+            request_tx,
+            peers_handle: self.handle.peers_handle().clone(),
+            num_active_peers: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Handler for received messages from a handle
+    fn on_handle_message(&mut self, msg: NetworkHandleMessage) {
+        match msg {
+            NetworkHandleMessage::EventListener(tx) => self.event_listeners.push(tx),
+            NetworkHandleMessage::DiscoveryListener(tx) => self.discovery_listeners.push(tx),
+            NetworkHandleMessage::FetchClient(tx) => {
+                let _ = tx.send(self.fetch_client());
+            }
+            NetworkHandleMessage::GetStatus(tx) => {
+                let _ = tx.send(self.status.clone());
+            }
+            NetworkHandleMessage::GetPeerInfos(tx) |
+            NetworkHandleMessage::GetPeerInfosByIds(_, tx) |
+            NetworkHandleMessage::GetPeerInfosByPeerKind(_, tx) => {
+                let _ = tx.send(Vec::new());
+            }
+            NetworkHandleMessage::GetPeerInfoById(_, tx) => {
+                let _ = tx.send(None);
+            }
+            NetworkHandleMessage::GetReputationById(_, tx) => {
+                let _ = tx.send(None);
+            }
+            NetworkHandleMessage::GetTransactionsHandle(tx) => {
+                let _ = tx.send(None);
+            }
+            NetworkHandleMessage::Shutdown(tx) => {
+                let _ = tx.send(());
+            }
+            NetworkHandleMessage::AddTrustedPeerId(_) |
+            NetworkHandleMessage::AddPeerAddress(..) |
+            NetworkHandleMessage::RemovePeer(..) |
+            NetworkHandleMessage::DisconnectPeer(..) |
+            NetworkHandleMessage::AnnounceBlock(..) |
+            NetworkHandleMessage::SendTransaction { .. } |
+            NetworkHandleMessage::SendPooledTransactionHashes { .. } |
+            NetworkHandleMessage::EthRequest { .. } |
+            NetworkHandleMessage::ReputationChange(..) |
+            NetworkHandleMessage::StatusUpdate { .. } |
+            NetworkHandleMessage::SetNetworkState(_) |
+            NetworkHandleMessage::AddRlpxSubProtocol(_) => {
+                trace!(target: "net", "Ignoring network handle message, p2p is disabled");
+            }
+        }
+    }
+}
+
+impl Future for DisabledNetwork {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        // the handle keeps a sender itself, so the channel is never closed
+        while let Poll::Ready(Some(msg)) = this.from_handle_rx.poll_next_unpin(cx) {
+            this.on_handle_message(msg);
+        }
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::rng_secret_key, NetworkEvents};
+    use reth_interfaces::p2p::{error::RequestError, headers::client::HeadersClient};
+    use reth_network_api::{NetworkInfo, Peers, PeersInfo};
+    use reth_primitives::BlockHashOrNumber;
+    use reth_provider::test_utils::NoopProvider;
+
+    #[tokio::test]
+    async fn serves_handle_without_peers() {
+        let secret_key = rng_secret_key();
+        let config = NetworkConfig::builder(secret_key).build(NoopProvider::default());
+        let network = DisabledNetwork::new(config);
+        let handle = network.handle().clone();
+        tokio::spawn(network);
+
+        assert_eq!(*handle.peer_id(), pk2id(&secret_key.public_key(SECP256K1)));
+        assert_eq!(handle.num_connected_peers(), 0);
+        assert!(handle.get_all_peers().await.unwrap().is_empty());
+        assert_eq!(handle.network_status().await.unwrap().eth_protocol_info.network, 1);
+        assert!(handle.transactions_handle().await.is_none());
+
+        // the event stream stays open, but never yields anything
+        let mut events = handle.event_listener();
+        assert!(futures::poll!(events.next()).is_pending());
+
+        let client = handle.fetch_client().await.unwrap();
+        assert!(matches!(
+            client.get_header(BlockHashOrNumber::Number(0)).await,
+            Err(RequestError::ChannelClosed)
+        ));
+
+        handle.shutdown().await.unwrap();
+    }
+}
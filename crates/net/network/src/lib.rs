@@ -118,6 +118,7 @@ mod budget;
 mod builder;
 mod cache;
 pub mod config;
+mod disabled;
 mod discovery;
 pub mod error;
 pub mod eth_requests;
@@ -138,6 +139,7 @@ pub mod transactions;
 
 pub use builder::NetworkBuilder;
 pub use config::{NetworkConfig, NetworkConfigBuilder};
+pub use disabled::DisabledNetwork;
 pub use discovery::{Discovery, DiscoveryEvent};
 pub use fetch::FetchClient;
 pub use manager::{NetworkEvent, NetworkManager};
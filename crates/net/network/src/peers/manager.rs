@@ -42,6 +42,11 @@ pub struct PeersHandle {
 // === impl PeersHandle ===
 
 impl PeersHandle {
+    /// Creates a handle that sends its commands to the given channel.
+    pub(crate) const fn new(manager_tx: mpsc::UnboundedSender<PeerCommand>) -> Self {
+        Self { manager_tx }
+    }
+
     fn send(&self, cmd: PeerCommand) {
         let _ = self.manager_tx.send(cmd);
     }
@@ -29,29 +29,36 @@ use reth_db::{
     DatabaseEnv,
 };
 use reth_interfaces::p2p::either::EitherDownloader;
-use reth_network::{NetworkBuilder, NetworkConfig, NetworkEvents, NetworkHandle};
+use reth_network::{DisabledNetwork, NetworkBuilder, NetworkConfig, NetworkEvents, NetworkHandle};
 use reth_node_api::{FullNodeTypes, FullNodeTypesAdapter, NodeTypes};
 use reth_node_core::{
+    bad_block_store::{BadBlockStore, DEFAULT_MAX_BAD_BLOCKS},
     cli::config::{PayloadBuilderConfig, RethRpcConfig, RethTransactionPoolConfig},
     dirs::{ChainPath, DataDirPath, MaybePlatformPath},
+    disk_monitor::DiskSpaceMonitor,
     engine_api_store::EngineApiStore,
     events::cl::ConsensusLayerHealthEvents,
     exit::NodeExitFuture,
     init::init_genesis,
     node_config::NodeConfig,
     primitives::{kzg::KzgSettings, Head},
+    reorg_monitor::{ReorgMonitor, DEFAULT_TRACKED_CANONICAL_HASHES},
+    spot_check::BlockSpotChecker,
+    static_file_heal::heal_static_files,
     utils::write_peers_to_file,
 };
 use reth_primitives::{constants::eip4844::MAINNET_KZG_TRUSTED_SETUP, format_ether, ChainSpec};
-use reth_provider::{providers::BlockchainProvider, ChainSpecProvider, ProviderFactory};
+use reth_provider::{
+    providers::BlockchainProvider, CanonStateSubscriptions, ChainSpecProvider, ProviderFactory,
+};
 use reth_prune::PrunerBuilder;
 use reth_revm::EvmProcessorFactory;
 use reth_rpc_engine_api::EngineApi;
 use reth_static_file::StaticFileProducer;
 use reth_tasks::TaskExecutor;
-use reth_tracing::tracing::{debug, error, info};
+use reth_tracing::tracing::{debug, error, info, warn};
 use reth_transaction_pool::{PoolConfig, TransactionPool};
-use std::{cmp::max, str::FromStr, sync::Arc, thread::available_parallelism};
+use std::{str::FromStr, sync::Arc};
 use tokio::sync::{mpsc::unbounded_channel, oneshot};
 
 /// The builtin provider type of the reth node.
@@ -62,6 +69,10 @@ type RethFullProviderType<DB, Evm> =
 type RethFullAdapter<DB, N> =
     FullNodeTypesAdapter<N, DB, RethFullProviderType<DB, <N as NodeTypes>::Evm>>;
 
+/// The number of file descriptors reserved for the database, peers and RPC connections when
+/// checking whether the open files limit is high enough for the static files.
+const RESERVED_FILE_DESCRIPTORS: u64 = 1024;
+
 #[cfg_attr(doc, aquamarine::aquamarine)]
 /// Declaratively construct a node.
 ///
@@ -481,24 +492,57 @@ where
 
         // Raise the fd limit of the process.
         // Does not do anything on windows.
-        fdlimit::raise_fd_limit()?;
+        let fd_limit = match fdlimit::raise_fd_limit()? {
+            fdlimit::Outcome::LimitRaised { to, .. } => Some(to),
+            fdlimit::Outcome::Unsupported => None,
+        };
 
-        // Limit the global rayon thread pool, reserving 2 cores for the rest of the system
+        // Limit the global rayon thread pool, by default reserving 2 cores for the rest of the
+        // system
+        let rayon_threads = config.threads.rayon_threads();
+        let thread_pool_args = config.threads.clone();
         let _ = ThreadPoolBuilder::new()
-            .num_threads(
-                available_parallelism().map_or(25, |cpus| max(cpus.get().saturating_sub(2), 2)),
-            )
+            .num_threads(rayon_threads)
+            .start_handler(move |idx| {
+                if let Some(core) = thread_pool_args.pinned_core(idx) {
+                    pin_current_thread(core);
+                }
+            })
             .build_global()
             .map_err(|e| error!("Failed to build global thread pool: {:?}", e));
+        debug!(target: "reth::cli", rayon_threads, pinned_cores = ?config.threads.pin_cores, "Configured global thread pool");
 
-        let provider_factory = ProviderFactory::new(
+        let mut provider_factory = ProviderFactory::new(
             database.clone(),
             Arc::clone(&config.chain),
             data_dir.static_files_path(),
         )?
         .with_static_files_metrics();
+        if let Some(max_open) = config.static_files.max_open {
+            provider_factory = provider_factory.with_static_files_max_open_jars(max_open as usize);
+        }
+        if let Some(threshold) = config.static_files.slow_commit_threshold {
+            provider_factory = provider_factory.with_static_files_slow_commit_threshold(threshold);
+        }
+        if let Some(blocks_per_file) = config.static_files.blocks_per_file {
+            provider_factory =
+                provider_factory.with_static_files_blocks_per_file(blocks_per_file)?;
+        }
         info!(target: "reth::cli", "Database opened");
 
+        if let Some(fd_limit) = fd_limit {
+            let static_file_fds =
+                provider_factory.static_file_provider().max_open_file_descriptors()? as u64;
+            if static_file_fds + RESERVED_FILE_DESCRIPTORS > fd_limit {
+                warn!(
+                    target: "reth::cli",
+                    fd_limit,
+                    static_file_fds,
+                    "The open files limit is too low to keep all static files open, raise the limit or set --static-files.max-open"
+                );
+            }
+        }
+
         let prometheus_handle = config.install_prometheus_recorder()?;
         config
             .start_metrics_endpoint(
@@ -524,6 +568,32 @@ where
         let prune_config = config.prune_config()?.or_else(|| reth_config.prune.clone());
 
         let evm_config = types.evm_config();
+
+        if config.static_files.auto_heal {
+            debug!(target: "reth::cli", "Healing static files");
+            let has_receipt_pruning = prune_config.as_ref().map_or(false, |prune| {
+                prune.segments.receipts.is_some() || !prune.segments.receipts_log_filter.is_empty()
+            });
+            let healed = heal_static_files(&provider_factory, has_receipt_pruning)?;
+            if let Some(unwind_target) = healed.unwind_target {
+                if unwind_target == 0 {
+                    eyre::bail!("static files are missing rows of all blocks, refusing to unwind the database to genesis")
+                }
+
+                info!(target: "reth::cli", unwind_target, "Unwinding the database to the static files");
+                let mut pipeline = config
+                    .build_unwind_pipeline(
+                        &reth_config.stages,
+                        Arc::clone(&consensus),
+                        provider_factory.clone(),
+                        prune_config.clone(),
+                        evm_config.clone(),
+                    )
+                    .await?;
+                pipeline.unwind(unwind_target, None)?;
+            }
+        }
+
         let tree_config = BlockchainTreeConfig::default();
         let tree = config.build_blockchain_tree(
             provider_factory.clone(),
@@ -576,6 +646,21 @@ where
         debug!(target: "reth::cli", "calling on_component_initialized hook");
         on_component_initialized.on_event(node_components.clone())?;
 
+        if let Some(sample_rate) = config.debug.spot_check_rate {
+            debug!(target: "reth::cli", sample_rate, "spawning block spot checker");
+            let spot_checker = BlockSpotChecker::new(
+                blockchain_db.clone(),
+                EvmProcessorFactory::new(config.chain.clone(), evm_config.clone()),
+                sample_rate,
+            );
+            executor
+                .spawn_blocking(Box::pin(spot_checker.run(blockchain_db.canonical_state_stream())));
+        }
+
+        debug!(target: "reth::cli", "spawning reorg monitor");
+        let reorg_monitor = ReorgMonitor::new(DEFAULT_TRACKED_CANONICAL_HASHES);
+        executor.spawn(Box::pin(reorg_monitor.run(blockchain_db.canonical_state_stream())));
+
         // create pipeline
         let network_client = network.fetch_client().await?;
         let (consensus_engine_tx, mut consensus_engine_rx) = unbounded_channel();
@@ -594,13 +679,33 @@ where
         let max_block = config.max_block(&network_client, provider_factory.clone()).await?;
         let mut hooks = EngineHooks::new();
 
+        // Pause block ingest if the disk is about to run full, pruning keeps running to free space
+        let disk_monitor = if config.disk.disable_monitor {
+            None
+        } else {
+            Some(DiskSpaceMonitor::new(
+                vec![data_dir.db_path(), data_dir.static_files_path()],
+                config.disk.warn_threshold as u64,
+                config.disk.critical_threshold as u64,
+            )?)
+        };
+        let disk_space = disk_monitor.as_ref().map(|monitor| monitor.disk_space());
+        let disk_pause_signal = disk_monitor.as_ref().map(|monitor| monitor.pause_signal());
+        if let Some(disk_monitor) = disk_monitor {
+            debug!(target: "reth::cli", "spawning disk space monitor");
+            executor.spawn(Box::pin(disk_monitor.run()));
+        }
+
         let static_file_producer = StaticFileProducer::new(
             provider_factory.clone(),
             provider_factory.static_file_provider(),
             prune_config.clone().unwrap_or_default().segments,
         );
         let static_file_producer_events = static_file_producer.lock().events();
-        hooks.add(StaticFileHook::new(static_file_producer.clone(), Box::new(executor.clone())));
+        hooks.add(
+            StaticFileHook::new(static_file_producer.clone(), Box::new(executor.clone()))
+                .with_tip_distance(config.static_files.tip_distance),
+        );
         info!(target: "reth::cli", "StaticFileProducer initialized");
 
         // Make sure ETL doesn't default to /tmp/, but to whatever datadir is set to
@@ -677,6 +782,7 @@ where
         let mut pruner = PrunerBuilder::new(prune_config.clone())
             .max_reorg_depth(tree_config.max_reorg_depth() as usize)
             .prune_delete_limit(config.chain.prune_delete_limit)
+            .max_entries_per_run(config.pruning.max_rows_per_run)
             .timeout(PrunerBuilder::DEFAULT_TIMEOUT)
             .build(provider_factory.clone());
 
@@ -700,8 +806,20 @@ where
             consensus_engine_rx,
             hooks,
         )?;
+        let beacon_consensus_engine = match disk_pause_signal {
+            Some(disk_pause_signal) => {
+                beacon_consensus_engine.with_ingest_pause_signal(disk_pause_signal)
+            }
+            None => beacon_consensus_engine,
+        };
         info!(target: "reth::cli", "Consensus engine initialized");
 
+        // record all blocks rejected by the consensus engine
+        let bad_block_store =
+            BadBlockStore::open(data_dir.bad_blocks_path(), DEFAULT_MAX_BAD_BLOCKS)?;
+        executor
+            .spawn(Box::pin(bad_block_store.clone().listen(beacon_engine_handle.event_listener())));
+
         let events = stream_select!(
             network.event_listener().map(Into::into),
             beacon_engine_handle.event_listener().map(Into::into),
@@ -750,6 +868,8 @@ where
             engine_api,
             &config,
             jwt_secret,
+            Arc::new(bad_block_store),
+            disk_space,
             rpc,
         )
         .await?;
@@ -1198,10 +1318,66 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
             .await
     }
 
+    /// Launches the network with the given config and returns the [NetworkHandle] connected to it.
+    ///
+    /// If the devp2p networking stack is disabled, no
+    /// [NetworkManager](reth_network::NetworkManager) is created and a [DisabledNetwork] serves
+    /// the handle instead. Otherwise, this builds the network and starts it with
+    /// [Self::start_network].
+    ///
+    /// Builds with the `disable-p2p` feature never start the devp2p networking stack.
+    #[cfg(not(feature = "disable-p2p"))]
+    pub async fn launch_network<Pool>(
+        &self,
+        network_config: NetworkConfig<Node::Provider>,
+        pool: Pool,
+    ) -> eyre::Result<NetworkHandle>
+    where
+        Pool: TransactionPool + Unpin + 'static,
+    {
+        if self.config.network.is_p2p_disabled() {
+            return Ok(self.launch_disabled_network(network_config))
+        }
+
+        let builder = reth_network::NetworkManager::builder(network_config).await?;
+        Ok(self.start_network(builder, pool))
+    }
+
+    /// Launches the network with the given config and returns the [NetworkHandle] connected to it.
+    ///
+    /// This build has the `disable-p2p` feature enabled, so the devp2p networking stack is never
+    /// started and a [DisabledNetwork] serves the handle instead.
+    #[cfg(feature = "disable-p2p")]
+    pub async fn launch_network<Pool>(
+        &self,
+        network_config: NetworkConfig<Node::Provider>,
+        _pool: Pool,
+    ) -> eyre::Result<NetworkHandle>
+    where
+        Pool: TransactionPool + Unpin + 'static,
+    {
+        Ok(self.launch_disabled_network(network_config))
+    }
+
+    /// Spawns a [DisabledNetwork] for the given config and returns the [NetworkHandle] it serves.
+    fn launch_disabled_network(
+        &self,
+        network_config: NetworkConfig<Node::Provider>,
+    ) -> NetworkHandle {
+        // there are no peers to gossip transactions with or to serve requests to
+        let network = DisabledNetwork::new(network_config);
+        let handle = network.handle().clone();
+        self.executor.spawn_critical("p2p network task", network);
+        handle
+    }
+
     /// Convenience function to start the network.
     ///
     /// Spawns the configured network and associated tasks and returns the [NetworkHandle] connected
     /// to that network.
+    ///
+    /// This always starts the full network, see [Self::launch_network] to respect a disabled
+    /// devp2p networking stack.
     pub fn start_network<Pool>(
         &self,
         builder: NetworkBuilder<Node::Provider, (), ()>,
@@ -1233,6 +1409,36 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
     }
 }
 
+/// Pins the current thread to the given CPU core.
+#[cfg(target_os = "linux")]
+fn pin_current_thread(core: usize) {
+    // `CPU_SET` panics on cores outside of the set
+    if core >= libc::CPU_SETSIZE as usize {
+        error!(target: "reth::cli", core, max = libc::CPU_SETSIZE, "Failed to pin thread to core out of range");
+        return
+    }
+
+    // SAFETY: the cpu set is zero initialized and only modified through the libc helpers, the
+    // affinity call only affects the calling thread
+    let res = unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        libc::CPU_ZERO(&mut set);
+        libc::CPU_SET(core, &mut set);
+        libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set)
+    };
+    if res != 0 {
+        error!(target: "reth::cli", core, err = %std::io::Error::last_os_error(), "Failed to pin thread to core");
+    }
+}
+
+/// Pins the current thread to the given CPU core.
+///
+/// CPU pinning is only supported on Linux, this is a no-op.
+#[cfg(not(target_os = "linux"))]
+fn pin_current_thread(core: usize) {
+    reth_tracing::tracing::warn!(target: "reth::cli", core, "CPU pinning is only supported on Linux");
+}
+
 /// The initial state of the node builder process.
 #[derive(Debug, Default)]
 #[non_exhaustive]
@@ -5,6 +5,7 @@ use futures::TryFutureExt;
 use reth_network::NetworkHandle;
 use reth_node_core::{
     cli::config::RethRpcConfig,
+    disk_monitor::DiskSpace,
     node_config::NodeConfig,
     rpc::{
         api::EngineApiServer,
@@ -15,13 +16,16 @@ use reth_node_core::{
     },
 };
 use reth_payload_builder::PayloadBuilderHandle;
+use reth_provider::BadBlockReader;
 use reth_rpc::JwtSecret;
 use reth_tasks::TaskExecutor;
 use reth_tracing::tracing::{debug, info};
 use std::{
     fmt,
     ops::{Deref, DerefMut},
+    sync::Arc,
 };
+use tokio::sync::watch;
 
 /// Contains the handles to the spawned RPC servers.
 ///
@@ -252,6 +256,8 @@ pub(crate) async fn launch_rpc_servers<Node, Engine>(
     engine_api: Engine,
     config: &NodeConfig,
     jwt_secret: JwtSecret,
+    bad_blocks: Arc<dyn BadBlockReader>,
+    disk_space: Option<watch::Receiver<DiskSpace>>,
     hooks: RpcHooks<Node>,
 ) -> eyre::Result<(RethRpcServerHandles, RpcRegistry<Node>)>
 where
@@ -264,14 +270,19 @@ where
     let module_config = config.rpc.transport_rpc_module_config();
     debug!(target: "reth::cli", http=?module_config.http(), ws=?module_config.ws(), "Using RPC module config");
 
-    let (mut modules, mut auth_module, registry) = RpcModuleBuilder::default()
+    let mut builder = RpcModuleBuilder::default()
         .with_provider(node.provider().clone())
         .with_pool(node.pool().clone())
         .with_network(node.network().clone())
         .with_events(node.provider().clone())
         .with_executor(node.task_executor().clone())
         .with_evm_config(node.evm_config())
-        .build_with_auth_server(module_config, engine_api);
+        .with_bad_block_reader(bad_blocks);
+    if let Some(disk_space) = disk_space {
+        builder = builder.with_disk_space(disk_space);
+    }
+    let (mut modules, mut auth_module, registry) =
+        builder.build_with_auth_server(module_config, engine_api);
 
     let mut registry = RpcRegistry { registry };
     let ctx = RpcContext {
@@ -1,9 +1,12 @@
 //! clap [Args](clap::Args) for database configuration
 
 use clap::Args;
-use reth_interfaces::db::LogLevel;
+use humantime::parse_duration;
+use reth_db::mdbx::MaxReadTransactionDuration;
+use reth_interfaces::db::{LogLevel, SyncMode};
+use std::time::Duration;
 
-use crate::version::default_client_version;
+use crate::{args::utils::parse_byte_size, version::default_client_version};
 
 /// Parameters for database configuration
 #[derive(Debug, Args, PartialEq, Eq, Default, Clone, Copy)]
@@ -16,6 +19,37 @@ pub struct DatabaseArgs {
     /// NFS volume.
     #[arg(long = "db.exclusive")]
     pub exclusive: Option<bool>,
+    /// Maximum duration of a read transaction before it's forcibly aborted.
+    ///
+    /// Long-lived read transactions prevent MDBX from reusing freed pages, which makes the
+    /// database grow. Defaults to 5 minutes.
+    ///
+    /// Parses strings using [humantime::parse_duration]
+    /// --db.max-read-transaction-duration 30s
+    #[arg(
+        long = "db.max-read-transaction-duration",
+        value_parser = parse_duration,
+        verbatim_doc_comment
+    )]
+    pub max_read_transaction_duration: Option<Duration>,
+    /// Maximum size of the database, e.g. `8TB`. Defaults to 4TB.
+    #[arg(long = "db.max-size", value_parser = parse_byte_size)]
+    pub max_size: Option<usize>,
+    /// Step by which the database file grows when it runs out of space, e.g. `4GB`. Defaults to
+    /// 4GB.
+    #[arg(long = "db.growth-step", value_parser = parse_byte_size)]
+    pub growth_step: Option<usize>,
+    /// Durability of committed database transactions. Defaults to `durable`.
+    ///
+    /// Modes other than `durable` may lose the most recent commits on a system crash, but keep
+    /// the database consistent.
+    #[arg(long = "db.sync-mode", value_enum)]
+    pub sync_mode: Option<SyncMode>,
+    /// Enable OS read-ahead for the database file.
+    ///
+    /// Speeds up linear scans on slow storage, but slows down random access.
+    #[arg(long = "db.read-ahead")]
+    pub read_ahead: Option<bool>,
 }
 
 impl DatabaseArgs {
@@ -24,6 +58,13 @@ impl DatabaseArgs {
         reth_db::mdbx::DatabaseArguments::new(default_client_version())
             .with_log_level(self.log_level)
             .with_exclusive(self.exclusive)
+            .with_max_read_transaction_duration(
+                self.max_read_transaction_duration.map(MaxReadTransactionDuration::Set),
+            )
+            .with_max_size(self.max_size)
+            .with_growth_step(self.growth_step)
+            .with_sync_mode(self.sync_mode)
+            .with_read_ahead(self.read_ahead)
     }
 }
 
@@ -45,4 +86,35 @@ mod tests {
         let args = CommandParser::<DatabaseArgs>::parse_from(["reth"]).args;
         assert_eq!(args, default_args);
     }
+
+    #[test]
+    fn test_parse_max_read_transaction_duration() {
+        let args = CommandParser::<DatabaseArgs>::parse_from([
+            "reth",
+            "--db.max-read-transaction-duration",
+            "30s",
+        ])
+        .args;
+        assert_eq!(args.max_read_transaction_duration, Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_parse_geometry_and_sync_mode() {
+        let args = CommandParser::<DatabaseArgs>::parse_from([
+            "reth",
+            "--db.max-size",
+            "8TB",
+            "--db.growth-step",
+            "1GB",
+            "--db.sync-mode",
+            "safe-no-sync",
+            "--db.read-ahead",
+            "true",
+        ])
+        .args;
+        assert_eq!(args.max_size, Some(8 * 1024 * 1024 * 1024 * 1024));
+        assert_eq!(args.growth_step, Some(1024 * 1024 * 1024));
+        assert_eq!(args.sync_mode, Some(SyncMode::SafeNoSync));
+        assert_eq!(args.read_ahead, Some(true));
+    }
 }
@@ -64,6 +64,18 @@ pub struct DebugArgs {
     /// will be written to specified location.
     #[arg(long = "debug.engine-api-store", help_heading = "Debug", value_name = "PATH")]
     pub engine_api_store: Option<PathBuf>,
+
+    /// Re-execute one in N canonical blocks on average to cross-check their stored receipts, logs
+    /// bloom and gas used.
+    ///
+    /// Discrepancies are logged and reported in the `spot_check_discrepancies` metric.
+    #[arg(
+        long = "debug.spot-check-rate",
+        help_heading = "Debug",
+        value_name = "N",
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    pub spot_check_rate: Option<u32>,
 }
 
 #[cfg(test)]
@@ -0,0 +1,71 @@
+//! clap [Args](clap::Args) for disk space monitoring
+
+use crate::args::utils::parse_byte_size;
+use clap::Args;
+
+/// The default free disk space below which a warning is logged.
+const DEFAULT_WARN_THRESHOLD: usize = 100 * 1024 * 1024 * 1024;
+
+/// The default free disk space below which block ingest is paused.
+const DEFAULT_CRITICAL_THRESHOLD: usize = 10 * 1024 * 1024 * 1024;
+
+/// Parameters for monitoring the free disk space of the data directory
+#[derive(Debug, Clone, Copy, Args, PartialEq, Eq)]
+#[command(next_help_heading = "Disk")]
+pub struct DiskArgs {
+    /// Free disk space below which a warning is logged, e.g. `100GB`.
+    ///
+    /// Must be above the critical threshold.
+    #[arg(long = "disk.warn-threshold", value_parser = parse_byte_size, default_value = "100GB")]
+    pub warn_threshold: usize,
+
+    /// Free disk space below which block ingest is paused until space is freed, e.g. `10GB`.
+    ///
+    /// Pruning keeps running while block ingest is paused.
+    #[arg(long = "disk.critical-threshold", value_parser = parse_byte_size, default_value = "10GB")]
+    pub critical_threshold: usize,
+
+    /// Disable the disk space monitor.
+    #[arg(long = "disk.disable-monitor")]
+    pub disable_monitor: bool,
+}
+
+impl Default for DiskArgs {
+    fn default() -> Self {
+        Self {
+            warn_threshold: DEFAULT_WARN_THRESHOLD,
+            critical_threshold: DEFAULT_CRITICAL_THRESHOLD,
+            disable_monitor: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_parse_disk_args() {
+        let args = CommandParser::<DiskArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, DiskArgs::default());
+
+        let args = CommandParser::<DiskArgs>::parse_from([
+            "reth",
+            "--disk.warn-threshold",
+            "20GB",
+            "--disk.critical-threshold",
+            "512MB",
+        ])
+        .args;
+        assert_eq!(args.warn_threshold, 20 * 1024 * 1024 * 1024);
+        assert_eq!(args.critical_threshold, 512 * 1024 * 1024);
+    }
+}
@@ -16,7 +16,7 @@ const MB_TO_BYTES: u64 = 1024 * 1024;
 #[command(next_help_heading = "Logging")]
 pub struct LogArgs {
     /// The format to use for logs written to stdout.
-    #[arg(long = "log.stdout.format", value_name = "FORMAT", global = true, default_value_t = LogFormat::Terminal)]
+    #[arg(long = "log.stdout.format", alias = "log.format", value_name = "FORMAT", global = true, default_value_t = LogFormat::Terminal)]
     pub log_stdout_format: LogFormat,
 
     /// The filter to use for logs written to stdout.
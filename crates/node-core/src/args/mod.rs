@@ -51,6 +51,18 @@ pub use dev_args::DevArgs;
 mod pruning_args;
 pub use pruning_args::PruningArgs;
 
+/// StaticFilesArgs for configuring moving data to static files
+mod static_files_args;
+pub use static_files_args::StaticFilesArgs;
+
+/// DiskArgs for configuring the disk space monitor
+mod disk_args;
+pub use disk_args::DiskArgs;
+
+/// ThreadPoolArgs for configuring the node's thread pools
+mod thread_pool_args;
+pub use thread_pool_args::ThreadPoolArgs;
+
 pub mod utils;
 
 pub mod types;
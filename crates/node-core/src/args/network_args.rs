@@ -98,6 +98,18 @@ pub struct NetworkArgs {
     /// is 128 KiB.
     #[arg(long = "pooled-tx-pack-soft-limit", value_name = "BYTES", default_value_t = DEFAULT_SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESP_ON_PACK_GET_POOLED_TRANSACTIONS_REQ)]
     pub soft_limit_byte_size_pooled_transactions_response_on_pack_request: usize,
+
+    /// Disable the devp2p networking stack entirely.
+    ///
+    /// No network manager is started: there are no discovery services, no RLPx listener and no
+    /// peers are dialed or accepted. Transaction gossip and the eth request handler are not
+    /// spawned either. This is intended for nodes that are driven exclusively via the engine API
+    /// or another local block source.
+    ///
+    /// Builds with the `disable-p2p` feature never start the devp2p networking stack, regardless
+    /// of this flag.
+    #[arg(long = "disable-p2p", conflicts_with_all = ["trusted_peers", "trusted_only", "bootnodes"])]
+    pub disable_p2p: bool,
 }
 
 impl NetworkArgs {
@@ -117,11 +129,14 @@ impl NetworkArgs {
         let peers_file = self.peers_file.clone().unwrap_or(default_peers_file);
 
         // Configure peer connections
-        let peers_config = config
+        let mut peers_config = config
             .peers
             .clone()
             .with_max_inbound_opt(self.max_inbound_peers)
             .with_max_outbound_opt(self.max_outbound_peers);
+        if self.disable_p2p {
+            peers_config = peers_config.with_max_inbound(0).with_max_outbound(0);
+        }
 
         // Configure transactions manager
         let transactions_manager_config = TransactionsManagerConfig {
@@ -148,6 +163,13 @@ impl NetworkArgs {
             HelloMessageWithProtocols::builder(peer_id).client_version(&self.identity).build(),
         );
 
+        if self.disable_p2p {
+            return network_config_builder
+                .boot_nodes(Vec::new())
+                .disable_discovery()
+                .disable_tx_gossip(true)
+        }
+
         self.discovery.apply_to_builder(network_config_builder)
     }
 
@@ -160,6 +182,17 @@ impl NetworkArgs {
         Some(peers_file)
     }
 
+    /// Returns `true` if the devp2p networking stack is disabled.
+    pub const fn is_p2p_disabled(&self) -> bool {
+        self.disable_p2p
+    }
+
+    /// Disables the devp2p networking stack.
+    pub const fn with_disabled_p2p(mut self) -> Self {
+        self.disable_p2p = true;
+        self
+    }
+
     /// Sets the p2p port to zero, to allow the OS to assign a random unused port when
     /// the network components bind to a socket.
     pub fn with_unused_p2p_port(mut self) -> Self {
@@ -195,6 +228,7 @@ impl Default for NetworkArgs {
             soft_limit_byte_size_pooled_transactions_response:
                 SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESPONSE,
             soft_limit_byte_size_pooled_transactions_response_on_pack_request: DEFAULT_SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESP_ON_PACK_GET_POOLED_TRANSACTIONS_REQ,
+            disable_p2p: false,
         }
     }
 }
@@ -340,6 +374,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_disable_p2p_args() {
+        let args = CommandParser::<NetworkArgs>::parse_from(["reth"]).args;
+        assert!(!args.disable_p2p);
+
+        let args = CommandParser::<NetworkArgs>::parse_from(["reth", "--disable-p2p"]).args;
+        assert!(args.disable_p2p);
+
+        let err = CommandParser::<NetworkArgs>::try_parse_from([
+            "reth",
+            "--disable-p2p",
+            "--trusted-only",
+        ])
+        .unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
     #[cfg(not(feature = "optimism"))]
     #[test]
     fn network_args_default_sanity_test() {
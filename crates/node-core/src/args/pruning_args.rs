@@ -15,6 +15,13 @@ pub struct PruningArgs {
     /// This flag takes priority over pruning configuration in reth.toml.
     #[arg(long, default_value_t = false)]
     pub full: bool,
+
+    /// Maximum number of rows deleted in a single pruner run, which happens every few blocks.
+    ///
+    /// By default, the limit grows with the number of blocks since the previous run. Lowering it
+    /// spreads pruning out over more runs and avoids latency spikes on busy RPC nodes.
+    #[arg(long = "prune.max-rows-per-run", value_name = "ROWS")]
+    pub max_rows_per_run: Option<usize>,
 }
 
 impl PruningArgs {
@@ -66,4 +73,12 @@ mod tests {
         let args = CommandParser::<PruningArgs>::parse_from(["reth"]).args;
         assert_eq!(args, default_args);
     }
+
+    #[test]
+    fn parse_max_rows_per_run() {
+        let args =
+            CommandParser::<PruningArgs>::parse_from(["reth", "--prune.max-rows-per-run", "1000"])
+                .args;
+        assert_eq!(args.max_rows_per_run, Some(1000));
+    }
 }
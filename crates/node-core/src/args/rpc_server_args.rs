@@ -3,6 +3,7 @@
 use crate::{
     args::{
         types::{MaxU32, ZeroAsNoneU64},
+        utils::parse_byte_size,
         GasPriceOracleArgs, RpcStateCacheArgs,
     },
     cli::config::RethRpcConfig,
@@ -16,12 +17,13 @@ use rand::Rng;
 use reth_network_api::{NetworkInfo, Peers};
 use reth_node_api::{ConfigureEvmEnv, EngineTypes};
 use reth_provider::{
-    AccountReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader,
-    EvmEnvProvider, HeaderProvider, StateProviderFactory,
+    AccountHistoryReader, AccountReader, BlockReaderIdExt, CanonStateSubscriptions,
+    ChainSpecProvider, ChangeSetReader, EvmEnvProvider, HeaderProvider, ReadSnapshotFactory,
+    StateProviderFactory, StaticFileProviderFactory,
 };
 use reth_rpc::{
     eth::{cache::EthStateCacheConfig, gas_oracle::GasPriceOracleConfig, RPC_DEFAULT_GAS_CAP},
-    JwtError, JwtSecret,
+    JwtError, JwtSecret, TraceCacheConfig, DEFAULT_TRACE_CACHE_MAX_SIZE,
 };
 use reth_rpc_builder::{
     auth::{AuthServerConfig, AuthServerHandle},
@@ -169,6 +171,24 @@ pub struct RpcServerArgs {
     )]
     pub rpc_gas_cap: u64,
 
+    /// Directory for caching the results of `debug_traceBlock*` calls with the `callTracer` or
+    /// `prestateTracer` across restarts.
+    ///
+    /// Block traces aren't cached if this isn't set.
+    #[arg(long = "rpc.trace-cache-dir", value_name = "PATH")]
+    pub rpc_trace_cache_dir: Option<PathBuf>,
+
+    /// Maximum total size of the cached block traces, e.g. `1GB`.
+    #[arg(long = "rpc.trace-cache-max-size", value_parser = parse_byte_size, default_value = "1GB")]
+    pub rpc_trace_cache_max_size: usize,
+
+    /// Trace the transactions of blocks with at least this many transactions in parallel in
+    /// `debug_traceBlock*` and `trace_block`.
+    ///
+    /// Blocks are traced sequentially if this isn't set.
+    #[arg(long = "rpc.parallel-tracing-min-txs", value_name = "COUNT")]
+    pub rpc_parallel_tracing_min_txs: Option<usize>,
+
     /// State cache configuration.
     #[command(flatten)]
     pub rpc_state_cache: RpcStateCacheArgs,
@@ -281,6 +301,9 @@ impl RpcServerArgs {
             + EvmEnvProvider
             + ChainSpecProvider
             + ChangeSetReader
+            + AccountHistoryReader
+            + StaticFileProviderFactory
+            + ReadSnapshotFactory
             + Clone
             + Unpin
             + 'static,
@@ -364,6 +387,14 @@ impl RethRpcConfig for RpcServerArgs {
             .rpc_gas_cap(self.rpc_gas_cap)
             .state_cache(self.state_cache_config())
             .gpo_config(self.gas_price_oracle_config())
+            .trace_cache(self.trace_cache_config())
+            .parallel_tracing_min_transactions(self.rpc_parallel_tracing_min_txs)
+    }
+
+    /// Returns the config of the block trace cache, if enabled.
+    fn trace_cache_config(&self) -> Option<TraceCacheConfig> {
+        let dir = self.rpc_trace_cache_dir.as_ref()?;
+        Some(TraceCacheConfig::new(dir).max_size(self.rpc_trace_cache_max_size as u64))
     }
 
     fn state_cache_config(&self) -> EthStateCacheConfig {
@@ -503,6 +534,9 @@ impl Default for RpcServerArgs {
             rpc_max_blocks_per_filter: constants::DEFAULT_MAX_BLOCKS_PER_FILTER.into(),
             rpc_max_logs_per_response: (constants::DEFAULT_MAX_LOGS_PER_RESPONSE as u64).into(),
             rpc_gas_cap: RPC_DEFAULT_GAS_CAP.into(),
+            rpc_trace_cache_dir: None,
+            rpc_trace_cache_max_size: DEFAULT_TRACE_CACHE_MAX_SIZE as usize,
+            rpc_parallel_tracing_min_txs: None,
             gas_price_oracle: GasPriceOracleArgs::default(),
             rpc_state_cache: RpcStateCacheArgs::default(),
         }
@@ -725,6 +759,25 @@ mod tests {
         assert_eq!(config.max_logs_per_response, Some(200));
     }
 
+    #[test]
+    fn test_trace_cache_config() {
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        assert_eq!(args.eth_config().trace_cache, None);
+
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc.trace-cache-dir",
+            "/tmp/traces",
+            "--rpc.trace-cache-max-size",
+            "512MB",
+        ])
+        .args;
+        assert_eq!(
+            args.eth_config().trace_cache,
+            Some(TraceCacheConfig::new("/tmp/traces").max_size(512 * 1024 * 1024))
+        );
+    }
+
     #[test]
     fn rpc_server_args_default_sanity_test() {
         let default_args = RpcServerArgs::default();
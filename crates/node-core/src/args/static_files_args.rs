@@ -0,0 +1,110 @@
+//! clap [Args](clap::Args) for static files configuration
+
+use clap::Args;
+use humantime::parse_duration;
+use std::time::Duration;
+
+/// Parameters for moving data from the database to static files
+#[derive(Debug, Clone, Copy, Args, PartialEq, Eq, Default)]
+#[command(next_help_heading = "Static Files")]
+pub struct StaticFilesArgs {
+    /// Move headers, transactions and receipts of blocks that are at least this many blocks
+    /// behind the tip from the database to static files, without waiting for them to be
+    /// finalized.
+    ///
+    /// By default, only finalized blocks are moved. This keeps the database small on nodes that
+    /// don't receive finalized blocks from a consensus layer. The distance must be larger than
+    /// the deepest expected reorg.
+    #[arg(long = "static-files.tip-distance", value_name = "BLOCKS")]
+    pub tip_distance: Option<u64>,
+
+    /// Maximum number of static files that are kept open at the same time.
+    ///
+    /// Every open static file holds two file descriptors and memory maps. When the limit is
+    /// reached, the least recently used static files are closed. By default, all static files are
+    /// kept open once they were accessed.
+    #[arg(long = "static-files.max-open", value_name = "FILES", value_parser = clap::value_parser!(u64).range(1..))]
+    pub max_open: Option<u64>,
+
+    /// Number of blocks stored in each static file.
+    ///
+    /// Smaller files allow unwinding with a finer granularity, while larger files keep the number
+    /// of files low on chains with fast block times. Must match the existing static files, whose
+    /// block count is detected automatically. Defaults to 500000 for new data directories.
+    #[arg(long = "static-files.blocks-per-file", value_name = "BLOCKS", value_parser = clap::value_parser!(u64).range(1..))]
+    pub blocks_per_file: Option<u64>,
+
+    /// Log a warning with diagnostics when a static file commit takes longer than this.
+    ///
+    /// Slow commits usually mean that the disk can't keep up with the ingest rate. Defaults to
+    /// 1s.
+    ///
+    /// Parses strings using [humantime::parse_duration]
+    #[arg(long = "static-files.slow-commit-threshold", value_name = "DURATION", value_parser = parse_duration)]
+    pub slow_commit_threshold: Option<Duration>,
+
+    /// Truncate static files to the last row committed to the database on startup.
+    ///
+    /// An unclean shutdown can leave rows in static files that the database never committed. The
+    /// discarded rows are logged. If static files are missing rows the database committed, the
+    /// database is unwound to the highest block the static files hold.
+    #[arg(long = "static-files.auto-heal", visible_alias = "auto-heal")]
+    pub auto_heal: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_parse_static_files_args() {
+        let args = CommandParser::<StaticFilesArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, StaticFilesArgs::default());
+
+        let args = CommandParser::<StaticFilesArgs>::parse_from([
+            "reth",
+            "--static-files.tip-distance",
+            "1024",
+        ])
+        .args;
+        assert_eq!(args.tip_distance, Some(1024));
+
+        let args =
+            CommandParser::<StaticFilesArgs>::parse_from(["reth", "--static-files.max-open", "64"])
+                .args;
+        assert_eq!(args.max_open, Some(64));
+
+        let args = CommandParser::<StaticFilesArgs>::parse_from([
+            "reth",
+            "--static-files.blocks-per-file",
+            "100000",
+        ])
+        .args;
+        assert_eq!(args.blocks_per_file, Some(100_000));
+
+        let args = CommandParser::<StaticFilesArgs>::parse_from([
+            "reth",
+            "--static-files.slow-commit-threshold",
+            "500ms",
+        ])
+        .args;
+        assert_eq!(args.slow_commit_threshold, Some(Duration::from_millis(500)));
+
+        let args = CommandParser::<StaticFilesArgs>::parse_from(["reth", "--auto-heal"]).args;
+        assert!(args.auto_heal);
+        assert!(CommandParser::<StaticFilesArgs>::try_parse_from([
+            "reth",
+            "--static-files.max-open",
+            "0"
+        ])
+        .is_err());
+    }
+}
@@ -0,0 +1,134 @@
+//! clap [Args](clap::Args) for thread pool configuration
+
+use clap::{builder::RangedU64ValueParser, Args};
+use std::{cmp::max, thread::available_parallelism};
+
+/// Number of cores that are reserved for the rest of the system when sizing the rayon pool.
+const RESERVED_CORES: usize = 2;
+
+/// Number of cores a thread can be pinned to, the size of the Linux CPU set.
+const MAX_PINNED_CORES: usize = 1024;
+
+/// Parameters for configuring the thread pools used by the node
+#[derive(Debug, Clone, Args, PartialEq, Eq, Default)]
+#[command(next_help_heading = "Threads")]
+pub struct ThreadPoolArgs {
+    /// Number of threads in the global rayon thread pool.
+    ///
+    /// This pool is used for CPU heavy work such as sender recovery, static file hashing and
+    /// state root computation.
+    ///
+    /// Defaults to the number of available cores minus 2, with a minimum of 2.
+    #[arg(
+        long = "threads.rayon",
+        value_name = "NUM",
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    pub rayon_threads: Option<usize>,
+
+    /// Maximum number of threads in the tokio blocking pool.
+    ///
+    /// Blocking tasks include database commits of the consensus engine and pipeline. Defaults to
+    /// the tokio default of 512.
+    #[arg(
+        long = "threads.blocking",
+        value_name = "NUM",
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    pub max_blocking_threads: Option<usize>,
+
+    /// Comma separated list of CPU cores to pin the rayon worker threads to.
+    ///
+    /// Worker threads are assigned to the given cores in a round-robin fashion. Cores must be
+    /// lower than 1024. Only supported on Linux, ignored with a warning on other platforms.
+    #[arg(
+        long = "threads.pin-cores",
+        value_name = "CORES",
+        value_delimiter = ',',
+        value_parser = RangedU64ValueParser::<usize>::new().range(..MAX_PINNED_CORES as u64)
+    )]
+    pub pin_cores: Vec<usize>,
+}
+
+impl ThreadPoolArgs {
+    /// Returns the number of threads to use for the global rayon thread pool.
+    ///
+    /// If not configured, this reserves 2 cores for the rest of the system.
+    pub fn rayon_threads(&self) -> usize {
+        self.rayon_threads.unwrap_or_else(|| {
+            available_parallelism()
+                .map_or(25, |cpus| max(cpus.get().saturating_sub(RESERVED_CORES), 2))
+        })
+    }
+
+    /// Returns the core the rayon worker thread with the given index should be pinned to, if any.
+    pub fn pinned_core(&self, thread_index: usize) -> Option<usize> {
+        if self.pin_cores.is_empty() {
+            return None
+        }
+        Some(self.pin_cores[thread_index % self.pin_cores.len()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_parse_thread_pool_args() {
+        let default_args = ThreadPoolArgs::default();
+        let args = CommandParser::<ThreadPoolArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, default_args);
+
+        let args = CommandParser::<ThreadPoolArgs>::parse_from([
+            "reth",
+            "--threads.rayon",
+            "4",
+            "--threads.blocking",
+            "16",
+            "--threads.pin-cores",
+            "2,3",
+        ])
+        .args;
+        assert_eq!(args.rayon_threads(), 4);
+        assert_eq!(args.max_blocking_threads, Some(16));
+        assert_eq!(args.pinned_core(0), Some(2));
+        assert_eq!(args.pinned_core(1), Some(3));
+        assert_eq!(args.pinned_core(2), Some(2));
+    }
+
+    #[test]
+    fn test_reject_zero_threads() {
+        assert!(CommandParser::<ThreadPoolArgs>::try_parse_from(["reth", "--threads.rayon", "0"])
+            .is_err());
+    }
+
+    #[test]
+    fn test_reject_out_of_range_cores() {
+        let max_core = (MAX_PINNED_CORES - 1).to_string();
+        let args = CommandParser::<ThreadPoolArgs>::parse_from([
+            "reth",
+            "--threads.pin-cores",
+            &format!("0,{max_core}"),
+        ])
+        .args;
+        assert_eq!(args.pin_cores, vec![0, MAX_PINNED_CORES - 1]);
+
+        for cores in ["1024", "0,1024", "-1", "a"] {
+            assert!(CommandParser::<ThreadPoolArgs>::try_parse_from([
+                "reth",
+                "--threads.pin-cores",
+                cores
+            ])
+            .is_err());
+        }
+    }
+}
@@ -28,6 +28,25 @@ pub fn parse_duration_from_secs(arg: &str) -> eyre::Result<Duration, std::num::P
     Ok(Duration::from_secs(seconds))
 }
 
+/// Helper to parse a size in bytes, with an optional binary unit suffix, e.g. `512MB` or `4TB`.
+pub fn parse_byte_size(arg: &str) -> eyre::Result<usize> {
+    let arg = arg.trim();
+    let split = arg.find(|c: char| !c.is_ascii_digit()).unwrap_or(arg.len());
+    let (value, unit) = arg.split_at(split);
+    let multiplier: usize = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "KB" => 1024,
+        "MB" => 1024 * 1024,
+        "GB" => 1024 * 1024 * 1024,
+        "TB" => 1024 * 1024 * 1024 * 1024,
+        unit => eyre::bail!("unknown size unit: {unit}"),
+    };
+    value
+        .parse::<usize>()?
+        .checked_mul(multiplier)
+        .ok_or_else(|| eyre::eyre!("size is too large: {arg}"))
+}
+
 /// Clap value parser for [ChainSpec]s that takes either a built-in chainspec or the path
 /// to a custom one.
 pub fn chain_spec_value_parser(s: &str) -> eyre::Result<Arc<ChainSpec>, eyre::Error> {
@@ -168,6 +187,15 @@ mod tests {
     use secp256k1::rand::thread_rng;
     use std::collections::HashMap;
 
+    #[test]
+    fn parse_byte_sizes() {
+        assert_eq!(parse_byte_size("1024").unwrap(), 1024);
+        assert_eq!(parse_byte_size("512MB").unwrap(), 512 * 1024 * 1024);
+        assert_eq!(parse_byte_size("4tb").unwrap(), 4 * 1024 * 1024 * 1024 * 1024);
+        assert!(parse_byte_size("4XB").is_err());
+        assert!(parse_byte_size("GB").is_err());
+    }
+
     #[test]
     fn parse_known_chain_spec() {
         for chain in SUPPORTED_CHAINS {
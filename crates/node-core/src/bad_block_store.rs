@@ -0,0 +1,256 @@
+//! Stores blocks that were rejected as invalid to disk, so they can be inspected later.
+
+use futures::{Stream, StreamExt};
+use parking_lot::RwLock;
+use reth_beacon_consensus::BeaconConsensusEngineEvent;
+use reth_interfaces::provider::ProviderResult;
+use reth_primitives::{fs, SealedBlock, B256};
+use reth_provider::{BadBlock, BadBlockReader};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    path::PathBuf,
+    sync::Arc,
+    time::SystemTime,
+};
+use tracing::*;
+
+/// The default maximum number of bad blocks kept by the [BadBlockStore].
+pub const DEFAULT_MAX_BAD_BLOCKS: usize = 128;
+
+/// The on-disk representation of a bad block.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StoredBadBlock {
+    /// The rejected block.
+    block: SealedBlock,
+    /// The reason the block was rejected.
+    reason: String,
+    /// The hash of the transaction that caused the block to be rejected, if known.
+    offending_transaction: Option<B256>,
+}
+
+impl From<StoredBadBlock> for BadBlock {
+    fn from(stored: StoredBadBlock) -> Self {
+        let StoredBadBlock { block, reason, offending_transaction } = stored;
+        Self { block, reason, offending_transaction }
+    }
+}
+
+/// A capped store of blocks that were rejected by consensus or execution validation.
+///
+/// Every bad block is written to its own JSON file in the store directory. Once the configured
+/// maximum is exceeded, the oldest entries are removed from disk.
+#[derive(Debug, Clone)]
+pub struct BadBlockStore {
+    inner: Arc<BadBlockStoreInner>,
+}
+
+#[derive(Debug)]
+struct BadBlockStoreInner {
+    /// The path to the directory that stores the bad blocks.
+    path: PathBuf,
+    /// The maximum number of bad blocks to keep.
+    max_blocks: usize,
+    /// The stored bad blocks and their files, oldest first.
+    blocks: RwLock<VecDeque<(PathBuf, BadBlock)>>,
+}
+
+impl BadBlockStore {
+    /// Opens the [BadBlockStore] at the given path, loading all previously stored bad blocks.
+    ///
+    /// The path is expected to be a directory, where individual bad block JSON files are stored.
+    /// Files that can't be decoded are deleted, since they would otherwise never be evicted.
+    pub fn open(path: PathBuf, max_blocks: usize) -> eyre::Result<Self> {
+        fs::create_dir_all(&path)?;
+
+        let mut filenames_by_ts = BTreeMap::<u128, Vec<PathBuf>>::default();
+        for entry in fs::read_dir(&path)? {
+            let entry = entry?;
+            let filename = entry.file_name();
+            if let Some(filename) = filename.to_str().filter(|n| n.ends_with(".json")) {
+                if let Some(Ok(timestamp)) = filename.split('-').next().map(|n| n.parse::<u128>()) {
+                    filenames_by_ts.entry(timestamp).or_default().push(entry.path());
+                } else {
+                    warn!(target: "reth::bad_blocks", %filename, "Could not parse timestamp from filename");
+                }
+            } else {
+                warn!(target: "reth::bad_blocks", ?filename, "Skipping non json file");
+            }
+        }
+
+        let mut blocks = VecDeque::new();
+        for path in filenames_by_ts.into_values().flatten() {
+            match serde_json::from_slice::<StoredBadBlock>(&fs::read(&path)?) {
+                Ok(stored) => blocks.push_back((path, stored.into())),
+                Err(err) => {
+                    warn!(target: "reth::bad_blocks", ?path, %err, "Removing bad block that failed to decode");
+                    fs::remove_file(&path)?;
+                }
+            }
+        }
+
+        let store = Self {
+            inner: Arc::new(BadBlockStoreInner { path, max_blocks, blocks: RwLock::new(blocks) }),
+        };
+        store.evict()?;
+
+        debug!(target: "reth::bad_blocks", path = ?store.inner.path, len = store.len(), "Opened bad block store");
+        Ok(store)
+    }
+
+    /// Returns the number of stored bad blocks.
+    pub fn len(&self) -> usize {
+        self.inner.blocks.read().len()
+    }
+
+    /// Returns `true` if no bad blocks are stored.
+    pub fn is_empty(&self) -> bool {
+        self.inner.blocks.read().is_empty()
+    }
+
+    /// Writes the given [BadBlock] to disk, appending the `recorded_at` time to the path.
+    ///
+    /// Blocks that are already stored are ignored.
+    pub fn insert(&self, bad_block: BadBlock, recorded_at: SystemTime) -> eyre::Result<()> {
+        let hash = bad_block.block.hash();
+        if self.inner.blocks.read().iter().any(|(_, stored)| stored.block.hash() == hash) {
+            return Ok(())
+        }
+
+        let timestamp =
+            recorded_at.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_millis();
+        let path = self.inner.path.join(format!("{timestamp}-{hash}.json"));
+        let BadBlock { block, reason, offending_transaction } = bad_block;
+        let stored = StoredBadBlock { block, reason, offending_transaction };
+        fs::write(&path, serde_json::to_vec(&stored)?)?;
+
+        self.inner.blocks.write().push_back((path, stored.into()));
+        self.evict()
+    }
+
+    /// Removes the oldest bad blocks until the store is within its configured capacity.
+    ///
+    /// A bad block is only forgotten once its file is deleted, so a file that fails to be deleted
+    /// is retried on the next eviction instead of being leaked.
+    fn evict(&self) -> eyre::Result<()> {
+        let mut blocks = self.inner.blocks.write();
+        while blocks.len() > self.inner.max_blocks {
+            let Some((path, _)) = blocks.front() else { break };
+            if path.exists() {
+                fs::remove_file(path)?;
+            }
+            blocks.pop_front();
+        }
+        Ok(())
+    }
+
+    /// Records all blocks that are reported as invalid by the consensus engine.
+    pub async fn listen<St>(self, mut events: St)
+    where
+        St: Stream<Item = BeaconConsensusEngineEvent> + Unpin,
+    {
+        while let Some(event) = events.next().await {
+            if let BeaconConsensusEngineEvent::InvalidBlock {
+                block,
+                reason,
+                offending_transaction,
+            } = event
+            {
+                let hash = block.hash();
+                let bad_block =
+                    BadBlock { block: Arc::unwrap_or_clone(block), reason, offending_transaction };
+                if let Err(error) = self.insert(bad_block, SystemTime::now()) {
+                    error!(target: "reth::bad_blocks", ?hash, %error, "Failed to store bad block");
+                }
+            }
+        }
+    }
+}
+
+impl BadBlockReader for BadBlockStore {
+    fn bad_blocks(&self) -> ProviderResult<Vec<BadBlock>> {
+        Ok(self.inner.blocks.read().iter().rev().map(|(_, block)| block.clone()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::Header;
+    use std::time::Duration;
+
+    fn bad_block(number: u64) -> BadBlock {
+        let header = Header { number, ..Default::default() }.seal_slow();
+        BadBlock {
+            block: SealedBlock { header, ..Default::default() },
+            reason: "invalid state root".to_string(),
+            offending_transaction: None,
+        }
+    }
+
+    #[test]
+    fn insert_evicts_oldest() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BadBlockStore::open(dir.path().to_path_buf(), 2).unwrap();
+        assert!(store.is_empty());
+
+        for number in 0..3 {
+            store
+                .insert(bad_block(number), SystemTime::UNIX_EPOCH + Duration::from_secs(number))
+                .unwrap();
+        }
+
+        let blocks = store.bad_blocks().unwrap();
+        assert_eq!(blocks.iter().map(|b| b.block.number).collect::<Vec<_>>(), vec![2, 1]);
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 2);
+
+        // duplicates are ignored
+        store.insert(bad_block(2), SystemTime::now()).unwrap();
+        assert_eq!(store.len(), 2);
+
+        // reopening restores the stored blocks
+        let reopened = BadBlockStore::open(dir.path().to_path_buf(), 2).unwrap();
+        assert_eq!(reopened.bad_blocks().unwrap(), blocks);
+    }
+
+    #[test]
+    fn open_removes_undecodable_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BadBlockStore::open(dir.path().to_path_buf(), 2).unwrap();
+        store.insert(bad_block(0), SystemTime::UNIX_EPOCH).unwrap();
+        let undecodable = dir.path().join("1-undecodable.json");
+        fs::write(&undecodable, b"not a bad block").unwrap();
+
+        let reopened = BadBlockStore::open(dir.path().to_path_buf(), 2).unwrap();
+        assert_eq!(reopened.len(), 1);
+        assert!(!undecodable.exists());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn failed_eviction_keeps_file_tracked() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BadBlockStore::open(dir.path().to_path_buf(), 1).unwrap();
+        store.insert(bad_block(0), SystemTime::UNIX_EPOCH).unwrap();
+
+        // a directory in place of the oldest file can't be removed with `remove_file`
+        let oldest = store.inner.blocks.read()[0].0.clone();
+        fs::remove_file(&oldest).unwrap();
+        fs::create_dir_all(oldest.join("nested")).unwrap();
+
+        assert!(store
+            .insert(bad_block(1), SystemTime::UNIX_EPOCH + Duration::from_secs(1))
+            .is_err());
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.inner.blocks.read()[0].0, oldest);
+
+        // once the file can be removed, the next eviction deletes it
+        std::fs::remove_dir_all(&oldest).unwrap();
+        fs::write(&oldest, b"{}").unwrap();
+        store.evict().unwrap();
+        assert_eq!(store.len(), 1);
+        assert!(!oldest.exists());
+        assert_eq!(store.bad_blocks().unwrap()[0].block.number, 1);
+    }
+}
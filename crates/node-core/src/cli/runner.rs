@@ -8,11 +8,22 @@ use tracing::{debug, error, trace};
 /// Executes CLI commands.
 #[derive(Clone, Debug, Default)]
 #[non_exhaustive]
-pub struct CliRunner;
+pub struct CliRunner {
+    /// The maximum number of threads of the tokio blocking pool, if configured.
+    max_blocking_threads: Option<usize>,
+}
 
 // === impl CliRunner ===
 
 impl CliRunner {
+    /// Sets the maximum number of threads of the tokio blocking pool.
+    ///
+    /// If `None`, the tokio default is used.
+    pub fn with_max_blocking_threads(mut self, max_blocking_threads: Option<usize>) -> Self {
+        self.max_blocking_threads = max_blocking_threads;
+        self
+    }
+
     /// Executes the given _async_ command on the tokio runtime until the command future resolves or
     /// until the process receives a `SIGINT` or `SIGTERM` signal.
     ///
@@ -26,7 +37,8 @@ impl CliRunner {
         F: Future<Output = Result<(), E>>,
         E: Send + Sync + From<std::io::Error> + From<reth_tasks::PanickedTaskError> + 'static,
     {
-        let AsyncCliRunner { context, mut task_manager, tokio_runtime } = AsyncCliRunner::new()?;
+        let AsyncCliRunner { context, mut task_manager, tokio_runtime } =
+            AsyncCliRunner::new(self.max_blocking_threads)?;
 
         // Executes the command until it finished or ctrl-c was fired
         let command_res = tokio_runtime.block_on(run_to_completion_or_panic(
@@ -58,7 +70,7 @@ impl CliRunner {
         F: Future<Output = Result<(), E>>,
         E: Send + Sync + From<std::io::Error> + 'static,
     {
-        let tokio_runtime = tokio_runtime()?;
+        let tokio_runtime = tokio_runtime_with_max_blocking_threads(self.max_blocking_threads)?;
         tokio_runtime.block_on(run_until_ctrl_c(fut))?;
         Ok(())
     }
@@ -72,7 +84,7 @@ impl CliRunner {
         F: Future<Output = Result<(), E>> + Send + 'static,
         E: Send + Sync + From<std::io::Error> + 'static,
     {
-        let tokio_runtime = tokio_runtime()?;
+        let tokio_runtime = tokio_runtime_with_max_blocking_threads(self.max_blocking_threads)?;
         let handle = tokio_runtime.handle().clone();
         let fut = tokio_runtime.handle().spawn_blocking(move || handle.block_on(fut));
         tokio_runtime
@@ -99,8 +111,8 @@ struct AsyncCliRunner {
 impl AsyncCliRunner {
     /// Attempts to create a tokio Runtime and additional context required to execute commands
     /// asynchronously.
-    fn new() -> Result<Self, std::io::Error> {
-        let tokio_runtime = tokio_runtime()?;
+    fn new(max_blocking_threads: Option<usize>) -> Result<Self, std::io::Error> {
+        let tokio_runtime = tokio_runtime_with_max_blocking_threads(max_blocking_threads)?;
         let task_manager = TaskManager::new(tokio_runtime.handle().clone());
         let task_executor = task_manager.executor();
         Ok(Self { context: CliContext { task_executor }, task_manager, tokio_runtime })
@@ -120,6 +132,20 @@ pub fn tokio_runtime() -> Result<tokio::runtime::Runtime, std::io::Error> {
     tokio::runtime::Builder::new_multi_thread().enable_all().build()
 }
 
+/// Creates a new default tokio multi-thread [Runtime](tokio::runtime::Runtime) with all features
+/// enabled and the given limit on the number of blocking threads.
+///
+/// If `max_blocking_threads` is `None`, this is equivalent to [tokio_runtime].
+pub fn tokio_runtime_with_max_blocking_threads(
+    max_blocking_threads: Option<usize>,
+) -> Result<tokio::runtime::Runtime, std::io::Error> {
+    let mut builder = tokio::runtime::Builder::new_multi_thread();
+    if let Some(max_blocking_threads) = max_blocking_threads {
+        builder.max_blocking_threads(max_blocking_threads);
+    }
+    builder.enable_all().build()
+}
+
 /// Runs the given future to completion or until a critical task panicked.
 ///
 /// Returns the error if a task panicked, or the given future returned an error.
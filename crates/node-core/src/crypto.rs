@@ -0,0 +1,50 @@
+//! Information about the cryptographic backends reth was built with.
+//!
+//! Keccak hashing dominates trie and state root computation, and secp256k1 public key recovery
+//! dominates sender recovery. Keccak has an accelerated implementation that is selected at build
+//! time via the `asm-keccak` feature, which can be compared with `reth debug crypto-bench`.
+//! Signatures are always recovered with libsecp256k1.
+
+/// The keccak256 implementation used for hashing.
+///
+/// Enable the `asm-keccak` feature to use the assembly implementation.
+pub const KECCAK_BACKEND: &str =
+    if cfg!(feature = "asm-keccak") { "keccak-asm" } else { "tiny-keccak" };
+
+/// Returns the CPU features available on this host that the accelerated backends make use of.
+pub fn accelerated_cpu_features() -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut features = Vec::new();
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if std::arch::is_x86_feature_detected!("avx2") {
+            features.push("avx2");
+        }
+        if std::arch::is_x86_feature_detected!("bmi2") {
+            features.push("bmi2");
+        }
+        if std::arch::is_x86_feature_detected!("sha") {
+            features.push("sha");
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("sha3") {
+            features.push("sha3");
+        }
+    }
+
+    features
+}
+
+/// Logs the cryptographic backends in use and the accelerated CPU features of this host.
+pub fn log_crypto_backends() {
+    tracing::info!(
+        target: "reth::cli",
+        keccak = KECCAK_BACKEND,
+        cpu_features = ?accelerated_cpu_features(),
+        "Cryptographic backends"
+    );
+}
@@ -309,6 +309,14 @@ impl<D> ChainPath<D> {
         self.0.join("blobstore").into()
     }
 
+    /// Returns the path to the directory for this chain where blocks that were rejected as invalid
+    /// are stored.
+    ///
+    /// `<DIR>/<CHAIN_ID>/bad-blocks`
+    pub fn bad_blocks_path(&self) -> PathBuf {
+        self.0.join("bad-blocks").into()
+    }
+
     /// Returns the path to the local transactions backup file
     ///
     /// `<DIR>/<CHAIN_ID>/txpool-transactions-backup.rlp`
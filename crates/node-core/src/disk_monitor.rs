@@ -0,0 +1,198 @@
+//! Monitors the free disk space of the data directory.
+
+use reth_metrics::{metrics::Gauge, Metrics};
+use reth_primitives::U64;
+pub use reth_rpc_types::{DiskSpace, DiskSpaceStatus};
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tokio::sync::watch;
+use tracing::*;
+
+/// The interval at which the free disk space is checked.
+pub const DISK_SPACE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Periodically checks the free space of the filesystems that hold the database and static files.
+///
+/// Running out of space in the middle of a write can leave the database or a static file in an
+/// inconsistent state. Once the free space drops below the critical threshold, the monitor
+/// signals that block ingest should pause, so the node stops writing new blocks at a clean point
+/// instead of failing halfway through. Pruning keeps running, since it's what frees space.
+///
+/// The current [DiskSpace] is exported as metrics and served by `reth_diskSpace`.
+#[derive(Debug)]
+pub struct DiskSpaceMonitor {
+    /// The paths whose filesystems are checked.
+    paths: Vec<PathBuf>,
+    /// Free space in bytes below which a warning is logged.
+    warn_threshold: u64,
+    /// Free space in bytes below which block ingest is paused.
+    critical_threshold: u64,
+    /// The current free disk space.
+    disk_space: watch::Sender<DiskSpace>,
+    /// Whether block ingest should pause, `true` while the status is critical.
+    paused: watch::Sender<bool>,
+    metrics: DiskSpaceMetrics,
+}
+
+impl DiskSpaceMonitor {
+    /// Creates a new [DiskSpaceMonitor] for the given paths and thresholds in bytes.
+    ///
+    /// Fails if the warning threshold isn't above the critical threshold.
+    pub fn new(
+        paths: Vec<PathBuf>,
+        warn_threshold: u64,
+        critical_threshold: u64,
+    ) -> eyre::Result<Self> {
+        if warn_threshold <= critical_threshold {
+            eyre::bail!(
+                "disk space warning threshold ({warn_threshold} bytes) must be above the critical threshold ({critical_threshold} bytes)"
+            )
+        }
+        Ok(Self {
+            paths,
+            warn_threshold,
+            critical_threshold,
+            disk_space: watch::channel(DiskSpace::default()).0,
+            paused: watch::channel(false).0,
+            metrics: DiskSpaceMetrics::default(),
+        })
+    }
+
+    /// Returns a receiver for the current [DiskSpace].
+    pub fn disk_space(&self) -> watch::Receiver<DiskSpace> {
+        self.disk_space.subscribe()
+    }
+
+    /// Returns a receiver that is `true` while the free disk space is critically low and block
+    /// ingest should pause.
+    pub fn pause_signal(&self) -> watch::Receiver<bool> {
+        self.paused.subscribe()
+    }
+
+    /// Checks the free space of all paths and updates the status based on the lowest one.
+    pub fn check(&self) -> io::Result<DiskSpaceStatus> {
+        let mut available = u64::MAX;
+        for path in &self.paths {
+            available = available.min(available_space(path)?);
+        }
+
+        let status = if available < self.critical_threshold {
+            DiskSpaceStatus::Critical
+        } else if available < self.warn_threshold {
+            DiskSpaceStatus::Low
+        } else {
+            DiskSpaceStatus::Ok
+        };
+
+        self.metrics.available_bytes.set(available as f64);
+        self.metrics.status.set(status as u8 as f64);
+
+        let previous = self
+            .disk_space
+            .send_replace(DiskSpace { status, available: Some(U64::from(available)) })
+            .status;
+        self.paused.send_replace(status == DiskSpaceStatus::Critical);
+        match status {
+            DiskSpaceStatus::Critical if previous != status => {
+                error!(target: "reth::disk", available, threshold = self.critical_threshold, "Free disk space is critically low, pausing block ingest");
+            }
+            DiskSpaceStatus::Low if previous != status => {
+                warn!(target: "reth::disk", available, threshold = self.warn_threshold, "Free disk space is low");
+            }
+            DiskSpaceStatus::Ok if previous != status => {
+                info!(target: "reth::disk", available, "Free disk space recovered");
+            }
+            _ => {}
+        }
+
+        Ok(status)
+    }
+
+    /// Checks the free disk space every [DISK_SPACE_CHECK_INTERVAL].
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(DISK_SPACE_CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(err) = self.check() {
+                debug!(target: "reth::disk", %err, "Failed to check free disk space");
+            }
+        }
+    }
+}
+
+/// Returns the space in bytes available to unprivileged users on the filesystem of the path.
+#[cfg(unix)]
+pub fn available_space(path: &Path) -> io::Result<u64> {
+    use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+    let path = CString::new(path.as_os_str().as_bytes())?;
+    let mut stat = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+    // SAFETY: `path` is a valid NUL-terminated string and `stat` is only read if the call succeeds
+    let stat = unsafe {
+        if libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) != 0 {
+            return Err(io::Error::last_os_error())
+        }
+        stat.assume_init()
+    };
+    #[allow(clippy::unnecessary_cast)]
+    Ok(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
+/// Returns the space in bytes available to unprivileged users on the filesystem of the path.
+#[cfg(not(unix))]
+pub fn available_space(_path: &Path) -> io::Result<u64> {
+    Err(io::Error::new(io::ErrorKind::Unsupported, "free disk space can only be checked on unix"))
+}
+
+/// Metrics for the [DiskSpaceMonitor].
+#[derive(Metrics)]
+#[metrics(scope = "disk")]
+struct DiskSpaceMetrics {
+    /// The lowest free space in bytes of the monitored filesystems
+    available_bytes: Gauge,
+    /// The disk space status: 0 if ok, 1 if low and 2 if critical
+    status: Gauge,
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_from_thresholds() {
+        let dir = tempfile::tempdir().unwrap();
+        let available = available_space(dir.path()).unwrap();
+        assert!(available > 0);
+
+        let monitor = DiskSpaceMonitor::new(vec![dir.path().to_path_buf()], 1, 0).unwrap();
+        let disk_space = monitor.disk_space();
+        let paused = monitor.pause_signal();
+        assert_eq!(*disk_space.borrow(), DiskSpace::default());
+        assert_eq!(monitor.check().unwrap(), DiskSpaceStatus::Ok);
+        assert_eq!(disk_space.borrow().status, DiskSpaceStatus::Ok);
+        assert!(disk_space.borrow().available.is_some());
+        assert!(!*paused.borrow());
+
+        let monitor = DiskSpaceMonitor::new(vec![dir.path().to_path_buf()], u64::MAX, 0).unwrap();
+        assert_eq!(monitor.check().unwrap(), DiskSpaceStatus::Low);
+
+        let monitor =
+            DiskSpaceMonitor::new(vec![dir.path().to_path_buf()], u64::MAX, u64::MAX - 1).unwrap();
+        let disk_space = monitor.disk_space();
+        let paused = monitor.pause_signal();
+        assert_eq!(monitor.check().unwrap(), DiskSpaceStatus::Critical);
+        assert_eq!(disk_space.borrow().status, DiskSpaceStatus::Critical);
+        assert!(*paused.borrow());
+    }
+
+    #[test]
+    fn rejects_warn_threshold_not_above_critical() {
+        let paths = vec![PathBuf::from(".")];
+        assert!(DiskSpaceMonitor::new(paths.clone(), 10, 10).is_err());
+        assert!(DiskSpaceMonitor::new(paths.clone(), 5, 10).is_err());
+        assert!(DiskSpaceMonitor::new(paths, 11, 10).is_ok());
+    }
+}
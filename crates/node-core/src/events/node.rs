@@ -286,6 +286,9 @@ impl<DB> NodeState<DB> {
             BeaconConsensusEngineEvent::ForkBlockAdded(block) => {
                 info!(number=block.number, hash=?block.hash(), "Block added to fork chain");
             }
+            BeaconConsensusEngineEvent::InvalidBlock { block, offending_transaction, .. } => {
+                debug!(number=block.number, hash=?block.hash(), ?offending_transaction, "Block rejected as invalid");
+            }
         }
     }
 
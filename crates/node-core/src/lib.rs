@@ -9,14 +9,20 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 pub mod args;
+pub mod bad_block_store;
 pub mod cli;
+pub mod crypto;
 pub mod dirs;
+pub mod disk_monitor;
 pub mod engine_api_store;
 pub mod events;
 pub mod exit;
 pub mod init;
 pub mod metrics;
 pub mod node_config;
+pub mod reorg_monitor;
+pub mod spot_check;
+pub mod static_file_heal;
 pub mod utils;
 pub mod version;
 
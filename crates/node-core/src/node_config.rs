@@ -2,8 +2,9 @@
 
 use crate::{
     args::{
-        get_secret_key, DatabaseArgs, DebugArgs, DevArgs, DiscoveryArgs, NetworkArgs,
-        PayloadBuilderArgs, PruningArgs, RpcServerArgs, TxPoolArgs,
+        get_secret_key, DatabaseArgs, DebugArgs, DevArgs, DiscoveryArgs, DiskArgs, NetworkArgs,
+        PayloadBuilderArgs, PruningArgs, RpcServerArgs, StaticFilesArgs, ThreadPoolArgs,
+        TxPoolArgs,
     },
     cli::config::RethTransactionPoolConfig,
     dirs::{ChainPath, DataDirPath},
@@ -24,8 +25,8 @@ use reth_config::{
 };
 use reth_db::{database::Database, database_metrics::DatabaseMetrics};
 use reth_downloaders::{
-    bodies::bodies::BodiesDownloaderBuilder,
-    headers::reverse_headers::ReverseHeadersDownloaderBuilder,
+    bodies::{bodies::BodiesDownloaderBuilder, noop::NoopBodiesDownloader},
+    headers::{noop::NoopHeaderDownloader, reverse_headers::ReverseHeadersDownloaderBuilder},
 };
 use reth_interfaces::{
     blockchain_tree::BlockchainTreeEngine,
@@ -71,9 +72,13 @@ use reth_transaction_pool::{
     EthTransactionPool, TransactionPool, TransactionValidationTaskExecutor,
 };
 use secp256k1::SecretKey;
-use std::{net::SocketAddr, path::PathBuf, sync::Arc};
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::PathBuf,
+    sync::Arc,
+};
 use tokio::sync::{
-    mpsc::{Receiver, UnboundedSender},
+    mpsc::{unbounded_channel, Receiver, UnboundedSender},
     watch,
 };
 use tracing::*;
@@ -192,6 +197,15 @@ pub struct NodeConfig {
 
     /// All pruning related arguments
     pub pruning: PruningArgs,
+
+    /// All thread pool related arguments with --threads prefix
+    pub threads: ThreadPoolArgs,
+
+    /// All static files related arguments with --static-files prefix
+    pub static_files: StaticFilesArgs,
+
+    /// All disk space monitor related arguments with --disk prefix
+    pub disk: DiskArgs,
 }
 
 impl NodeConfig {
@@ -280,6 +294,24 @@ impl NodeConfig {
         self
     }
 
+    /// Set the thread pool args for the node
+    pub fn with_threads(mut self, threads: ThreadPoolArgs) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Set the static files args for the node
+    pub fn with_static_files(mut self, static_files: StaticFilesArgs) -> Self {
+        self.static_files = static_files;
+        self
+    }
+
+    /// Set the disk space monitor args for the node
+    pub fn with_disk(mut self, disk: DiskArgs) -> Self {
+        self.disk = disk;
+        self
+    }
+
     /// Get the network secret from the given data dir
     pub fn network_secret(&self, data_dir: &ChainPath<DataDirPath>) -> eyre::Result<SecretKey> {
         let network_secret_path =
@@ -585,6 +617,42 @@ impl NodeConfig {
         Ok(pipeline)
     }
 
+    /// Builds a [Pipeline] that can only unwind, since its downloaders never download anything.
+    pub async fn build_unwind_pipeline<DB, EvmConfig>(
+        &self,
+        config: &StageConfig,
+        consensus: Arc<dyn Consensus>,
+        provider_factory: ProviderFactory<DB>,
+        prune_config: Option<PruneConfig>,
+        evm_config: EvmConfig,
+    ) -> eyre::Result<Pipeline<DB>>
+    where
+        DB: Database + Clone + 'static,
+        EvmConfig: ConfigureEvm + Clone + 'static,
+    {
+        let static_file_producer = StaticFileProducer::new(
+            provider_factory.clone(),
+            provider_factory.static_file_provider(),
+            prune_config.clone().unwrap_or_default().segments,
+        );
+        let (metrics_tx, _) = unbounded_channel();
+
+        self.build_pipeline(
+            provider_factory,
+            config,
+            NoopHeaderDownloader::default(),
+            NoopBodiesDownloader::default(),
+            consensus,
+            None,
+            false,
+            metrics_tx,
+            prune_config,
+            static_file_producer,
+            evm_config,
+        )
+        .await
+    }
+
     /// Loads 'MAINNET_KZG_TRUSTED_SETUP'
     pub fn kzg_settings(&self) -> eyre::Result<Arc<KzgSettings>> {
         Ok(Arc::clone(&MAINNET_KZG_TRUSTED_SETUP))
@@ -758,7 +826,18 @@ impl NodeConfig {
             .network
             .network_config(config, self.chain.clone(), secret_key, default_peers_path)
             .with_task_executor(Box::new(executor))
-            .set_head(head)
+            .set_head(head);
+
+        if cfg!(feature = "disable-p2p") || self.network.is_p2p_disabled() {
+            // nothing is bound when p2p is disabled, but nothing should appear to be reachable
+            // from the outside either
+            debug!(target: "reth::cli", "P2P networking disabled");
+            return cfg_builder
+                .set_addrs(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0))
+                .build(client)
+        }
+
+        let cfg_builder = cfg_builder
             .listener_addr(SocketAddr::new(
                 self.network.addr,
                 // set discovery port based on instance number
@@ -938,6 +1017,9 @@ impl Default for NodeConfig {
             db: DatabaseArgs::default(),
             dev: DevArgs::default(),
             pruning: PruningArgs::default(),
+            threads: ThreadPoolArgs::default(),
+            static_files: StaticFilesArgs::default(),
+            disk: DiskArgs::default(),
         }
     }
 }
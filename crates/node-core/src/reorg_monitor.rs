@@ -0,0 +1,142 @@
+//! Detects when the canonical block hash at a height changes.
+
+use futures::{Stream, StreamExt};
+use reth_metrics::{
+    metrics::{Counter, Gauge},
+    Metrics,
+};
+use reth_primitives::{BlockNumber, B256};
+use reth_provider::CanonStateNotification;
+use std::collections::BTreeMap;
+use tracing::*;
+
+/// The default number of canonical block hashes that are remembered to detect reorgs.
+pub const DEFAULT_TRACKED_CANONICAL_HASHES: usize = 10_000;
+
+/// A change of the canonical block hash at a height.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainReorg {
+    /// The lowest height whose canonical hash changed.
+    pub number: BlockNumber,
+    /// The previous canonical hash at that height.
+    pub old_hash: B256,
+    /// The new canonical hash at that height.
+    pub new_hash: B256,
+    /// The number of previously canonical blocks that were replaced.
+    pub depth: u64,
+}
+
+/// Remembers the hashes of the most recent canonical blocks and reports a [ChainReorg] whenever a
+/// newly committed block replaces a previously canonical block at the same height.
+///
+/// Unlike the reorg metrics of the blockchain tree, this also catches hash changes of chains that
+/// are extended linearly, e.g. when blocks are unwound and resubmitted with different contents.
+#[derive(Debug)]
+pub struct ReorgMonitor {
+    /// The canonical hashes of the most recent blocks.
+    hashes: BTreeMap<BlockNumber, B256>,
+    /// The maximum number of remembered hashes.
+    capacity: usize,
+    metrics: ReorgMetrics,
+}
+
+impl ReorgMonitor {
+    /// Creates a new [ReorgMonitor] that remembers up to `capacity` canonical block hashes.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            hashes: BTreeMap::new(),
+            capacity: capacity.max(1),
+            metrics: ReorgMetrics::default(),
+        }
+    }
+
+    /// Records newly committed canonical blocks, ordered by block number, and returns the reorg
+    /// if any of them replaced a previously canonical block.
+    pub fn on_canonical_blocks(
+        &mut self,
+        blocks: impl IntoIterator<Item = (BlockNumber, B256)>,
+    ) -> Option<ChainReorg> {
+        let mut reorg = None;
+        for (number, hash) in blocks {
+            if reorg.is_none() {
+                reorg = self.hashes.get(&number).filter(|old_hash| **old_hash != hash).map(
+                    |old_hash| {
+                        let tip = self.hashes.last_key_value().map_or(number, |(tip, _)| *tip);
+                        ChainReorg {
+                            number,
+                            old_hash: *old_hash,
+                            new_hash: hash,
+                            depth: tip - number + 1,
+                        }
+                    },
+                );
+                // the replaced blocks aren't canonical anymore
+                if reorg.is_some() {
+                    self.hashes.split_off(&number);
+                }
+            }
+            self.hashes.insert(number, hash);
+        }
+
+        while self.hashes.len() > self.capacity {
+            self.hashes.pop_first();
+        }
+
+        if let Some(reorg) = &reorg {
+            self.metrics.reorgs_total.increment(1);
+            self.metrics.latest_depth.set(reorg.depth as f64);
+        }
+        reorg
+    }
+
+    /// Checks all chains that become canonical for replaced blocks.
+    pub async fn run<St>(mut self, mut notifications: St)
+    where
+        St: Stream<Item = CanonStateNotification> + Unpin,
+    {
+        while let Some(notification) = notifications.next().await {
+            let Some(chain) = notification.committed() else { continue };
+            let blocks = chain.blocks().iter().map(|(number, block)| (*number, block.hash()));
+            if let Some(ChainReorg { number, old_hash, new_hash, depth }) =
+                self.on_canonical_blocks(blocks)
+            {
+                warn!(target: "reth::reorg", number, depth, ?old_hash, ?new_hash, "Canonical block hash changed");
+            }
+        }
+    }
+}
+
+/// Metrics for the [ReorgMonitor].
+#[derive(Metrics)]
+#[metrics(scope = "chain_reorg")]
+struct ReorgMetrics {
+    /// The number of times the canonical hash at a height changed
+    reorgs_total: Counter,
+    /// The number of blocks replaced by the latest reorg
+    latest_depth: Gauge,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_replaced_canonical_blocks() {
+        let mut monitor = ReorgMonitor::new(3);
+        let hash = B256::with_last_byte;
+
+        // linear extension
+        assert_eq!(monitor.on_canonical_blocks([(1, hash(1)), (2, hash(2))]), None);
+        assert_eq!(monitor.on_canonical_blocks([(3, hash(3)), (4, hash(4))]), None);
+
+        // block 1 was forgotten, so replacing it isn't detected
+        assert_eq!(monitor.on_canonical_blocks([(1, hash(11))]), None);
+
+        // resubmitting blocks 3 and 4 with different hashes replaces both
+        assert_eq!(
+            monitor.on_canonical_blocks([(3, hash(13)), (4, hash(14))]),
+            Some(ChainReorg { number: 3, old_hash: hash(3), new_hash: hash(13), depth: 2 })
+        );
+        assert_eq!(monitor.on_canonical_blocks([(5, hash(15))]), None);
+    }
+}
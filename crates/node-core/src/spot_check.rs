@@ -0,0 +1,127 @@
+//! Re-executes randomly sampled canonical blocks to cross-check their stored results.
+
+use futures::{Stream, StreamExt};
+use rand::Rng;
+use reth_interfaces::{
+    executor::BlockExecutionError,
+    provider::{ProviderError, ProviderResult},
+};
+use reth_metrics::{metrics::Counter, Metrics};
+use reth_primitives::BlockNumber;
+use reth_provider::{
+    BlockReader, CanonStateNotification, ExecutorFactory, HeaderProvider, StateProviderFactory,
+    TransactionVariant,
+};
+use tracing::*;
+
+/// Errors that can occur while spot-checking a block.
+#[derive(Debug, thiserror::Error)]
+pub enum SpotCheckError {
+    /// The block or its parent state could not be loaded.
+    #[error(transparent)]
+    Provider(#[from] ProviderError),
+    /// Re-executing the block didn't reproduce the stored results.
+    #[error(transparent)]
+    Execution(#[from] BlockExecutionError),
+}
+
+/// Samples blocks as they become canonical and re-executes them on top of their parent state.
+///
+/// Re-execution verifies the receipts root, logs bloom and gas used against the block header, so
+/// blocks that were ingested with inconsistent data are surfaced early instead of when the data
+/// is eventually queried.
+#[derive(Debug)]
+pub struct BlockSpotChecker<Provider, EF> {
+    /// The provider used to load sampled blocks and their parent state.
+    provider: Provider,
+    /// The factory for the executors that re-execute sampled blocks.
+    executor_factory: EF,
+    /// Every block is checked with a probability of `1 / sample_rate`.
+    sample_rate: u32,
+    metrics: SpotCheckMetrics,
+}
+
+impl<Provider, EF> BlockSpotChecker<Provider, EF>
+where
+    Provider: BlockReader + HeaderProvider + StateProviderFactory,
+    EF: ExecutorFactory,
+{
+    /// Creates a new [BlockSpotChecker] that checks one in `sample_rate` blocks on average.
+    pub fn new(provider: Provider, executor_factory: EF, sample_rate: u32) -> Self {
+        Self {
+            provider,
+            executor_factory,
+            sample_rate: sample_rate.max(1),
+            metrics: SpotCheckMetrics::default(),
+        }
+    }
+
+    /// Re-executes the block with the given number on top of its parent state and verifies the
+    /// results against the block header.
+    ///
+    /// The genesis block has no parent state to execute on top of and is always skipped.
+    pub fn check_block(&self, number: BlockNumber) -> Result<(), SpotCheckError> {
+        let Some(parent) = number.checked_sub(1) else { return Ok(()) };
+        let block = self
+            .provider
+            .block_with_senders(number.into(), TransactionVariant::WithHash)?
+            .ok_or(ProviderError::BlockNotFound(number.into()))?;
+        let td = self
+            .provider
+            .header_td_by_number(number)?
+            .ok_or(ProviderError::TotalDifficultyNotFound(number))?;
+        let state = self.provider.history_by_block_number(parent)?;
+
+        let mut executor = self.executor_factory.with_state(state);
+        executor.execute_and_verify_receipt(&block, td)?;
+        Ok(())
+    }
+
+    /// Checks sampled blocks of all chains that become canonical.
+    ///
+    /// NOTE: This executes blocks, so it should be spawned on a blocking thread.
+    pub async fn run<St>(self, mut notifications: St)
+    where
+        St: Stream<Item = CanonStateNotification> + Unpin,
+    {
+        while let Some(notification) = notifications.next().await {
+            let Some(chain) = notification.committed() else { continue };
+            for number in chain.blocks().keys().copied() {
+                if number == 0 || !rand::thread_rng().gen_ratio(1, self.sample_rate) {
+                    continue
+                }
+                if let Err(err) = self.record_check(number) {
+                    debug!(target: "reth::spot_check", number, %err, "Failed to load block for spot check");
+                }
+            }
+        }
+    }
+
+    /// Checks the block and records the outcome, returning an error if the block couldn't be
+    /// loaded.
+    fn record_check(&self, number: BlockNumber) -> ProviderResult<()> {
+        match self.check_block(number) {
+            Ok(()) => {
+                trace!(target: "reth::spot_check", number, "Spot check passed");
+                self.metrics.checked_blocks.increment(1);
+            }
+            Err(SpotCheckError::Execution(err)) => {
+                warn!(target: "reth::spot_check", number, %err, "Re-executed block doesn't match stored results");
+                self.metrics.checked_blocks.increment(1);
+                self.metrics.discrepancies.increment(1);
+            }
+            Err(SpotCheckError::Provider(err)) => return Err(err),
+        }
+        Ok(())
+    }
+}
+
+/// Metrics for the [BlockSpotChecker].
+#[derive(Metrics)]
+#[metrics(scope = "spot_check")]
+struct SpotCheckMetrics {
+    /// The number of blocks that were re-executed
+    checked_blocks: Counter,
+    /// The number of re-executed blocks that didn't match their stored results
+    discrepancies: Counter,
+}
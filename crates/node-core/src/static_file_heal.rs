@@ -0,0 +1,352 @@
+//! Startup recovery of static files that are ahead of the database.
+
+use reth_db::{cursor::DbCursorRO, database::Database, tables, transaction::DbTx};
+use reth_interfaces::provider::ProviderResult;
+use reth_primitives::{stage::StageId, BlockNumber, StaticFileSegment, TxNumber};
+use reth_provider::{
+    providers::StaticFileWriter, BlockReader, DatabaseProviderRO, ProviderFactory,
+    StageCheckpointReader,
+};
+use tracing::*;
+
+/// Rows of a static file segment that were discarded because the database never committed them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiscardedStaticFileRows {
+    /// The segment the rows were removed from.
+    pub segment: StaticFileSegment,
+    /// The highest block of the segment as committed to the database, which is the highest block
+    /// left in the segment.
+    pub checkpoint: BlockNumber,
+    /// The number of removed rows, i.e. headers, transactions or receipts.
+    pub rows: u64,
+}
+
+/// The outcome of [heal_static_files].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HealedStaticFiles {
+    /// Rows that were removed from static files because the database never committed them.
+    pub discarded: Vec<DiscardedStaticFileRows>,
+    /// The block the database has to be unwound to, because static files are missing rows of
+    /// blocks the database committed.
+    pub unwind_target: Option<BlockNumber>,
+}
+
+/// Aligns static file segments and the stage checkpoints of the database.
+///
+/// Data is written to static files before the database transaction that records it is committed,
+/// so an unclean shutdown in between leaves static files with rows the database doesn't know
+/// about. The stages truncate those rows when they run next, but headers and data of stages that
+/// don't run again can stay inconsistent. This truncates all segments eagerly and returns what
+/// was discarded.
+///
+/// Conversely, static files that lost rows the database committed, e.g. because a static file
+/// was truncated while healing it, can't be repaired from the database. The stages writing them
+/// have to run again, so this returns the highest block all segments hold as the target to unwind
+/// the database to. Receipts are only checked if they're written to static files, i.e. if
+/// `has_receipt_pruning` is false.
+pub fn heal_static_files<DB: Database>(
+    factory: &ProviderFactory<DB>,
+    has_receipt_pruning: bool,
+) -> ProviderResult<HealedStaticFiles> {
+    let provider = factory.provider()?;
+    let static_file_provider = factory.static_file_provider();
+    let mut discarded = Vec::new();
+    let mut unwind_targets = Vec::new();
+
+    let checkpoint =
+        |stage| provider.get_stage_checkpoint(stage).map(|c| c.unwrap_or_default().block_number);
+
+    // headers: static files must contain exactly the headers up to the headers checkpoint
+    let headers_checkpoint = checkpoint(StageId::Headers)?;
+    let highest_header =
+        static_file_provider.get_highest_static_file_block(StaticFileSegment::Headers);
+    match highest_header {
+        Some(highest) if highest > headers_checkpoint => {
+            let rows = highest - headers_checkpoint;
+            static_file_provider.latest_writer(StaticFileSegment::Headers)?.prune_headers(rows)?;
+            discarded.push(DiscardedStaticFileRows {
+                segment: StaticFileSegment::Headers,
+                checkpoint: headers_checkpoint,
+                rows,
+            });
+        }
+        // headers that were never moved to static files are still in the database
+        Some(highest)
+            if highest < headers_checkpoint &&
+                provider.tx_ref().get::<tables::Headers>(highest + 1)?.is_none() =>
+        {
+            unwind_targets.push(highest)
+        }
+        _ => {}
+    }
+
+    // transactions: static files must contain exactly the transactions of the database
+    let bodies_checkpoint = checkpoint(StageId::Bodies)?;
+    let next_tx_num = provider
+        .tx_ref()
+        .cursor_read::<tables::TransactionBlocks>()?
+        .last()?
+        .map(|(id, _)| id + 1)
+        .unwrap_or_default();
+    let next_static_file_tx_num = static_file_provider
+        .get_highest_static_file_tx(StaticFileSegment::Transactions)
+        .map_or(0, |highest| highest + 1);
+    if next_static_file_tx_num > next_tx_num {
+        let rows = next_static_file_tx_num - next_tx_num;
+        static_file_provider
+            .latest_writer(StaticFileSegment::Transactions)?
+            .prune_transactions(rows, bodies_checkpoint)?;
+        discarded.push(DiscardedStaticFileRows {
+            segment: StaticFileSegment::Transactions,
+            checkpoint: bodies_checkpoint,
+            rows,
+        });
+    } else if let Some(highest_block) =
+        static_file_provider.get_highest_static_file_block(StaticFileSegment::Transactions)
+    {
+        let behind = next_static_file_tx_num < next_tx_num || highest_block < bodies_checkpoint;
+        if behind &&
+            provider.tx_ref().get::<tables::Transactions>(next_static_file_tx_num)?.is_none()
+        {
+            unwind_targets.push(last_block_with_rows(
+                &provider,
+                highest_block.min(bodies_checkpoint),
+                next_static_file_tx_num,
+            )?);
+        }
+    }
+
+    // receipts: only written to static files if they aren't pruned, in which case static files
+    // must contain exactly the receipts up to the last transaction of the execution checkpoint
+    let execution_checkpoint = checkpoint(StageId::Execution)?;
+    let next_receipt_num = provider
+        .block_body_indices(execution_checkpoint)?
+        .map(|indices| indices.next_tx_num())
+        .unwrap_or_default();
+    if let Some(highest_receipt) =
+        static_file_provider.get_highest_static_file_tx(StaticFileSegment::Receipts)
+    {
+        if highest_receipt + 1 > next_receipt_num {
+            let rows = highest_receipt + 1 - next_receipt_num;
+            static_file_provider
+                .latest_writer(StaticFileSegment::Receipts)?
+                .prune_receipts(rows, execution_checkpoint)?;
+            discarded.push(DiscardedStaticFileRows {
+                segment: StaticFileSegment::Receipts,
+                checkpoint: execution_checkpoint,
+                rows,
+            });
+        }
+    }
+    if !has_receipt_pruning {
+        let next_static_file_receipt_num = static_file_provider
+            .get_highest_static_file_tx(StaticFileSegment::Receipts)
+            .map_or(0, |highest| highest + 1);
+        let highest_block = static_file_provider
+            .get_highest_static_file_block(StaticFileSegment::Receipts)
+            .unwrap_or_default();
+        let behind =
+            next_static_file_receipt_num < next_receipt_num || highest_block < execution_checkpoint;
+        if behind &&
+            provider.tx_ref().get::<tables::Receipts>(next_static_file_receipt_num)?.is_none()
+        {
+            unwind_targets.push(last_block_with_rows(
+                &provider,
+                highest_block.min(execution_checkpoint),
+                next_static_file_receipt_num,
+            )?);
+        }
+    }
+
+    for DiscardedStaticFileRows { segment, checkpoint, rows } in &discarded {
+        warn!(target: "reth::cli", %segment, checkpoint, rows, "Discarded static file rows that weren't committed to the database");
+    }
+    let unwind_target = unwind_targets.into_iter().min();
+    if let Some(unwind_target) = unwind_target {
+        warn!(target: "reth::cli", unwind_target, "Static files are missing rows the database committed");
+    }
+
+    Ok(HealedStaticFiles { discarded, unwind_target })
+}
+
+/// Returns the highest block up to `block` whose transactions are all below
+/// `next_static_file_tx_num`, i.e. whose transactions or receipts are all in static files.
+fn last_block_with_rows<DB: Database>(
+    provider: &DatabaseProviderRO<DB>,
+    mut block: BlockNumber,
+    next_static_file_tx_num: TxNumber,
+) -> ProviderResult<BlockNumber> {
+    while block > 0 {
+        if provider
+            .block_body_indices(block)?
+            .is_some_and(|indices| indices.next_tx_num() <= next_static_file_tx_num)
+        {
+            break
+        }
+        block -= 1;
+    }
+    Ok(block)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::init::init_genesis;
+    use reth_db::{models::StoredBlockBodyIndices, transaction::DbTxMut};
+    use reth_primitives::{
+        stage::StageCheckpoint, Header, Receipt, TransactionSignedNoHash, MAINNET, U256,
+    };
+    use reth_provider::{
+        test_utils::create_test_provider_factory_with_chain_spec, StageCheckpointWriter,
+    };
+
+    #[test]
+    fn discards_uncommitted_headers() {
+        let factory = create_test_provider_factory_with_chain_spec(MAINNET.clone());
+        init_genesis(factory.clone()).unwrap();
+        let static_file_provider = factory.static_file_provider();
+
+        // nothing to heal right after genesis
+        assert_eq!(heal_static_files(&factory, false).unwrap(), HealedStaticFiles::default());
+
+        // write headers to static files that the database never committed
+        {
+            let mut writer =
+                static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+            for number in 1..=2 {
+                let header = Header { number, ..Default::default() };
+                let hash = header.hash_slow();
+                writer.append_header(header, U256::ZERO, hash).unwrap();
+            }
+            writer.commit().unwrap();
+        }
+        assert_eq!(
+            static_file_provider.get_highest_static_file_block(StaticFileSegment::Headers),
+            Some(2)
+        );
+
+        assert_eq!(
+            heal_static_files(&factory, false).unwrap(),
+            HealedStaticFiles {
+                discarded: vec![DiscardedStaticFileRows {
+                    segment: StaticFileSegment::Headers,
+                    checkpoint: 0,
+                    rows: 2
+                }],
+                unwind_target: None,
+            }
+        );
+        assert_eq!(
+            static_file_provider.get_highest_static_file_block(StaticFileSegment::Headers),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn discards_uncommitted_transactions_and_receipts() {
+        let factory = create_test_provider_factory_with_chain_spec(MAINNET.clone());
+        init_genesis(factory.clone()).unwrap();
+        let static_file_provider = factory.static_file_provider();
+
+        // write transactions and receipts of the genesis block that the database never committed
+        {
+            let mut writer =
+                static_file_provider.latest_writer(StaticFileSegment::Transactions).unwrap();
+            writer.increment_block(StaticFileSegment::Transactions, 0).unwrap();
+            for tx_num in 0..2 {
+                writer.append_transaction(tx_num, TransactionSignedNoHash::default()).unwrap();
+            }
+            writer.commit().unwrap();
+
+            let mut writer =
+                static_file_provider.latest_writer(StaticFileSegment::Receipts).unwrap();
+            writer.increment_block(StaticFileSegment::Receipts, 0).unwrap();
+            for tx_num in 0..3 {
+                writer.append_receipt(tx_num, Receipt::default()).unwrap();
+            }
+            writer.commit().unwrap();
+        }
+
+        assert_eq!(
+            heal_static_files(&factory, false).unwrap(),
+            HealedStaticFiles {
+                discarded: vec![
+                    DiscardedStaticFileRows {
+                        segment: StaticFileSegment::Transactions,
+                        checkpoint: 0,
+                        rows: 2
+                    },
+                    DiscardedStaticFileRows {
+                        segment: StaticFileSegment::Receipts,
+                        checkpoint: 0,
+                        rows: 3
+                    }
+                ],
+                unwind_target: None,
+            }
+        );
+        for segment in [StaticFileSegment::Transactions, StaticFileSegment::Receipts] {
+            assert_eq!(static_file_provider.get_highest_static_file_tx(segment), None);
+        }
+        assert_eq!(heal_static_files(&factory, false).unwrap(), HealedStaticFiles::default());
+    }
+
+    #[test]
+    fn unwinds_to_static_files_missing_committed_rows() {
+        let factory = create_test_provider_factory_with_chain_spec(MAINNET.clone());
+        init_genesis(factory.clone()).unwrap();
+        let static_file_provider = factory.static_file_provider();
+
+        let save_checkpoint = |stage, block| {
+            let provider_rw = factory.provider_rw().unwrap();
+            provider_rw.save_stage_checkpoint(stage, StageCheckpoint::new(block)).unwrap();
+            provider_rw.commit().unwrap();
+        };
+
+        // static files hold headers up to block 3, the database up to block 5
+        {
+            let mut writer =
+                static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+            for number in 1..=3 {
+                let header = Header { number, ..Default::default() };
+                let hash = header.hash_slow();
+                writer.append_header(header, U256::ZERO, hash).unwrap();
+            }
+            writer.commit().unwrap();
+        }
+        save_checkpoint(StageId::Headers, 5);
+        assert_eq!(
+            heal_static_files(&factory, false).unwrap(),
+            HealedStaticFiles { discarded: Vec::new(), unwind_target: Some(3) }
+        );
+
+        // static files hold the transaction of block 1, the database also the one of block 2
+        {
+            let mut writer =
+                static_file_provider.latest_writer(StaticFileSegment::Transactions).unwrap();
+            writer.increment_block(StaticFileSegment::Transactions, 0).unwrap();
+            writer.increment_block(StaticFileSegment::Transactions, 1).unwrap();
+            writer.append_transaction(0, TransactionSignedNoHash::default()).unwrap();
+            writer.commit().unwrap();
+
+            let provider_rw = factory.provider_rw().unwrap();
+            let tx = provider_rw.tx_ref();
+            for block in 1..=2 {
+                let indices = StoredBlockBodyIndices { first_tx_num: block - 1, tx_count: 1 };
+                tx.put::<tables::BlockBodyIndices>(block, indices).unwrap();
+                tx.put::<tables::TransactionBlocks>(block - 1, block).unwrap();
+            }
+            provider_rw.commit().unwrap();
+        }
+        save_checkpoint(StageId::Bodies, 2);
+        assert_eq!(
+            heal_static_files(&factory, false).unwrap(),
+            HealedStaticFiles { discarded: Vec::new(), unwind_target: Some(1) }
+        );
+
+        // receipts of executed blocks are only expected in static files without receipt pruning
+        save_checkpoint(StageId::Execution, 2);
+        assert_eq!(heal_static_files(&factory, true).unwrap().unwind_target, Some(1));
+        assert_eq!(heal_static_files(&factory, false).unwrap().unwind_target, Some(0));
+    }
+}
@@ -194,8 +194,7 @@ where
         ctx: &BuilderContext<Node>,
         pool: Pool,
     ) -> eyre::Result<NetworkHandle> {
-        let network = ctx.network_builder().await?;
-        let handle = ctx.start_network(network, pool);
+        let handle = ctx.launch_network(ctx.network_config()?, pool).await?;
 
         Ok(handle)
     }
@@ -6,7 +6,7 @@ use crate::{
     OptimismEngineTypes, OptimismEvmConfig,
 };
 use reth_basic_payload_builder::{BasicPayloadJobGenerator, BasicPayloadJobGeneratorConfig};
-use reth_network::{NetworkHandle, NetworkManager};
+use reth_network::NetworkHandle;
 use reth_node_builder::{
     components::{ComponentsBuilder, NetworkBuilder, PayloadServiceBuilder, PoolBuilder},
     node::{FullNodeTypes, NodeTypes},
@@ -240,9 +240,7 @@ where
         network_config.tx_gossip_disabled = disable_txpool_gossip;
         network_config.optimism_network_config.sequencer_endpoint = sequencer_http;
 
-        let network = NetworkManager::builder(network_config).await?;
-
-        let handle = ctx.start_network(network, pool);
+        let handle = ctx.launch_network(network_config, pool).await?;
 
         Ok(handle)
     }
@@ -713,6 +713,25 @@ impl SealedHeader {
         &self,
         parent: &SealedHeader,
         chain_spec: &ChainSpec,
+    ) -> Result<(), HeaderValidationError> {
+        self.validate_against_parent_without_blob_fields(parent, chain_spec)?;
+
+        // ensure that the blob gas fields for this block
+        if chain_spec.is_cancun_active_at_timestamp(self.timestamp) {
+            self.validate_4844_header_against_parent(parent)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks the same as [`Self::validate_against_parent`], except for the blob gas fields
+    /// (EIP-4844).
+    ///
+    /// This is meant for chains whose headers don't carry blob gas information.
+    pub fn validate_against_parent_without_blob_fields(
+        &self,
+        parent: &SealedHeader,
+        chain_spec: &ChainSpec,
     ) -> Result<(), HeaderValidationError> {
         // Parent number is consistent.
         if parent.number + 1 != self.number {
@@ -773,11 +792,6 @@ impl SealedHeader {
             }
         }
 
-        // ensure that the blob gas fields for this block
-        if chain_spec.is_cancun_active_at_timestamp(self.timestamp) {
-            self.validate_4844_header_against_parent(parent)?;
-        }
-
         Ok(())
     }
 
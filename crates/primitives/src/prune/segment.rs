@@ -6,10 +6,12 @@ use thiserror::Error;
 /// Segment of the data that can be pruned.
 #[main_codec]
 #[derive(Debug, Display, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 pub enum PruneSegment {
     /// Prune segment responsible for the `TransactionSenders` table.
     SenderRecovery,
     /// Prune segment responsible for the `TransactionHashNumbers` table.
+    #[cfg_attr(feature = "clap", value(alias = "txlookup"))]
     TransactionLookup,
     /// Prune segment responsible for all rows in `Receipts` table.
     Receipts,
@@ -154,6 +154,8 @@ pub struct SegmentHeader {
     tx_range: Option<SegmentRangeInclusive>,
     /// Segment type
     segment: StaticFileSegment,
+    /// XXH3 checksum of the data file, recorded once the static file is complete
+    checksum: Option<u64>,
 }
 
 impl SegmentHeader {
@@ -164,7 +166,7 @@ impl SegmentHeader {
         tx_range: Option<SegmentRangeInclusive>,
         segment: StaticFileSegment,
     ) -> Self {
-        Self { expected_block_range, block_range, tx_range, segment }
+        Self { expected_block_range, block_range, tx_range, segment, checksum: None }
     }
 
     /// Returns the static file segment kind.
@@ -182,6 +184,16 @@ impl SegmentHeader {
         self.tx_range.as_ref()
     }
 
+    /// Returns the checksum of the data file, if the static file is complete.
+    pub fn checksum(&self) -> Option<u64> {
+        self.checksum
+    }
+
+    /// Sets the checksum of the data file.
+    pub fn set_checksum(&mut self, checksum: Option<u64>) {
+        self.checksum = checksum;
+    }
+
     /// The expected block start of the segment.
     pub fn expected_block_start(&self) -> BlockNumber {
         self.expected_block_range.start()
@@ -192,6 +204,11 @@ impl SegmentHeader {
         self.expected_block_range.end()
     }
 
+    /// Returns `true` if the segment holds every block of its expected block range.
+    pub fn is_complete(&self) -> bool {
+        self.block_end() == Some(self.expected_block_end())
+    }
+
     /// Returns the first block number of the segment.
     pub fn block_start(&self) -> Option<BlockNumber> {
         self.block_range.as_ref().map(|b| b.start())
@@ -251,7 +268,10 @@ impl SegmentHeader {
     }
 
     /// Removes `num` elements from end of tx or block range.
+    ///
+    /// Clears the checksum, since the static file is no longer complete.
     pub fn prune(&mut self, num: u64) {
+        self.checksum = None;
         match self.segment {
             StaticFileSegment::Headers => {
                 if let Some(range) = &mut self.block_range {
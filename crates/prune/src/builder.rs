@@ -19,6 +19,9 @@ pub struct PrunerBuilder {
     /// the amount of blocks between pruner runs to account for the difference in amount of new
     /// data coming in.
     pub prune_delete_limit: usize,
+    /// Maximum total entries to prune per run, regardless of the number of blocks since the
+    /// previous run.
+    pub max_entries_per_run: Option<usize>,
     /// Time a pruner job can run before timing out.
     pub timeout: Option<Duration>,
 }
@@ -58,6 +61,15 @@ impl PrunerBuilder {
         self
     }
 
+    /// Sets the maximum total entries to prune per run.
+    ///
+    /// This bounds the time a single run holds the database write lock when many blocks passed
+    /// since the previous run.
+    pub fn max_entries_per_run(mut self, max_entries_per_run: Option<usize>) -> Self {
+        self.max_entries_per_run = max_entries_per_run;
+        self
+    }
+
     /// Sets the timeout for pruner, per run.
     ///
     /// CAUTION: Account and Storage History prune segments treat this timeout as a soft limit,
@@ -77,6 +89,7 @@ impl PrunerBuilder {
             self.block_interval,
             self.prune_delete_limit,
             self.max_reorg_depth,
+            self.max_entries_per_run,
             self.timeout,
         )
     }
@@ -89,6 +102,7 @@ impl Default for PrunerBuilder {
             segments: PruneModes::none(),
             max_reorg_depth: 64,
             prune_delete_limit: MAINNET.prune_delete_limit,
+            max_entries_per_run: None,
             timeout: Some(Self::DEFAULT_TIMEOUT),
         }
     }
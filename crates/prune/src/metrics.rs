@@ -1,5 +1,5 @@
 use reth_metrics::{
-    metrics::{Gauge, Histogram},
+    metrics::{Counter, Gauge, Histogram},
     Metrics,
 };
 use reth_primitives::PruneSegment;
@@ -34,4 +34,8 @@ pub(crate) struct PrunerSegmentMetrics {
     pub(crate) duration_seconds: Histogram,
     /// Highest pruned block per segment
     pub(crate) highest_pruned_block: Gauge,
+    /// Number of entries pruned from this segment in a single pruner run
+    pub(crate) entries_pruned_per_run: Histogram,
+    /// Total number of entries pruned from this segment
+    pub(crate) entries_pruned_total: Counter,
 }
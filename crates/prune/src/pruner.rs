@@ -44,6 +44,9 @@ pub struct Pruner<DB> {
     /// Maximum number of blocks to be pruned per run, as an additional restriction to
     /// `previous_tip_block_number`.
     prune_max_blocks_per_run: usize,
+    /// Maximum total entries to prune (delete from database) per run, regardless of the number of
+    /// blocks since the previous run. Limits the time a single run holds the database write lock.
+    max_entries_per_run: Option<usize>,
     /// Maximum time for a one pruner run.
     timeout: Option<Duration>,
     #[doc(hidden)]
@@ -59,6 +62,7 @@ impl<DB: Database> Pruner<DB> {
         min_block_interval: usize,
         delete_limit: usize,
         prune_max_blocks_per_run: usize,
+        max_entries_per_run: Option<usize>,
         timeout: Option<Duration>,
     ) -> Self {
         Self {
@@ -68,6 +72,7 @@ impl<DB: Database> Pruner<DB> {
             previous_tip_block_number: None,
             delete_limit_per_block: delete_limit,
             prune_max_blocks_per_run,
+            max_entries_per_run,
             timeout,
             metrics: Metrics::default(),
             listeners: Default::default(),
@@ -110,8 +115,12 @@ impl<DB: Database> Pruner<DB> {
             })
             .min(self.prune_max_blocks_per_run);
 
-        let mut limiter = PruneLimiter::default()
-            .set_deleted_entries_limit(self.delete_limit_per_block * blocks_since_last_run);
+        let mut deleted_entries_limit = self.delete_limit_per_block * blocks_since_last_run;
+        if let Some(max_entries_per_run) = self.max_entries_per_run {
+            deleted_entries_limit = deleted_entries_limit.min(max_entries_per_run);
+        }
+
+        let mut limiter = PruneLimiter::default().set_deleted_entries_limit(deleted_entries_limit);
         if let Some(timeout) = self.timeout {
             limiter = limiter.set_time_limit(timeout);
         };
@@ -198,10 +207,10 @@ impl<DB: Database> Pruner<DB> {
                     segment
                         .save_checkpoint(provider, checkpoint.as_prune_checkpoint(prune_mode))?;
                 }
-                self.metrics
-                    .get_prune_segment_metrics(segment.segment())
-                    .duration_seconds
-                    .record(segment_start.elapsed());
+                let segment_metrics = self.metrics.get_prune_segment_metrics(segment.segment());
+                segment_metrics.duration_seconds.record(segment_start.elapsed());
+                segment_metrics.entries_pruned_per_run.record(output.pruned as f64);
+                segment_metrics.entries_pruned_total.increment(output.pruned as u64);
                 if let Some(highest_pruned_block) =
                     output.checkpoint.and_then(|checkpoint| checkpoint.block_number)
                 {
@@ -291,10 +300,17 @@ impl<DB: Database> Pruner<DB> {
 #[cfg(test)]
 mod tests {
 
-    use crate::Pruner;
-    use reth_db::test_utils::{create_test_rw_db, create_test_static_files_dir};
-    use reth_primitives::MAINNET;
-    use reth_provider::ProviderFactory;
+    use crate::{segments::SenderRecovery, Pruner};
+    use reth_db::{
+        tables,
+        test_utils::{create_test_rw_db, create_test_static_files_dir},
+    };
+    use reth_interfaces::test_utils::{generators, generators::random_block_range};
+    use reth_primitives::{
+        PruneInterruptReason, PruneMode, PruneProgress, PruneSegment, B256, MAINNET,
+    };
+    use reth_provider::{ProviderFactory, PruneCheckpointReader};
+    use reth_stages::test_utils::{StorageKind, TestStageDB};
 
     #[test]
     fn is_pruning_needed() {
@@ -302,7 +318,7 @@ mod tests {
         let (_static_dir, static_dir_path) = create_test_static_files_dir();
         let provider_factory = ProviderFactory::new(db, MAINNET.clone(), static_dir_path)
             .expect("create provide factory with static_files");
-        let mut pruner = Pruner::new(provider_factory, vec![], 5, 0, 5, None);
+        let mut pruner = Pruner::new(provider_factory, vec![], 5, 0, 5, None, None);
 
         // No last pruned block number was set before
         let first_block_number = 1;
@@ -318,4 +334,65 @@ mod tests {
         let third_block_number = second_block_number;
         assert!(!pruner.is_pruning_needed(third_block_number));
     }
+
+    #[test]
+    fn max_entries_per_run() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(&mut rng, 1..=20, B256::ZERO, 2..3);
+        db.insert_blocks(blocks.iter(), StorageKind::Database(None)).expect("insert blocks");
+        let transaction_senders = blocks
+            .iter()
+            .flat_map(|block| &block.body)
+            .enumerate()
+            .map(|(tx_number, transaction)| {
+                (tx_number as u64, transaction.recover_signer().expect("recover signer"))
+            })
+            .collect::<Vec<_>>();
+        db.insert_transaction_senders(transaction_senders.clone())
+            .expect("insert transaction senders");
+
+        // the per block limit alone would allow pruning all senders in a single run
+        let mut pruner = Pruner::new(
+            db.factory.clone(),
+            vec![Box::new(SenderRecovery::new(PruneMode::Full))],
+            1,
+            1000,
+            100,
+            Some(15),
+            None,
+        );
+
+        let remaining_senders = || {
+            db.table::<tables::TransactionSenders>()
+                .unwrap()
+                .into_iter()
+                .map(|(tx_number, _)| tx_number)
+                .collect::<Vec<_>>()
+        };
+        let checkpoint = || {
+            db.factory
+                .provider()
+                .unwrap()
+                .get_prune_checkpoint(PruneSegment::SenderRecovery)
+                .unwrap()
+                .and_then(|checkpoint| checkpoint.tx_number)
+        };
+
+        // every run stops at the cap and the next one resumes from its checkpoint
+        let limit_reached =
+            PruneProgress::HasMoreData(PruneInterruptReason::DeletedEntriesLimitReached);
+        assert_eq!(pruner.run(20).unwrap(), limit_reached);
+        assert_eq!(remaining_senders(), (15..40).collect::<Vec<_>>());
+        assert_eq!(checkpoint(), Some(14));
+
+        assert_eq!(pruner.run(21).unwrap(), limit_reached);
+        assert_eq!(remaining_senders(), (30..40).collect::<Vec<_>>());
+        assert_eq!(checkpoint(), Some(29));
+
+        assert_eq!(pruner.run(22).unwrap(), PruneProgress::Finished);
+        assert!(remaining_senders().is_empty());
+        assert_eq!(checkpoint(), Some(39));
+    }
 }
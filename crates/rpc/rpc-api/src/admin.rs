@@ -45,4 +45,9 @@ pub trait AdminApi {
     /// Returns the ENR of the node.
     #[method(name = "nodeInfo")]
     async fn node_info(&self) -> RpcResult<NodeInfo>;
+
+    /// Sets the log level of the given target, e.g. `provider::static_file`, without restarting
+    /// the node. An empty target sets the default log level.
+    #[method(name = "setLogLevel")]
+    fn set_log_level(&self, target: String, level: String) -> RpcResult<()>;
 }
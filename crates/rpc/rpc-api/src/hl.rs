@@ -0,0 +1,31 @@
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_primitives::{Address, BlockNumberOrTag};
+use reth_rpc_types::{AccountHistory, BeneficiaryFees};
+
+/// Hyperliquid API namespace for HL-specific methods
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "hl"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "hl"))]
+pub trait HlApi {
+    /// Returns the balance and nonce of the account after every block in the given range in which
+    /// it changed.
+    ///
+    /// At most `limit` entries are returned, if there are more the response contains the block to
+    /// continue from.
+    #[method(name = "getAccountHistory")]
+    async fn get_account_history(
+        &self,
+        address: Address,
+        from_block: BlockNumberOrTag,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> RpcResult<AccountHistory>;
+
+    /// Returns the number of blocks, gas used and priority fees per block beneficiary in the given
+    /// block range.
+    #[method(name = "getBeneficiaryFees")]
+    async fn get_beneficiary_fees(
+        &self,
+        from_block: BlockNumberOrTag,
+        to_block: Option<BlockNumberOrTag>,
+    ) -> RpcResult<Vec<BeneficiaryFees>>;
+}
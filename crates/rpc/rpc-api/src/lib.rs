@@ -21,6 +21,7 @@ mod engine;
 mod eth;
 mod eth_filter;
 mod eth_pubsub;
+mod hl;
 mod mev;
 mod net;
 mod otterscan;
@@ -44,6 +45,7 @@ pub mod servers {
         eth::EthApiServer,
         eth_filter::EthFilterApiServer,
         eth_pubsub::EthPubSubApiServer,
+        hl::HlApiServer,
         mev::MevApiServer,
         net::NetApiServer,
         otterscan::OtterscanServer,
@@ -70,6 +72,7 @@ pub mod clients {
         engine::{EngineApiClient, EngineEthApiClient},
         eth::EthApiClient,
         eth_filter::EthFilterApiClient,
+        hl::HlApiClient,
         mev::MevApiClient,
         net::NetApiClient,
         otterscan::OtterscanClient,
@@ -1,5 +1,6 @@
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use reth_primitives::{Address, BlockId, U256};
+use reth_rpc_types::{DiskSpace, StaticFileStats};
 use std::collections::HashMap;
 
 /// Reth API namespace for reth-specific methods
@@ -12,4 +13,13 @@ pub trait RethApi {
         &self,
         block_id: BlockId,
     ) -> RpcResult<HashMap<Address, U256>>;
+
+    /// Returns statistics of the static files of every segment
+    #[method(name = "staticFileStats")]
+    async fn reth_static_file_stats(&self) -> RpcResult<Vec<StaticFileStats>>;
+
+    /// Returns the free disk space of the node and whether block ingest is paused because it's
+    /// critically low
+    #[method(name = "diskSpace")]
+    async fn reth_disk_space(&self) -> RpcResult<DiskSpace>;
 }
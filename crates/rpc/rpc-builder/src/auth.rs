@@ -14,8 +14,8 @@ use jsonrpsee::{
 use reth_network_api::{NetworkInfo, Peers};
 use reth_node_api::{ConfigureEvmEnv, EngineTypes};
 use reth_provider::{
-    BlockReaderIdExt, ChainSpecProvider, EvmEnvProvider, HeaderProvider, ReceiptProviderIdExt,
-    StateProviderFactory,
+    BlockReaderIdExt, ChainSpecProvider, EvmEnvProvider, HeaderProvider, ReadSnapshotFactory,
+    ReceiptProviderIdExt, StateProviderFactory,
 };
 use reth_rpc::{
     eth::{
@@ -52,6 +52,7 @@ where
         + HeaderProvider
         + ReceiptProviderIdExt
         + StateProviderFactory
+        + ReadSnapshotFactory
         + Clone
         + Unpin
         + 'static,
@@ -108,6 +109,7 @@ where
         + EvmEnvProvider
         + HeaderProvider
         + StateProviderFactory
+        + ReadSnapshotFactory
         + Clone
         + Unpin
         + 'static,
@@ -7,7 +7,7 @@ use reth_rpc::{
         gas_oracle::GasPriceOracleConfig,
         EthFilterConfig, FeeHistoryCacheConfig, RPC_DEFAULT_GAS_CAP,
     },
-    EthApi, EthFilter, EthPubSub,
+    EthApi, EthFilter, EthPubSub, TraceCacheConfig,
 };
 use reth_tasks::pool::BlockingTaskPool;
 use serde::{Deserialize, Serialize};
@@ -49,6 +49,11 @@ pub struct EthConfig {
     pub stale_filter_ttl: std::time::Duration,
     /// Settings for the fee history cache
     pub fee_history_cache: FeeHistoryCacheConfig,
+    /// Settings for the on-disk cache of block traces, disabled if not set.
+    pub trace_cache: Option<TraceCacheConfig>,
+    /// Blocks with at least this many transactions are traced in parallel by `debug_traceBlock*`
+    /// and `trace_block`, disabled if not set.
+    pub parallel_tracing_min_transactions: Option<usize>,
 }
 
 impl EthConfig {
@@ -75,6 +80,8 @@ impl Default for EthConfig {
             rpc_gas_cap: RPC_DEFAULT_GAS_CAP.into(),
             stale_filter_ttl: DEFAULT_STALE_FILTER_TTL,
             fee_history_cache: FeeHistoryCacheConfig::default(),
+            trace_cache: None,
+            parallel_tracing_min_transactions: None,
         }
     }
 }
@@ -115,4 +122,16 @@ impl EthConfig {
         self.rpc_gas_cap = rpc_gas_cap;
         self
     }
+
+    /// Configures the on-disk cache of block traces
+    pub fn trace_cache(mut self, trace_cache: Option<TraceCacheConfig>) -> Self {
+        self.trace_cache = trace_cache;
+        self
+    }
+
+    /// Configures the minimum number of transactions of blocks that are traced in parallel
+    pub fn parallel_tracing_min_transactions(mut self, min_transactions: Option<usize>) -> Self {
+        self.parallel_tracing_min_transactions = min_transactions;
+        self
+    }
 }
@@ -20,8 +20,9 @@
 //! use reth_network_api::{NetworkInfo, Peers};
 //! use reth_node_api::ConfigureEvmEnv;
 //! use reth_provider::{
-//!     AccountReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider,
-//!     ChangeSetReader, EvmEnvProvider, StateProviderFactory,
+//!     AccountHistoryReader, AccountReader, BlockReaderIdExt, CanonStateSubscriptions,
+//!     ChainSpecProvider, ChangeSetReader, EvmEnvProvider, ReadSnapshotFactory,
+//!     StateProviderFactory, StaticFileProviderFactory,
 //! };
 //! use reth_rpc_builder::{
 //!     RethRpcModule, RpcModuleBuilder, RpcServerConfig, ServerBuilder, TransportRpcModuleConfig,
@@ -39,8 +40,11 @@
 //!         + BlockReaderIdExt
 //!         + ChainSpecProvider
 //!         + ChangeSetReader
+//!         + AccountHistoryReader
 //!         + StateProviderFactory
 //!         + EvmEnvProvider
+//!         + StaticFileProviderFactory
+//!         + ReadSnapshotFactory
 //!         + Clone
 //!         + Unpin
 //!         + 'static,
@@ -80,8 +84,9 @@
 //! use reth_network_api::{NetworkInfo, Peers};
 //! use reth_node_api::{ConfigureEvmEnv, EngineTypes};
 //! use reth_provider::{
-//!     AccountReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider,
-//!     ChangeSetReader, EvmEnvProvider, StateProviderFactory,
+//!     AccountHistoryReader, AccountReader, BlockReaderIdExt, CanonStateSubscriptions,
+//!     ChainSpecProvider, ChangeSetReader, EvmEnvProvider, ReadSnapshotFactory,
+//!     StateProviderFactory, StaticFileProviderFactory,
 //! };
 //! use reth_rpc::JwtSecret;
 //! use reth_rpc_api::EngineApiServer;
@@ -104,8 +109,11 @@
 //!         + BlockReaderIdExt
 //!         + ChainSpecProvider
 //!         + ChangeSetReader
+//!         + AccountHistoryReader
 //!         + StateProviderFactory
 //!         + EvmEnvProvider
+//!         + StaticFileProviderFactory
+//!         + ReadSnapshotFactory
 //!         + Clone
 //!         + Unpin
 //!         + 'static,
@@ -171,8 +179,9 @@ pub use reth_ipc::server::{Builder as IpcServerBuilder, Endpoint};
 use reth_network_api::{noop::NoopNetwork, NetworkInfo, Peers};
 use reth_node_api::{ConfigureEvmEnv, EngineTypes};
 use reth_provider::{
-    AccountReader, BlockReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider,
-    ChangeSetReader, EvmEnvProvider, StateProviderFactory,
+    AccountHistoryReader, AccountReader, BadBlockReader, BlockReader, BlockReaderIdExt,
+    CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader, EvmEnvProvider,
+    ReadSnapshotFactory, StateProviderFactory, StaticFileProviderFactory,
 };
 use reth_rpc::{
     eth::{
@@ -182,10 +191,11 @@ use reth_rpc::{
         EthBundle, FeeHistoryCache,
     },
     AdminApi, AuthLayer, Claims, DebugApi, EngineEthApi, EthApi, EthFilter, EthPubSub,
-    EthSubscriptionIdProvider, JwtAuthValidator, JwtSecret, NetApi, OtterscanApi, RPCApi, RethApi,
-    TraceApi, TxPoolApi, Web3Api,
+    EthSubscriptionIdProvider, HlApi, JwtAuthValidator, JwtSecret, NetApi, OtterscanApi, RPCApi,
+    RethApi, TraceApi, TraceCache, TxPoolApi, Web3Api,
 };
 use reth_rpc_api::servers::*;
+use reth_rpc_types::DiskSpace;
 use reth_tasks::{
     pool::{BlockingTaskGuard, BlockingTaskPool},
     TaskSpawner, TokioTaskExecutor,
@@ -197,12 +207,14 @@ use std::{
     fmt,
     net::{Ipv4Addr, SocketAddr, SocketAddrV4},
     str::FromStr,
+    sync::Arc,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 use strum::{AsRefStr, EnumIter, IntoStaticStr, ParseError, VariantArray, VariantNames};
+use tokio::sync::watch;
 use tower::layer::util::{Identity, Stack};
 use tower_http::cors::CorsLayer;
-use tracing::{instrument, trace};
+use tracing::{instrument, trace, warn};
 
 // re-export for convenience
 pub use crate::eth::{EthConfig, EthHandlers};
@@ -244,6 +256,9 @@ where
         + EvmEnvProvider
         + ChainSpecProvider
         + ChangeSetReader
+        + AccountHistoryReader
+        + StaticFileProviderFactory
+        + ReadSnapshotFactory
         + Clone
         + Unpin
         + 'static,
@@ -278,6 +293,10 @@ pub struct RpcModuleBuilder<Provider, Pool, Network, Tasks, Events, EvmConfig> {
     events: Events,
     /// Defines how the EVM should be configured before execution.
     evm_config: EvmConfig,
+    /// Source of blocks that were rejected as invalid, served by `debug_getBadBlocks`.
+    bad_blocks: Option<Arc<dyn BadBlockReader>>,
+    /// Free disk space reported by the disk space monitor, served by `reth_diskSpace`.
+    disk_space: Option<watch::Receiver<DiskSpace>>,
 }
 
 // === impl RpcBuilder ===
@@ -294,7 +313,16 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
         events: Events,
         evm_config: EvmConfig,
     ) -> Self {
-        Self { provider, pool, network, executor, events, evm_config }
+        Self {
+            provider,
+            pool,
+            network,
+            executor,
+            events,
+            evm_config,
+            bad_blocks: None,
+            disk_space: None,
+        }
     }
 
     /// Configure the provider instance.
@@ -305,8 +333,17 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
     where
         P: BlockReader + StateProviderFactory + EvmEnvProvider + 'static,
     {
-        let Self { pool, network, executor, events, evm_config, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, events, evm_config }
+        let Self { pool, network, executor, events, evm_config, bad_blocks, disk_space, .. } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            events,
+            evm_config,
+            bad_blocks,
+            disk_space,
+        }
     }
 
     /// Configure the transaction pool instance.
@@ -317,8 +354,19 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
     where
         P: TransactionPool + 'static,
     {
-        let Self { provider, network, executor, events, evm_config, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, events, evm_config }
+        let Self {
+            provider, network, executor, events, evm_config, bad_blocks, disk_space, ..
+        } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            events,
+            evm_config,
+            bad_blocks,
+            disk_space,
+        }
     }
 
     /// Configure a [NoopTransactionPool] instance.
@@ -329,7 +377,9 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
     pub fn with_noop_pool(
         self,
     ) -> RpcModuleBuilder<Provider, NoopTransactionPool, Network, Tasks, Events, EvmConfig> {
-        let Self { provider, executor, events, network, evm_config, .. } = self;
+        let Self {
+            provider, executor, events, network, evm_config, bad_blocks, disk_space, ..
+        } = self;
         RpcModuleBuilder {
             provider,
             executor,
@@ -337,6 +387,8 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
             network,
             evm_config,
             pool: NoopTransactionPool::default(),
+            bad_blocks,
+            disk_space,
         }
     }
 
@@ -348,8 +400,18 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
     where
         N: NetworkInfo + Peers + 'static,
     {
-        let Self { provider, pool, executor, events, evm_config, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, events, evm_config }
+        let Self { provider, pool, executor, events, evm_config, bad_blocks, disk_space, .. } =
+            self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            events,
+            evm_config,
+            bad_blocks,
+            disk_space,
+        }
     }
 
     /// Configure a [NoopNetwork] instance.
@@ -360,7 +422,8 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
     pub fn with_noop_network(
         self,
     ) -> RpcModuleBuilder<Provider, Pool, NoopNetwork, Tasks, Events, EvmConfig> {
-        let Self { provider, pool, executor, events, evm_config, .. } = self;
+        let Self { provider, pool, executor, events, evm_config, bad_blocks, disk_space, .. } =
+            self;
         RpcModuleBuilder {
             provider,
             pool,
@@ -368,6 +431,8 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
             events,
             network: NoopNetwork::default(),
             evm_config,
+            bad_blocks,
+            disk_space,
         }
     }
 
@@ -379,8 +444,17 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
     where
         T: TaskSpawner + 'static,
     {
-        let Self { pool, network, provider, events, evm_config, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, events, evm_config }
+        let Self { pool, network, provider, events, evm_config, bad_blocks, disk_space, .. } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            events,
+            evm_config,
+            bad_blocks,
+            disk_space,
+        }
     }
 
     /// Configure [TokioTaskExecutor] as the task executor to use for additional tasks.
@@ -390,7 +464,7 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
     pub fn with_tokio_executor(
         self,
     ) -> RpcModuleBuilder<Provider, Pool, Network, TokioTaskExecutor, Events, EvmConfig> {
-        let Self { pool, network, provider, events, evm_config, .. } = self;
+        let Self { pool, network, provider, events, evm_config, bad_blocks, disk_space, .. } = self;
         RpcModuleBuilder {
             provider,
             network,
@@ -398,6 +472,8 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
             events,
             executor: TokioTaskExecutor::default(),
             evm_config,
+            bad_blocks,
+            disk_space,
         }
     }
 
@@ -409,8 +485,18 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
     where
         E: CanonStateSubscriptions + 'static,
     {
-        let Self { provider, pool, executor, network, evm_config, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, events, evm_config }
+        let Self { provider, pool, executor, network, evm_config, bad_blocks, disk_space, .. } =
+            self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            events,
+            evm_config,
+            bad_blocks,
+            disk_space,
+        }
     }
 
     /// Configure the evm configuration type
@@ -421,8 +507,30 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
     where
         E: ConfigureEvmEnv + 'static,
     {
-        let Self { provider, pool, executor, network, events, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, events, evm_config }
+        let Self { provider, pool, executor, network, events, bad_blocks, disk_space, .. } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            events,
+            evm_config,
+            bad_blocks,
+            disk_space,
+        }
+    }
+
+    /// Configure the source of blocks that were rejected as invalid, served by
+    /// `debug_getBadBlocks`.
+    pub fn with_bad_block_reader(mut self, bad_blocks: Arc<dyn BadBlockReader>) -> Self {
+        self.bad_blocks = Some(bad_blocks);
+        self
+    }
+
+    /// Configure the source of the free disk space served by `reth_diskSpace`.
+    pub fn with_disk_space(mut self, disk_space: watch::Receiver<DiskSpace>) -> Self {
+        self.disk_space = Some(disk_space);
+        self
     }
 }
 
@@ -435,6 +543,9 @@ where
         + EvmEnvProvider
         + ChainSpecProvider
         + ChangeSetReader
+        + AccountHistoryReader
+        + StaticFileProviderFactory
+        + ReadSnapshotFactory
         + Clone
         + Unpin
         + 'static,
@@ -464,7 +575,8 @@ where
     {
         let mut modules = TransportRpcModules::default();
 
-        let Self { provider, pool, network, executor, events, evm_config } = self;
+        let Self { provider, pool, network, executor, events, evm_config, bad_blocks, disk_space } =
+            self;
 
         let TransportRpcModuleConfig { http, ws, ipc, config } = module_config.clone();
 
@@ -477,6 +589,8 @@ where
             config.unwrap_or_default(),
             evm_config,
         );
+        registry.bad_blocks = bad_blocks;
+        registry.disk_space = disk_space;
 
         modules.config = module_config;
         modules.http = registry.maybe_module(http.as_ref());
@@ -519,8 +633,13 @@ where
         self,
         config: RpcModuleConfig,
     ) -> RethModuleRegistry<Provider, Pool, Network, Tasks, Events, EvmConfig> {
-        let Self { provider, pool, network, executor, events, evm_config } = self;
-        RethModuleRegistry::new(provider, pool, network, executor, events, config, evm_config)
+        let Self { provider, pool, network, executor, events, evm_config, bad_blocks, disk_space } =
+            self;
+        let mut registry =
+            RethModuleRegistry::new(provider, pool, network, executor, events, config, evm_config);
+        registry.bad_blocks = bad_blocks;
+        registry.disk_space = disk_space;
+        registry
     }
 
     /// Configures all [RpcModule]s specific to the given [TransportRpcModuleConfig] which can be
@@ -530,7 +649,8 @@ where
     pub fn build(self, module_config: TransportRpcModuleConfig) -> TransportRpcModules<()> {
         let mut modules = TransportRpcModules::default();
 
-        let Self { provider, pool, network, executor, events, evm_config } = self;
+        let Self { provider, pool, network, executor, events, evm_config, bad_blocks, disk_space } =
+            self;
 
         if !module_config.is_empty() {
             let TransportRpcModuleConfig { http, ws, ipc, config } = module_config.clone();
@@ -544,6 +664,8 @@ where
                 config.unwrap_or_default(),
                 evm_config,
             );
+            registry.bad_blocks = bad_blocks;
+            registry.disk_space = disk_space;
 
             modules.config = module_config;
             modules.http = registry.maybe_module(http.as_ref());
@@ -731,6 +853,9 @@ impl RpcModuleSelection {
             + EvmEnvProvider
             + ChainSpecProvider
             + ChangeSetReader
+            + AccountHistoryReader
+            + StaticFileProviderFactory
+            + ReadSnapshotFactory
             + Clone
             + Unpin
             + 'static,
@@ -854,6 +979,8 @@ pub enum RethRpcModule {
     Reth,
     /// `ots_` module
     Ots,
+    /// `hl_` module
+    Hl,
     /// For single non-standard `eth_` namespace call `eth_callBundle`
     ///
     /// This is separate from [RethRpcModule::Eth] because it is a non standardized call that
@@ -902,6 +1029,7 @@ impl FromStr for RethRpcModule {
             "rpc" => RethRpcModule::Rpc,
             "reth" => RethRpcModule::Reth,
             "ots" => RethRpcModule::Ots,
+            "hl" => RethRpcModule::Hl,
             "eth-call-bundle" | "eth_callBundle" => RethRpcModule::EthCallBundle,
             _ => return Err(ParseError::VariantNotFound),
         })
@@ -948,6 +1076,12 @@ pub struct RethModuleRegistry<Provider, Pool, Network, Tasks, Events, EvmConfig>
     blocking_pool_guard: BlockingTaskGuard,
     /// Contains the [Methods] of a module
     modules: HashMap<RethRpcModule, Methods>,
+    /// Source of blocks that were rejected as invalid, served by `debug_getBadBlocks`.
+    bad_blocks: Option<Arc<dyn BadBlockReader>>,
+    /// Free disk space reported by the disk space monitor, served by `reth_diskSpace`.
+    disk_space: Option<watch::Receiver<DiskSpace>>,
+    /// Cache for the traces of entire blocks, shared by all `debug` handlers.
+    trace_cache: Option<Arc<TraceCache>>,
 }
 
 // === impl RethModuleRegistry ===
@@ -965,6 +1099,16 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
         config: RpcModuleConfig,
         evm_config: EvmConfig,
     ) -> Self {
+        // the cache only speeds up tracing, so failing to open it shouldn't prevent startup
+        let trace_cache = config.eth.trace_cache.clone().and_then(|trace_cache| {
+            let dir = trace_cache.dir.clone();
+            TraceCache::open(trace_cache)
+                .map(Arc::new)
+                .map_err(|err| {
+                    warn!(target: "rpc", ?dir, %err, "Failed to open trace cache, block traces won't be cached")
+                })
+                .ok()
+        });
         Self {
             provider,
             pool,
@@ -976,9 +1120,23 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig>
             blocking_pool_guard: BlockingTaskGuard::new(config.eth.max_tracing_requests),
             config,
             events,
+            bad_blocks: None,
+            disk_space: None,
+            trace_cache,
         }
     }
 
+    /// Configures the source of blocks that were rejected as invalid, served by
+    /// `debug_getBadBlocks`.
+    pub fn set_bad_block_reader(&mut self, bad_blocks: Arc<dyn BadBlockReader>) {
+        self.bad_blocks = Some(bad_blocks);
+    }
+
+    /// Configures the source of the free disk space served by `reth_diskSpace`.
+    pub fn set_disk_space(&mut self, disk_space: watch::Receiver<DiskSpace>) {
+        self.disk_space = Some(disk_space);
+    }
+
     /// Returns a reference to the pool
     pub fn pool(&self) -> &Pool {
         &self.pool
@@ -1053,6 +1211,9 @@ where
         + EvmEnvProvider
         + ChainSpecProvider
         + ChangeSetReader
+        + AccountHistoryReader
+        + StaticFileProviderFactory
+        + ReadSnapshotFactory
         + Clone
         + Unpin
         + 'static,
@@ -1154,6 +1315,13 @@ where
         self
     }
 
+    /// Register Hl namespace
+    pub fn register_hl(&mut self) -> &mut Self {
+        let hlapi = self.hl_api();
+        self.modules.insert(RethRpcModule::Hl, hlapi.into_rpc().into());
+        self
+    }
+
     /// Helper function to create a [RpcModule] if it's not `None`
     fn maybe_module(&mut self, config: Option<&RpcModuleSelection>) -> Option<RpcModule<()>> {
         let config = config?;
@@ -1206,13 +1374,25 @@ where
                                 .into_rpc()
                                 .into()
                         }
-                        RethRpcModule::Debug => DebugApi::new(
-                            self.provider.clone(),
-                            eth_api.clone(),
-                            self.blocking_pool_guard.clone(),
-                        )
-                        .into_rpc()
-                        .into(),
+                        RethRpcModule::Debug => {
+                            let mut debug_api = DebugApi::new(
+                                self.provider.clone(),
+                                eth_api.clone(),
+                                self.blocking_pool_guard.clone(),
+                            );
+                            if let Some(bad_blocks) = &self.bad_blocks {
+                                debug_api = debug_api.with_bad_block_reader(bad_blocks.clone());
+                            }
+                            if let Some(trace_cache) = &self.trace_cache {
+                                debug_api = debug_api.with_trace_cache(trace_cache.clone());
+                            }
+                            if let Some(min_transactions) =
+                                self.config.eth.parallel_tracing_min_transactions
+                            {
+                                debug_api = debug_api.with_parallel_tracing(min_transactions);
+                            }
+                            debug_api.into_rpc().into()
+                        }
                         RethRpcModule::Eth => {
                             // merge all eth handlers
                             let mut module = eth_api.clone().into_rpc();
@@ -1224,13 +1404,19 @@ where
                         RethRpcModule::Net => {
                             NetApi::new(self.network.clone(), eth_api.clone()).into_rpc().into()
                         }
-                        RethRpcModule::Trace => TraceApi::new(
-                            self.provider.clone(),
-                            eth_api.clone(),
-                            self.blocking_pool_guard.clone(),
-                        )
-                        .into_rpc()
-                        .into(),
+                        RethRpcModule::Trace => {
+                            let mut trace_api = TraceApi::new(
+                                self.provider.clone(),
+                                eth_api.clone(),
+                                self.blocking_pool_guard.clone(),
+                            );
+                            if let Some(min_transactions) =
+                                self.config.eth.parallel_tracing_min_transactions
+                            {
+                                trace_api = trace_api.with_parallel_tracing(min_transactions);
+                            }
+                            trace_api.into_rpc().into()
+                        }
                         RethRpcModule::Web3 => Web3Api::new(self.network.clone()).into_rpc().into(),
                         RethRpcModule::Txpool => {
                             TxPoolApi::new(self.pool.clone()).into_rpc().into()
@@ -1245,7 +1431,17 @@ where
                         .into(),
                         RethRpcModule::Ots => OtterscanApi::new(eth_api.clone()).into_rpc().into(),
                         RethRpcModule::Reth => {
-                            RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
+                            let mut reth_api = RethApi::new(
+                                self.provider.clone(),
+                                Box::new(self.executor.clone()),
+                            );
+                            if let Some(disk_space) = &self.disk_space {
+                                reth_api = reth_api.with_disk_space(disk_space.clone());
+                            }
+                            reth_api.into_rpc().into()
+                        }
+                        RethRpcModule::Hl => {
+                            HlApi::new(self.provider.clone(), Box::new(self.executor.clone()))
                                 .into_rpc()
                                 .into()
                         }
@@ -1377,7 +1573,12 @@ where
     /// If called outside of the tokio runtime. See also [Self::eth_api]
     pub fn trace_api(&mut self) -> TraceApi<Provider, EthApi<Provider, Pool, Network, EvmConfig>> {
         let eth = self.eth_handlers();
-        TraceApi::new(self.provider.clone(), eth.api, self.blocking_pool_guard.clone())
+        let trace_api =
+            TraceApi::new(self.provider.clone(), eth.api, self.blocking_pool_guard.clone());
+        match self.config.eth.parallel_tracing_min_transactions {
+            Some(min_transactions) => trace_api.with_parallel_tracing(min_transactions),
+            None => trace_api,
+        }
     }
 
     /// Instantiates [EthBundle] Api
@@ -1407,7 +1608,18 @@ where
     /// If called outside of the tokio runtime. See also [Self::eth_api]
     pub fn debug_api(&mut self) -> DebugApi<Provider, EthApi<Provider, Pool, Network, EvmConfig>> {
         let eth_api = self.eth_api();
-        DebugApi::new(self.provider.clone(), eth_api, self.blocking_pool_guard.clone())
+        let mut debug_api =
+            DebugApi::new(self.provider.clone(), eth_api, self.blocking_pool_guard.clone());
+        if let Some(bad_blocks) = &self.bad_blocks {
+            debug_api = debug_api.with_bad_block_reader(bad_blocks.clone());
+        }
+        if let Some(trace_cache) = &self.trace_cache {
+            debug_api = debug_api.with_trace_cache(trace_cache.clone());
+        }
+        if let Some(min_transactions) = self.config.eth.parallel_tracing_min_transactions {
+            debug_api = debug_api.with_parallel_tracing(min_transactions);
+        }
+        debug_api
     }
 
     /// Instantiates NetApi
@@ -1422,7 +1634,16 @@ where
 
     /// Instantiates RethApi
     pub fn reth_api(&mut self) -> RethApi<Provider> {
-        RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
+        let mut reth_api = RethApi::new(self.provider.clone(), Box::new(self.executor.clone()));
+        if let Some(disk_space) = &self.disk_space {
+            reth_api = reth_api.with_disk_space(disk_space.clone());
+        }
+        reth_api
+    }
+
+    /// Instantiates HlApi
+    pub fn hl_api(&mut self) -> HlApi<Provider> {
+        HlApi::new(self.provider.clone(), Box::new(self.executor.clone()))
     }
 }
 
@@ -2383,6 +2604,7 @@ mod tests {
                 "rpc" => RethRpcModule::Rpc,
                 "ots" => RethRpcModule::Ots,
                 "reth" => RethRpcModule::Reth,
+                "hl" => RethRpcModule::Hl,
             );
     }
 
@@ -0,0 +1,20 @@
+use alloy_primitives::{Bytes, B256};
+use alloy_rpc_types::RichBlock;
+use serde::{Deserialize, Serialize};
+
+/// A block that was rejected by the client, as returned by `debug_getBadBlocks`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BadBlock {
+    /// Hash of the rejected block.
+    pub hash: B256,
+    /// The rejected block.
+    pub block: RichBlock,
+    /// The RLP encoded block.
+    pub rlp: Bytes,
+    /// The reason the block was rejected.
+    pub reason: String,
+    /// The hash of the transaction that caused the block to be rejected, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offending_transaction: Option<B256>,
+}
@@ -0,0 +1,40 @@
+use alloy_primitives::{Address, U256, U64};
+use serde::{Deserialize, Serialize};
+
+/// The balance and nonce of an account after a block in which it changed.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountHistoryEntry {
+    /// The block in which the account changed.
+    pub block_number: U64,
+    /// The balance of the account after the block.
+    pub balance: U256,
+    /// The nonce of the account after the block.
+    pub nonce: U64,
+}
+
+/// A page of an account's balance and nonce history, as returned by `hl_getAccountHistory`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountHistory {
+    /// The account changes in this page, ordered by block number.
+    pub entries: Vec<AccountHistoryEntry>,
+    /// The block to continue from to fetch the next page, if there are more entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_block: Option<U64>,
+}
+
+/// The gas and priority fees collected by a block beneficiary over a block range, as returned by
+/// `hl_getBeneficiaryFees`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BeneficiaryFees {
+    /// The fee recipient of the blocks.
+    pub beneficiary: Address,
+    /// The number of blocks in the range with this beneficiary.
+    pub blocks: U64,
+    /// The total gas used by the blocks.
+    pub gas_used: U64,
+    /// The total priority fees paid to the beneficiary. Base fees are burnt and not included.
+    pub fees: U256,
+}
@@ -11,12 +11,15 @@
 
 mod admin;
 pub mod beacon;
+mod debug;
 mod eth;
+mod hl;
 mod mev;
 mod net;
 mod otterscan;
 mod peer;
 pub mod relay;
+mod reth;
 mod rpc;
 
 // re-export for convenience
@@ -38,8 +41,11 @@ pub use eth::{
 };
 
 pub use admin::*;
+pub use debug::*;
+pub use hl::*;
 pub use mev::*;
 pub use net::*;
 pub use otterscan::*;
 pub use peer::*;
+pub use reth::*;
 pub use rpc::*;
@@ -0,0 +1,54 @@
+use alloy_primitives::U64;
+use serde::{Deserialize, Serialize};
+
+/// Statistics of the static files of a segment, as returned by `reth_staticFileStats`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StaticFileStats {
+    /// The segment, i.e. `headers`, `transactions` or `receipts`.
+    pub segment: String,
+    /// The number of static files.
+    pub files: U64,
+    /// The total size of the static files in bytes.
+    pub size: U64,
+    /// The total number of headers, transactions or receipts.
+    pub rows: U64,
+    /// The lowest block in the static files.
+    pub from_block: U64,
+    /// The highest block in the static files.
+    pub to_block: U64,
+    /// The lowest transaction in the static files, if the segment stores transaction data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_tx: Option<U64>,
+    /// The highest transaction in the static files, if the segment stores transaction data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_tx: Option<U64>,
+}
+
+/// The state of the free disk space of the node relative to its thresholds.
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum DiskSpaceStatus {
+    /// There's enough free disk space.
+    #[default]
+    Ok,
+    /// The free disk space is below the warning threshold.
+    Low,
+    /// The free disk space is below the critical threshold. Block ingest is paused until space
+    /// is freed.
+    Critical,
+}
+
+/// The free disk space of the node, as returned by `reth_diskSpace`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskSpace {
+    /// The disk space status.
+    pub status: DiskSpaceStatus,
+    /// The lowest free space in bytes of the filesystems that hold the database and static
+    /// files, if it was checked yet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub available: Option<U64>,
+}
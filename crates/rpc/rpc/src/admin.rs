@@ -1,4 +1,4 @@
-use crate::result::ToRpcResult;
+use crate::result::{internal_rpc_err, invalid_params_rpc_err, ToRpcResult};
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
 use reth_network_api::{NetworkInfo, PeerKind, Peers};
@@ -6,6 +6,7 @@ use reth_primitives::{AnyNode, ChainSpec, NodeRecord};
 use reth_rpc_api::AdminApiServer;
 use reth_rpc_types::{NodeInfo, PeerEthProtocolInfo, PeerInfo, PeerNetworkInfo, PeerProtocolsInfo};
 use std::sync::Arc;
+use tracing::level_filters::LevelFilter;
 
 /// `admin` API implementation.
 ///
@@ -94,6 +95,14 @@ where
         Ok(NodeInfo::new(enr, status, config))
     }
 
+    /// Handler for `admin_setLogLevel`
+    fn set_log_level(&self, target: String, level: String) -> RpcResult<()> {
+        let directive = log_directive(&target, &level).map_err(invalid_params_rpc_err)?;
+        reth_tracing::add_log_directive(&directive)
+            .map_err(|err| internal_rpc_err(err.to_string()))?;
+        Ok(())
+    }
+
     /// Handler for `admin_peerEvents`
     async fn subscribe_peer_events(
         &self,
@@ -103,8 +112,49 @@ where
     }
 }
 
+/// Returns the filter directive that sets the log level of `target`, or the default log level if
+/// `target` is empty.
+///
+/// Targets are module paths like `provider::static_file`, which keeps callers from injecting
+/// other directives or span filters.
+fn log_directive(target: &str, level: &str) -> Result<String, String> {
+    let level = level.parse::<LevelFilter>().map_err(|err| format!("invalid log level: {err}"))?;
+    if target.is_empty() {
+        return Ok(level.to_string())
+    }
+
+    let is_valid_target = target.split("::").all(|part| {
+        !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    });
+    if !is_valid_target {
+        return Err(format!("invalid log target: {target}"))
+    }
+
+    Ok(format!("{target}={level}"))
+}
+
 impl<N> std::fmt::Debug for AdminApi<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AdminApi").finish_non_exhaustive()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_log_directive() {
+        assert_eq!(log_directive("", "debug").unwrap(), "debug");
+        assert_eq!(
+            log_directive("provider::static_file", "TRACE").unwrap(),
+            "provider::static_file=trace"
+        );
+        assert_eq!(log_directive("reth-tasks", "info").unwrap(), "reth-tasks=info");
+
+        assert!(log_directive("provider", "loud").is_err());
+        for target in ["provider,net=trace", "net[span]", "provider=debug", "::net", "net::", " "] {
+            assert!(log_directive(target, "debug").is_err(), "{target}");
+        }
+    }
+}
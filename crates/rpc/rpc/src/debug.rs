@@ -1,21 +1,23 @@
 use crate::{
     eth::{
         error::{EthApiError, EthResult},
+        parallel_trace::trace_transactions_parallel,
         revm_utils::{prepare_call_env, EvmOverrides},
         EthTransactions,
     },
     result::{internal_rpc_err, ToRpcResult},
-    EthApiSpec,
+    EthApiSpec, TraceCache,
 };
 use alloy_rlp::{Decodable, Encodable};
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
 use reth_primitives::{
-    revm::env::tx_env_with_recovered, Address, Block, BlockId, BlockNumberOrTag, Bytes,
-    TransactionSignedEcRecovered, Withdrawals, B256, U256,
+    revm::env::tx_env_with_recovered, Address, Block, BlockId, BlockNumberOrTag, BlockWithSenders,
+    Bytes, TransactionSignedEcRecovered, Withdrawals, B256, U256,
 };
 use reth_provider::{
-    BlockReaderIdExt, ChainSpecProvider, HeaderProvider, StateProviderBox, TransactionVariant,
+    BadBlockReader, BlockReaderIdExt, ChainSpecProvider, HeaderProvider, StateProviderBox,
+    TransactionVariant,
 };
 use reth_revm::database::{StateProviderDatabase, SubState};
 use reth_rpc_api::DebugApiServer;
@@ -24,8 +26,9 @@ use reth_rpc_types::{
         BlockTraceResult, FourByteFrame, GethDebugBuiltInTracerType, GethDebugTracerType,
         GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace, NoopFrame, TraceResult,
     },
-    BlockError, Bundle, RichBlock, StateContext, TransactionRequest,
+    BadBlock, BlockError, BlockTransactionsKind, Bundle, StateContext, TransactionRequest,
 };
+use reth_rpc_types_compat::block::{from_block, from_block_with_tx_hashes};
 use reth_tasks::pool::BlockingTaskGuard;
 use revm::{
     db::CacheDB,
@@ -37,12 +40,19 @@ use revm_inspectors::tracing::{
 };
 use std::sync::Arc;
 use tokio::sync::{AcquireError, OwnedSemaphorePermit};
+use tracing::debug;
 
 /// `debug` API implementation.
 ///
 /// This type provides the functionality for handling `debug` related requests.
 pub struct DebugApi<Provider, Eth> {
     inner: Arc<DebugApiInner<Provider, Eth>>,
+    /// Source of blocks that were rejected as invalid, if any.
+    bad_blocks: Option<Arc<dyn BadBlockReader>>,
+    /// Cache for the traces of entire blocks, if enabled.
+    trace_cache: Option<Arc<TraceCache>>,
+    /// Blocks with at least this many transactions are traced in parallel, if set.
+    parallel_tracing_min_transactions: Option<usize>,
 }
 
 // === impl DebugApi ===
@@ -51,7 +61,26 @@ impl<Provider, Eth> DebugApi<Provider, Eth> {
     /// Create a new instance of the [DebugApi]
     pub fn new(provider: Provider, eth: Eth, blocking_task_guard: BlockingTaskGuard) -> Self {
         let inner = Arc::new(DebugApiInner { provider, eth_api: eth, blocking_task_guard });
-        Self { inner }
+        Self { inner, bad_blocks: None, trace_cache: None, parallel_tracing_min_transactions: None }
+    }
+
+    /// Configures the source of bad blocks served by `debug_getBadBlocks`.
+    pub fn with_bad_block_reader(mut self, bad_blocks: Arc<dyn BadBlockReader>) -> Self {
+        self.bad_blocks = Some(bad_blocks);
+        self
+    }
+
+    /// Configures the cache for block traces returned by `debug_traceBlock*`.
+    pub fn with_trace_cache(mut self, trace_cache: Arc<TraceCache>) -> Self {
+        self.trace_cache = Some(trace_cache);
+        self
+    }
+
+    /// Traces the transactions of blocks with at least `min_transactions` transactions in
+    /// parallel.
+    pub fn with_parallel_tracing(mut self, min_transactions: usize) -> Self {
+        self.parallel_tracing_min_transactions = Some(min_transactions);
+        self
     }
 
     /// Access the underlying `Eth` API.
@@ -86,6 +115,43 @@ where
             return Ok(Vec::new())
         }
 
+        if self.parallel_tracing_min_transactions.is_some_and(|min| transactions.len() >= min) {
+            let this = self.clone();
+            return self
+                .eth_api()
+                .spawn_blocking(move || {
+                    let block_hash = at.as_block_hash();
+                    let tx_hashes = transactions.iter().map(|tx| tx.hash).collect::<Vec<_>>();
+                    let envs = transactions
+                        .iter()
+                        .map(|tx| EnvWithHandlerCfg {
+                            env: Env::boxed(
+                                cfg.cfg_env.clone(),
+                                block_env.clone(),
+                                tx_env_with_recovered(tx),
+                            ),
+                            handler_cfg: cfg.handler_cfg,
+                        })
+                        .collect();
+
+                    trace_transactions_parallel(this.eth_api(), at, envs, |index, env, db| {
+                        let tx_hash = tx_hashes[index];
+                        let (result, state_changes) = this.trace_transaction(
+                            opts.clone(),
+                            env,
+                            db,
+                            Some(TransactionContext {
+                                block_hash,
+                                tx_hash: Some(tx_hash),
+                                tx_index: Some(index),
+                            }),
+                        )?;
+                        Ok((TraceResult::Success { result, tx_hash: Some(tx_hash) }, state_changes))
+                    })
+                })
+                .await
+        }
+
         // replay all transactions of the block
         let this = self.clone();
         self.eth_api()
@@ -187,6 +253,17 @@ where
             .block_hash_for_id(block_id)?
             .ok_or_else(|| EthApiError::UnknownBlockNumber)?;
 
+        if let Some(cache) = self.trace_cache.clone() {
+            let cache_opts = opts.clone();
+            let cached = self
+                .eth_api()
+                .spawn_blocking_future(async move { Ok(cache.get(block_hash, &cache_opts)) })
+                .await?;
+            if let Some(traces) = cached {
+                return Ok(traces)
+            }
+        }
+
         let ((cfg, block_env, _), block) = futures::try_join!(
             self.inner.eth_api.evm_env_at(block_hash.into()),
             self.inner.eth_api.block_by_id_with_senders(block_id),
@@ -197,14 +274,29 @@ where
         // its parent block's state
         let state_at = block.parent_hash;
 
-        self.trace_block_with(
-            state_at.into(),
-            block.into_transactions_ecrecovered().collect(),
-            cfg,
-            block_env,
-            opts,
-        )
-        .await
+        let traces = self
+            .trace_block_with(
+                state_at.into(),
+                block.into_transactions_ecrecovered().collect(),
+                cfg,
+                block_env,
+                opts.clone(),
+            )
+            .await?;
+
+        if let Some(cache) = self.trace_cache.clone() {
+            let traces = traces.clone();
+            self.eth_api()
+                .spawn_blocking_future(async move {
+                    if let Err(err) = cache.insert(block_hash, &opts, &traces) {
+                        debug!(target: "rpc::debug", ?block_hash, %err, "Failed to cache block traces");
+                    }
+                    Ok(())
+                })
+                .await?;
+        }
+
+        Ok(traces)
     }
 
     /// Trace the transaction according to the provided options.
@@ -710,8 +802,44 @@ where
     }
 
     /// Handler for `debug_getBadBlocks`
-    async fn bad_blocks(&self) -> RpcResult<Vec<RichBlock>> {
-        Err(internal_rpc_err("unimplemented"))
+    ///
+    /// Returns the recorded bad blocks, most recent first.
+    async fn bad_blocks(&self) -> RpcResult<Vec<BadBlock>> {
+        let Some(reader) = &self.bad_blocks else { return Ok(Vec::new()) };
+
+        let mut bad_blocks = Vec::new();
+        for bad_block in reader.bad_blocks().to_rpc_result()? {
+            let reth_provider::BadBlock { block, reason, offending_transaction } = bad_block;
+            let hash = block.hash();
+            let block = block.unseal();
+
+            let mut rlp = Vec::new();
+            block.encode(&mut rlp);
+
+            // senders can't be recovered if the block was rejected because of an invalid
+            // signature, in which case only the transaction hashes are returned
+            let rpc_block = match block.clone().with_recovered_senders() {
+                Some(block) => {
+                    from_block(block, U256::ZERO, BlockTransactionsKind::Full, Some(hash))
+                        .map_err(EthApiError::from)?
+                }
+                None => from_block_with_tx_hashes(
+                    BlockWithSenders { block, senders: Vec::new() },
+                    U256::ZERO,
+                    Some(hash),
+                ),
+            };
+
+            bad_blocks.push(BadBlock {
+                hash,
+                block: rpc_block.into(),
+                rlp: rlp.into(),
+                reason,
+                offending_transaction,
+            });
+        }
+
+        Ok(bad_blocks)
     }
 
     /// Handler for `debug_traceChain`
@@ -1005,7 +1133,12 @@ impl<Provider, Eth> std::fmt::Debug for DebugApi<Provider, Eth> {
 
 impl<Provider, Eth> Clone for DebugApi<Provider, Eth> {
     fn clone(&self) -> Self {
-        Self { inner: Arc::clone(&self.inner) }
+        Self {
+            inner: Arc::clone(&self.inner),
+            bad_blocks: self.bad_blocks.clone(),
+            trace_cache: self.trace_cache.clone(),
+            parallel_tracing_min_transactions: self.parallel_tracing_min_transactions,
+        }
     }
 }
 
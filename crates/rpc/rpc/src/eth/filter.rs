@@ -12,7 +12,10 @@ use core::fmt;
 use async_trait::async_trait;
 use jsonrpsee::{core::RpcResult, server::IdProvider};
 use reth_primitives::{ChainInfo, IntoRecoveredTransaction, TxHash};
-use reth_provider::{BlockIdReader, BlockReader, EvmEnvProvider, ProviderError};
+use reth_provider::{
+    providers::{ReadSnapshotManager, DEFAULT_MAX_READ_SNAPSHOT_AGE},
+    BlockIdReader, BlockReader, EvmEnvProvider, ProviderError, ReadSnapshotFactory,
+};
 use reth_rpc_api::EthFilterApiServer;
 use reth_rpc_types::{
     BlockNumHash, Filter, FilterBlockOption, FilterChanges, FilterId, FilteredParams, Log,
@@ -126,7 +129,7 @@ where
 
 impl<Provider, Pool> EthFilter<Provider, Pool>
 where
-    Provider: BlockReader + BlockIdReader + EvmEnvProvider + 'static,
+    Provider: BlockReader + BlockIdReader + EvmEnvProvider + ReadSnapshotFactory + 'static,
     Pool: TransactionPool + 'static,
     <Pool as TransactionPool>::Transaction: 'static,
 {
@@ -223,7 +226,7 @@ where
 #[async_trait]
 impl<Provider, Pool> EthFilterApiServer for EthFilter<Provider, Pool>
 where
-    Provider: BlockReader + BlockIdReader + EvmEnvProvider + 'static,
+    Provider: BlockReader + BlockIdReader + EvmEnvProvider + ReadSnapshotFactory + 'static,
     Pool: TransactionPool + 'static,
 {
     /// Handler for `eth_newFilter`
@@ -342,7 +345,7 @@ struct EthFilterInner<Provider, Pool> {
 
 impl<Provider, Pool> EthFilterInner<Provider, Pool>
 where
-    Provider: BlockReader + BlockIdReader + EvmEnvProvider + 'static,
+    Provider: BlockReader + BlockIdReader + EvmEnvProvider + ReadSnapshotFactory + 'static,
     Pool: TransactionPool + 'static,
 {
     /// Returns logs matching given filter object.
@@ -457,46 +460,55 @@ where
         let address_filter = FilteredParams::address_filter(&filter.address);
         let topics_filter = FilteredParams::topics_filter(&filter.topics);
 
+        // the range is scanned with read snapshots that are replaced between chunks, so that a
+        // long scan doesn't keep a single read transaction open
+        let mut snapshots = ReadSnapshotManager::new(&self.provider, DEFAULT_MAX_READ_SNAPSHOT_AGE);
+
         // loop over the range of new blocks and check logs if the filter matches the log's bloom
         // filter
         for (from, to) in
             BlockRangeInclusiveIter::new(from_block..=to_block, self.max_headers_range)
         {
-            let headers = self.provider.headers_range(from..=to)?;
-
-            for (idx, header) in headers.iter().enumerate() {
-                // only if filter matches
-                if FilteredParams::matches_address(header.logs_bloom, &address_filter) &&
-                    FilteredParams::matches_topics(header.logs_bloom, &topics_filter)
-                {
-                    // these are consecutive headers, so we can use the parent hash of the next
-                    // block to get the current header's hash
-                    let block_hash = match headers.get(idx + 1) {
-                        Some(parent) => parent.parent_hash,
-                        None => self
-                            .provider
-                            .block_hash(header.number)?
-                            .ok_or(ProviderError::BlockNotFound(header.number.into()))?,
-                    };
-
-                    if let Some(receipts) = self.eth_cache.get_receipts(block_hash).await? {
-                        append_matching_block_logs(
-                            &mut all_logs,
-                            &self.provider,
-                            &filter_params,
-                            BlockNumHash::new(header.number, block_hash),
-                            &receipts,
-                            false,
-                        )?;
-
-                        // size check but only if range is multiple blocks, so we always return all
-                        // logs of a single block
-                        let is_multi_block_range = from_block != to_block;
-                        if is_multi_block_range && all_logs.len() > self.max_logs_per_response {
-                            return Err(FilterError::QueryExceedsMaxResults(
-                                self.max_logs_per_response,
-                            ))
-                        }
+            let matching_blocks = {
+                let snapshot = snapshots.provider()?;
+                let headers = snapshot.headers_range(from..=to)?;
+
+                let mut matching_blocks = Vec::new();
+                for (idx, header) in headers.iter().enumerate() {
+                    // only if filter matches
+                    if FilteredParams::matches_address(header.logs_bloom, &address_filter) &&
+                        FilteredParams::matches_topics(header.logs_bloom, &topics_filter)
+                    {
+                        // these are consecutive headers, so we can use the parent hash of the next
+                        // block to get the current header's hash
+                        let block_hash = match headers.get(idx + 1) {
+                            Some(parent) => parent.parent_hash,
+                            None => snapshot
+                                .block_hash(header.number)?
+                                .ok_or(ProviderError::BlockNotFound(header.number.into()))?,
+                        };
+                        matching_blocks.push(BlockNumHash::new(header.number, block_hash));
+                    }
+                }
+                matching_blocks
+            };
+
+            for block in matching_blocks {
+                if let Some(receipts) = self.eth_cache.get_receipts(block.hash).await? {
+                    append_matching_block_logs(
+                        &mut all_logs,
+                        &self.provider,
+                        &filter_params,
+                        block,
+                        &receipts,
+                        false,
+                    )?;
+
+                    // size check but only if range is multiple blocks, so we always return all
+                    // logs of a single block
+                    let is_multi_block_range = from_block != to_block;
+                    if is_multi_block_range && all_logs.len() > self.max_logs_per_response {
+                        return Err(FilterError::QueryExceedsMaxResults(self.max_logs_per_response))
                     }
                 }
             }
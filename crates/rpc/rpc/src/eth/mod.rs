@@ -8,6 +8,7 @@ mod filter;
 pub mod gas_oracle;
 mod id_provider;
 mod logs_utils;
+pub(crate) mod parallel_trace;
 mod pubsub;
 pub mod revm_utils;
 mod signer;
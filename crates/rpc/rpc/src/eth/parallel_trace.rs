@@ -0,0 +1,207 @@
+//! Tracing of block transactions, sequentially or across multiple threads.
+
+use crate::eth::{error::EthResult, EthTransactions};
+use rayon::prelude::*;
+use reth_primitives::{BlockId, B256};
+use reth_provider::StateProviderBox;
+use reth_revm::database::{StateProviderDatabase, SubState};
+use revm::{
+    db::{CacheDB, DatabaseCommit, DbAccount},
+    primitives::{Address, Bytecode, EnvWithHandlerCfg, HashMap, State},
+};
+
+/// The state changes of all transactions preceding a chunk of transactions.
+#[derive(Debug, Default)]
+struct StateOverlay {
+    accounts: HashMap<Address, DbAccount>,
+    contracts: HashMap<B256, Bytecode>,
+}
+
+/// Traces the given transactions of a block one after another on the pre-block state at `at` and
+/// returns the results in order.
+///
+/// This is the sequential counterpart of [trace_transactions_parallel] and invokes `trace` the
+/// same way.
+///
+/// Caution: this is blocking and should be performed on the tracing pool.
+pub(crate) fn trace_transactions<Eth, T, F>(
+    eth_api: &Eth,
+    at: BlockId,
+    envs: Vec<EnvWithHandlerCfg>,
+    trace: F,
+) -> EthResult<Vec<T>>
+where
+    Eth: EthTransactions,
+    F: Fn(usize, EnvWithHandlerCfg, &mut SubState<StateProviderBox>) -> EthResult<(T, State)>,
+{
+    if envs.is_empty() {
+        return Ok(Vec::new())
+    }
+
+    let mut db = CacheDB::new(StateProviderDatabase::new(eth_api.state_at(at)?));
+    let mut results = Vec::with_capacity(envs.len());
+    let mut envs = envs.into_iter().enumerate().peekable();
+    while let Some((index, env)) = envs.next() {
+        let (result, state) = trace(index, env, &mut db)?;
+        if envs.peek().is_some() {
+            db.commit(state);
+        }
+        results.push(result);
+    }
+    Ok(results)
+}
+
+/// Traces the given transactions of a block in parallel and returns the results in order.
+///
+/// The transactions are split into one chunk per thread of the current thread pool. The
+/// transactions preceding each chunk are first executed sequentially without an inspector, and
+/// their accumulated state changes seed an independent state overlay on top of the pre-block state
+/// at `at` for each chunk. The chunks are then traced in parallel, each on its own overlay.
+///
+/// Executing without an inspector is considerably cheaper than tracing, so this reduces the
+/// latency of tracing large blocks at the expense of executing most transactions twice.
+///
+/// The `trace` closure is invoked with the index of the transaction in the block, its environment
+/// and the state it must be executed on, and returns the result and the state changes of the
+/// transaction.
+///
+/// Caution: this is blocking and should be performed on the tracing pool.
+pub(crate) fn trace_transactions_parallel<Eth, T, F>(
+    eth_api: &Eth,
+    at: BlockId,
+    envs: Vec<EnvWithHandlerCfg>,
+    trace: F,
+) -> EthResult<Vec<T>>
+where
+    Eth: EthTransactions,
+    T: Send,
+    F: Fn(usize, EnvWithHandlerCfg, &mut SubState<StateProviderBox>) -> EthResult<(T, State)>
+        + Sync,
+{
+    if envs.is_empty() {
+        return Ok(Vec::new())
+    }
+    let chunk_size = envs.len().div_ceil(rayon::current_num_threads());
+
+    // execute all but the last chunk to capture the state each chunk is traced on
+    let mut db = CacheDB::new(StateProviderDatabase::new(eth_api.state_at(at)?));
+    let mut chunks = Vec::new();
+    let mut envs = envs.into_iter().enumerate().peekable();
+    while envs.peek().is_some() {
+        let overlay =
+            StateOverlay { accounts: db.accounts.clone(), contracts: db.contracts.clone() };
+        let chunk = envs.by_ref().take(chunk_size).collect::<Vec<_>>();
+        if envs.peek().is_some() {
+            for (_, env) in &chunk {
+                let (res, _) = eth_api.transact(&mut db, env.clone())?;
+                db.commit(res.state);
+            }
+        }
+        chunks.push((overlay, chunk));
+    }
+    drop(db);
+
+    let results = chunks
+        .into_par_iter()
+        .map(|(overlay, chunk)| {
+            let mut db = CacheDB::new(StateProviderDatabase::new(eth_api.state_at(at)?));
+            db.accounts = overlay.accounts;
+            db.contracts = overlay.contracts;
+
+            let mut results = Vec::with_capacity(chunk.len());
+            let mut chunk = chunk.into_iter().peekable();
+            while let Some((index, env)) = chunk.next() {
+                let (result, state) = trace(index, env, &mut db)?;
+                if chunk.peek().is_some() {
+                    db.commit(state);
+                }
+                results.push(result);
+            }
+            Ok(results)
+        })
+        .collect::<EthResult<Vec<_>>>()?;
+
+    Ok(results.into_iter().flatten().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::{
+        cache::EthStateCache, gas_oracle::GasPriceOracle, EthApi, FeeHistoryCache,
+        FeeHistoryCacheConfig,
+    };
+    use reth_node_ethereum::EthEvmConfig;
+    use reth_primitives::{constants::ETHEREUM_BLOCK_GAS_LIMIT, BlockNumberOrTag, U256};
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+    use reth_tasks::pool::BlockingTaskPool;
+    use reth_transaction_pool::test_utils::testing_pool;
+    use revm::primitives::{CfgEnv, CfgEnvWithHandlerCfg, SpecId, TransactTo, TxEnv};
+
+    #[tokio::test]
+    async fn parallel_tracing_matches_sequential_tracing() {
+        let provider = MockEthProvider::default();
+        let senders = (0..4).map(|_| Address::random()).collect::<Vec<_>>();
+        for sender in &senders {
+            provider.add_account(*sender, ExtendedAccount::new(0, U256::from(1_000_000)));
+        }
+
+        let evm_config = EthEvmConfig::default();
+        let cache = EthStateCache::spawn(provider.clone(), Default::default(), evm_config);
+        let eth_api = EthApi::new(
+            provider.clone(),
+            testing_pool(),
+            (),
+            cache.clone(),
+            GasPriceOracle::new(provider, Default::default(), cache.clone()),
+            ETHEREUM_BLOCK_GAS_LIMIT,
+            BlockingTaskPool::build().expect("failed to build tracing pool"),
+            FeeHistoryCache::new(cache, FeeHistoryCacheConfig::default()),
+            evm_config,
+        );
+
+        // value transfers between the senders, so that every transaction depends on the state
+        // changes of the transactions before it
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), SpecId::LONDON);
+        let mut nonces = vec![0; senders.len()];
+        let envs = (0..50)
+            .map(|index| {
+                let from = index % senders.len();
+                let to = (index * 7 + 1) % senders.len();
+                let tx = TxEnv {
+                    caller: senders[from],
+                    transact_to: TransactTo::Call(senders[to]),
+                    value: U256::from(index * 1_000),
+                    nonce: Some(nonces[from]),
+                    gas_limit: 21_000,
+                    ..Default::default()
+                };
+                nonces[from] += 1;
+                EnvWithHandlerCfg::new_with_cfg_env(cfg.clone(), Default::default(), tx)
+            })
+            .collect::<Vec<_>>();
+
+        let at = BlockId::Number(BlockNumberOrTag::Latest);
+        let trace = |_, env: EnvWithHandlerCfg, db: &mut SubState<StateProviderBox>| {
+            let (res, _) = eth_api.transact(&mut *db, env)?;
+            let mut accounts = res
+                .state
+                .iter()
+                .map(|(address, account)| (*address, account.info.balance, account.info.nonce))
+                .collect::<Vec<_>>();
+            accounts.sort_unstable();
+            Ok(((res.result.gas_used(), accounts), res.state))
+        };
+
+        let sequential = trace_transactions(&eth_api, at, envs.clone(), trace).unwrap();
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+        let parallel =
+            pool.install(|| trace_transactions_parallel(&eth_api, at, envs, trace)).unwrap();
+
+        assert_eq!(sequential.len(), 50);
+        assert_eq!(parallel, sequential);
+        // every transfer succeeded, i.e. the nonce of each chunk's first transaction matched the
+        // state it was traced on
+        assert!(sequential.iter().all(|(gas_used, _)| *gas_used == 21_000));
+    }
+}
@@ -0,0 +1,204 @@
+use crate::eth::error::{EthApiError, EthResult};
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use reth_primitives::{Address, BlockNumberOrTag, U64};
+use reth_provider::{AccountHistoryReader, BlockReaderIdExt, ReadSnapshotFactory};
+use reth_rpc_api::HlApiServer;
+use reth_rpc_types::{AccountHistory, AccountHistoryEntry, BeneficiaryFees};
+use reth_tasks::TaskSpawner;
+use std::{future::Future, sync::Arc};
+use tokio::sync::oneshot;
+
+/// The maximum number of entries returned by a single `hl_getAccountHistory` call.
+pub const MAX_ACCOUNT_HISTORY_ENTRIES: usize = 1_000;
+
+/// The maximum number of blocks that can be aggregated by a single `hl_getBeneficiaryFees` call.
+pub const MAX_BENEFICIARY_FEES_BLOCK_RANGE: u64 = 10_000;
+
+/// `hl` API implementation.
+///
+/// This type provides the functionality for handling `hl` related requests.
+pub struct HlApi<Provider> {
+    inner: Arc<HlApiInner<Provider>>,
+}
+
+// === impl HlApi ===
+
+impl<Provider> HlApi<Provider> {
+    /// The provider that can interact with the chain.
+    pub fn provider(&self) -> &Provider {
+        &self.inner.provider
+    }
+
+    /// Create a new instance of the [HlApi]
+    pub fn new(provider: Provider, task_spawner: Box<dyn TaskSpawner>) -> Self {
+        let inner = Arc::new(HlApiInner { provider, task_spawner });
+        Self { inner }
+    }
+}
+
+impl<Provider> HlApi<Provider>
+where
+    Provider: BlockReaderIdExt + AccountHistoryReader + ReadSnapshotFactory + 'static,
+{
+    /// Executes the future on a new blocking task.
+    async fn on_blocking_task<C, F, R>(&self, c: C) -> EthResult<R>
+    where
+        C: FnOnce(Self) -> F,
+        F: Future<Output = EthResult<R>> + Send + 'static,
+        R: Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+        let this = self.clone();
+        let f = c(this);
+        self.inner.task_spawner.spawn_blocking(Box::pin(async move {
+            let res = f.await;
+            let _ = tx.send(res);
+        }));
+        rx.await.map_err(|_| EthApiError::InternalEthError)?
+    }
+
+    /// Returns the balance and nonce of the account after every block in the given range in which
+    /// it changed.
+    pub async fn account_history(
+        &self,
+        address: Address,
+        from_block: BlockNumberOrTag,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> EthResult<AccountHistory> {
+        self.on_blocking_task(|this| async move {
+            this.try_account_history(address, from_block, to_block, limit)
+        })
+        .await
+    }
+
+    fn try_account_history(
+        &self,
+        address: Address,
+        from_block: BlockNumberOrTag,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> EthResult<AccountHistory> {
+        let from = self
+            .provider()
+            .convert_block_number(from_block)?
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+        let to = self
+            .provider()
+            .convert_block_number(to_block.unwrap_or(BlockNumberOrTag::Latest))?
+            .ok_or(EthApiError::UnknownBlockNumber)?;
+        if from > to {
+            return Err(EthApiError::InvalidBlockRange)
+        }
+
+        let limit =
+            limit.unwrap_or(MAX_ACCOUNT_HISTORY_ENTRIES).clamp(1, MAX_ACCOUNT_HISTORY_ENTRIES);
+
+        // fetch one more entry than requested to determine where the next page starts
+        let mut history = self.provider().account_history(address, from..=to, limit + 1)?;
+        let next_block = if history.len() > limit {
+            history.pop().map(|(block_number, _)| U64::from(block_number))
+        } else {
+            None
+        };
+
+        let entries = history
+            .into_iter()
+            .map(|(block_number, account)| {
+                let account = account.unwrap_or_default();
+                AccountHistoryEntry {
+                    block_number: U64::from(block_number),
+                    balance: account.balance,
+                    nonce: U64::from(account.nonce),
+                }
+            })
+            .collect();
+
+        Ok(AccountHistory { entries, next_block })
+    }
+
+    /// Returns the number of blocks, gas used and priority fees per block beneficiary in the given
+    /// block range.
+    pub async fn beneficiary_fees(
+        &self,
+        from_block: BlockNumberOrTag,
+        to_block: Option<BlockNumberOrTag>,
+    ) -> EthResult<Vec<BeneficiaryFees>> {
+        self.on_blocking_task(|this| async move {
+            let from = this
+                .provider()
+                .convert_block_number(from_block)?
+                .ok_or(EthApiError::UnknownBlockNumber)?;
+            let to = this
+                .provider()
+                .convert_block_number(to_block.unwrap_or(BlockNumberOrTag::Latest))?
+                .ok_or(EthApiError::UnknownBlockNumber)?;
+            if from > to {
+                return Err(EthApiError::InvalidBlockRange)
+            }
+            if to - from >= MAX_BENEFICIARY_FEES_BLOCK_RANGE {
+                return Err(EthApiError::InvalidParams(format!(
+                    "block range exceeds the maximum of {MAX_BENEFICIARY_FEES_BLOCK_RANGE} blocks"
+                )))
+            }
+
+            let fees = reth_provider::beneficiary_fees(this.provider(), from..=to)?
+                .into_iter()
+                .map(|fees| BeneficiaryFees {
+                    beneficiary: fees.beneficiary,
+                    blocks: U64::from(fees.blocks),
+                    gas_used: U64::from(fees.gas_used),
+                    fees: fees.fees,
+                })
+                .collect();
+            Ok(fees)
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl<Provider> HlApiServer for HlApi<Provider>
+where
+    Provider: BlockReaderIdExt + AccountHistoryReader + ReadSnapshotFactory + 'static,
+{
+    /// Handler for `hl_getAccountHistory`
+    async fn get_account_history(
+        &self,
+        address: Address,
+        from_block: BlockNumberOrTag,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> RpcResult<AccountHistory> {
+        Ok(HlApi::account_history(self, address, from_block, to_block, limit).await?)
+    }
+
+    /// Handler for `hl_getBeneficiaryFees`
+    async fn get_beneficiary_fees(
+        &self,
+        from_block: BlockNumberOrTag,
+        to_block: Option<BlockNumberOrTag>,
+    ) -> RpcResult<Vec<BeneficiaryFees>> {
+        Ok(HlApi::beneficiary_fees(self, from_block, to_block).await?)
+    }
+}
+
+impl<Provider> std::fmt::Debug for HlApi<Provider> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HlApi").finish_non_exhaustive()
+    }
+}
+
+impl<Provider> Clone for HlApi<Provider> {
+    fn clone(&self) -> Self {
+        Self { inner: Arc::clone(&self.inner) }
+    }
+}
+
+struct HlApiInner<Provider> {
+    /// The provider that can interact with the chain.
+    provider: Provider,
+    /// The type that can spawn tasks which would otherwise block.
+    task_spawner: Box<dyn TaskSpawner>,
+}
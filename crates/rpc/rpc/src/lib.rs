@@ -28,24 +28,28 @@ mod admin;
 mod debug;
 mod engine;
 pub mod eth;
+mod hl;
 mod layers;
 mod net;
 mod otterscan;
 mod reth;
 mod rpc;
 mod trace;
+mod trace_cache;
 mod txpool;
 mod web3;
 pub use admin::AdminApi;
 pub use debug::DebugApi;
 pub use engine::{EngineApi, EngineEthApi};
 pub use eth::{EthApi, EthApiSpec, EthFilter, EthPubSub, EthSubscriptionIdProvider};
+pub use hl::{HlApi, MAX_ACCOUNT_HISTORY_ENTRIES, MAX_BENEFICIARY_FEES_BLOCK_RANGE};
 pub use layers::{AuthLayer, AuthValidator, Claims, JwtAuthValidator, JwtError, JwtSecret};
 pub use net::NetApi;
 pub use otterscan::OtterscanApi;
 pub use reth::RethApi;
 pub use rpc::RPCApi;
 pub use trace::TraceApi;
+pub use trace_cache::{TraceCache, TraceCacheConfig, DEFAULT_TRACE_CACHE_MAX_SIZE};
 pub use txpool::TxPoolApi;
 pub use web3::Web3Api;
 pub mod result;
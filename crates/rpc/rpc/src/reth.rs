@@ -2,18 +2,24 @@ use crate::eth::error::{EthApiError, EthResult};
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
 use reth_interfaces::RethResult;
-use reth_primitives::{Address, BlockId, U256};
-use reth_provider::{BlockReaderIdExt, ChangeSetReader, StateProviderFactory};
+use reth_primitives::{Address, BlockId, U256, U64};
+use reth_provider::{
+    providers::StaticFileSegmentStats, BlockReaderIdExt, ChangeSetReader, StateProviderFactory,
+    StaticFileProviderFactory,
+};
 use reth_rpc_api::RethApiServer;
+use reth_rpc_types::{DiskSpace, StaticFileStats};
 use reth_tasks::TaskSpawner;
 use std::{collections::HashMap, future::Future, sync::Arc};
-use tokio::sync::oneshot;
+use tokio::sync::{oneshot, watch};
 
 /// `reth` API implementation.
 ///
 /// This type provides the functionality for handling `reth` prototype RPC requests.
 pub struct RethApi<Provider> {
     inner: Arc<RethApiInner<Provider>>,
+    /// The free disk space reported by the disk space monitor, if it's running.
+    disk_space: Option<watch::Receiver<DiskSpace>>,
 }
 
 // === impl RethApi ===
@@ -27,13 +33,33 @@ impl<Provider> RethApi<Provider> {
     /// Create a new instance of the [RethApi]
     pub fn new(provider: Provider, task_spawner: Box<dyn TaskSpawner>) -> Self {
         let inner = Arc::new(RethApiInner { provider, task_spawner });
-        Self { inner }
+        Self { inner, disk_space: None }
+    }
+
+    /// Configures the source of the free disk space served by `reth_diskSpace`.
+    pub fn with_disk_space(mut self, disk_space: watch::Receiver<DiskSpace>) -> Self {
+        self.disk_space = Some(disk_space);
+        self
+    }
+
+    /// Returns the free disk space of the node.
+    ///
+    /// Fails if the disk space monitor isn't running.
+    pub fn disk_space(&self) -> EthResult<DiskSpace> {
+        self.disk_space
+            .as_ref()
+            .map(|disk_space| *disk_space.borrow())
+            .ok_or(EthApiError::Unsupported("disk space monitor is disabled"))
     }
 }
 
 impl<Provider> RethApi<Provider>
 where
-    Provider: BlockReaderIdExt + ChangeSetReader + StateProviderFactory + 'static,
+    Provider: BlockReaderIdExt
+        + ChangeSetReader
+        + StateProviderFactory
+        + StaticFileProviderFactory
+        + 'static,
 {
     /// Executes the future on a new blocking task.
     async fn on_blocking_task<C, F, R>(&self, c: C) -> EthResult<R>
@@ -61,6 +87,30 @@ where
             .await
     }
 
+    /// Returns statistics of the static files of every segment.
+    pub async fn static_file_stats(&self) -> EthResult<Vec<StaticFileStats>> {
+        self.on_blocking_task(|this| async move { this.try_static_file_stats() }).await
+    }
+
+    fn try_static_file_stats(&self) -> EthResult<Vec<StaticFileStats>> {
+        let stats = self.provider().static_file_provider().segment_stats()?;
+        Ok(stats
+            .into_iter()
+            .map(|StaticFileSegmentStats { segment, files, size, rows, block_range, tx_range }| {
+                StaticFileStats {
+                    segment: segment.as_str().to_string(),
+                    files: U64::from(files),
+                    size: U64::from(size),
+                    rows: U64::from(rows),
+                    from_block: U64::from(block_range.start()),
+                    to_block: U64::from(block_range.end()),
+                    from_tx: tx_range.map(|range| U64::from(range.start())),
+                    to_tx: tx_range.map(|range| U64::from(range.end())),
+                }
+            })
+            .collect())
+    }
+
     fn try_balance_changes_in_block(&self, block_id: BlockId) -> EthResult<HashMap<Address, U256>> {
         let Some(block_number) = self.provider().block_number_for_id(block_id)? else {
             return Err(EthApiError::UnknownBlockNumber)
@@ -86,7 +136,11 @@ where
 #[async_trait]
 impl<Provider> RethApiServer for RethApi<Provider>
 where
-    Provider: BlockReaderIdExt + ChangeSetReader + StateProviderFactory + 'static,
+    Provider: BlockReaderIdExt
+        + ChangeSetReader
+        + StateProviderFactory
+        + StaticFileProviderFactory
+        + 'static,
 {
     /// Handler for `reth_getBalanceChangesInBlock`
     async fn reth_get_balance_changes_in_block(
@@ -95,6 +149,16 @@ where
     ) -> RpcResult<HashMap<Address, U256>> {
         Ok(RethApi::balance_changes_in_block(self, block_id).await?)
     }
+
+    /// Handler for `reth_staticFileStats`
+    async fn reth_static_file_stats(&self) -> RpcResult<Vec<StaticFileStats>> {
+        Ok(RethApi::static_file_stats(self).await?)
+    }
+
+    /// Handler for `reth_diskSpace`
+    async fn reth_disk_space(&self) -> RpcResult<DiskSpace> {
+        Ok(RethApi::disk_space(self)?)
+    }
 }
 
 impl<Provider> std::fmt::Debug for RethApi<Provider> {
@@ -105,7 +169,7 @@ impl<Provider> std::fmt::Debug for RethApi<Provider> {
 
 impl<Provider> Clone for RethApi<Provider> {
     fn clone(&self) -> Self {
-        Self { inner: Arc::clone(&self.inner) }
+        Self { inner: Arc::clone(&self.inner), disk_space: self.disk_space.clone() }
     }
 }
 
@@ -115,3 +179,26 @@ struct RethApiInner<Provider> {
     /// The type that can spawn tasks which would otherwise block.
     task_spawner: Box<dyn TaskSpawner>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_provider::test_utils::NoopProvider;
+    use reth_rpc_types::DiskSpaceStatus;
+    use reth_tasks::TokioTaskExecutor;
+
+    #[test]
+    fn disk_space() {
+        let api = RethApi::new(NoopProvider::default(), Box::<TokioTaskExecutor>::default());
+        assert!(matches!(api.disk_space(), Err(EthApiError::Unsupported(_))));
+
+        let (disk_space_tx, disk_space_rx) = watch::channel(DiskSpace::default());
+        let api = api.with_disk_space(disk_space_rx);
+        assert_eq!(api.disk_space().unwrap(), DiskSpace::default());
+
+        let critical =
+            DiskSpace { status: DiskSpaceStatus::Critical, available: Some(U64::from(1)) };
+        disk_space_tx.send_replace(critical);
+        assert_eq!(api.clone().disk_space().unwrap(), critical);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::eth::{
     error::{EthApiError, EthResult},
+    parallel_trace::{trace_transactions, trace_transactions_parallel},
     revm_utils::{prepare_call_env, EvmOverrides},
     utils::recover_raw_transaction,
     EthTransactions,
@@ -8,11 +9,16 @@ use async_trait::async_trait;
 use jsonrpsee::core::RpcResult as Result;
 use reth_consensus_common::calc::{base_block_reward, block_reward};
 use reth_primitives::{
-    revm::env::tx_env_with_recovered, BlockId, BlockNumberOrTag, Bytes, SealedHeader, B256, U256,
+    revm::env::tx_env_with_recovered, BlockId, BlockNumberOrTag, Bytes, SealedBlockWithSenders,
+    SealedHeader, B256, U256,
+};
+use reth_provider::{
+    providers::{ReadSnapshotManager, DEFAULT_MAX_READ_SNAPSHOT_AGE},
+    BlockReader, ChainSpecProvider, EvmEnvProvider, ReadSnapshotFactory, StateProviderBox,
+    StateProviderFactory,
 };
-use reth_provider::{BlockReader, ChainSpecProvider, EvmEnvProvider, StateProviderFactory};
 use reth_revm::{
-    database::StateProviderDatabase,
+    database::{StateProviderDatabase, SubState},
     tracing::{parity::populate_state_diff, TracingInspector, TracingInspectorConfig},
 };
 use reth_rpc_api::TraceApiServer;
@@ -24,22 +30,27 @@ use reth_rpc_types::{
         parity::*,
         tracerequest::TraceCallRequest,
     },
-    BlockError, BlockOverrides, Index, TransactionRequest,
+    BlockError, BlockOverrides, Index, TransactionInfo, TransactionRequest,
 };
 use reth_tasks::pool::BlockingTaskGuard;
 use revm::{
     db::{CacheDB, DatabaseCommit},
-    primitives::EnvWithHandlerCfg,
+    primitives::{BlockEnv, CfgEnvWithHandlerCfg, EnvWithHandlerCfg},
 };
 use revm_inspectors::opcode::OpcodeGasInspector;
 use std::{collections::HashSet, sync::Arc};
 use tokio::sync::{AcquireError, OwnedSemaphorePermit};
 
+/// The number of blocks `trace_filter` reads from a single read snapshot.
+const TRACE_FILTER_BLOCKS_PER_SNAPSHOT: u64 = 10;
+
 /// `trace` API implementation.
 ///
 /// This type provides the functionality for handling `trace` related requests.
 pub struct TraceApi<Provider, Eth> {
     inner: Arc<TraceApiInner<Provider, Eth>>,
+    /// Blocks with at least this many transactions are traced in parallel, if set.
+    parallel_tracing_min_transactions: Option<usize>,
 }
 
 // === impl TraceApi ===
@@ -53,7 +64,14 @@ impl<Provider, Eth> TraceApi<Provider, Eth> {
     /// Create a new instance of the [TraceApi]
     pub fn new(provider: Provider, eth_api: Eth, blocking_task_guard: BlockingTaskGuard) -> Self {
         let inner = Arc::new(TraceApiInner { provider, eth_api, blocking_task_guard });
-        Self { inner }
+        Self { inner, parallel_tracing_min_transactions: None }
+    }
+
+    /// Traces the transactions of blocks with at least `min_transactions` transactions in
+    /// parallel.
+    pub fn with_parallel_tracing(mut self, min_transactions: usize) -> Self {
+        self.parallel_tracing_min_transactions = Some(min_transactions);
+        self
     }
 
     /// Acquires a permit to execute a tracing call.
@@ -73,7 +91,12 @@ impl<Provider, Eth> TraceApi<Provider, Eth> {
 
 impl<Provider, Eth> TraceApi<Provider, Eth>
 where
-    Provider: BlockReader + StateProviderFactory + EvmEnvProvider + ChainSpecProvider + 'static,
+    Provider: BlockReader
+        + StateProviderFactory
+        + EvmEnvProvider
+        + ChainSpecProvider
+        + ReadSnapshotFactory
+        + 'static,
     Eth: EthTransactions + 'static,
 {
     /// Executes the given call and returns a number of possible traces for it.
@@ -261,26 +284,46 @@ where
             ))
         }
 
-        // fetch all blocks in that range
-        let blocks = self.provider().block_range(start..=end)?;
-
-        // find relevant blocks to trace
+        // find relevant blocks to trace, reading the range in chunks so the scan doesn't keep a
+        // single read transaction open
         let mut target_blocks = Vec::new();
-        for block in blocks {
-            let mut transaction_indices = HashSet::new();
-            let mut highest_matching_index = 0;
-            for (tx_idx, tx) in block.body.iter().enumerate() {
-                let from = tx.recover_signer().ok_or(BlockError::InvalidSignature)?;
-                let to = tx.to();
-                if matcher.matches(from, to) {
-                    let idx = tx_idx as u64;
-                    transaction_indices.insert(idx);
-                    highest_matching_index = idx;
+        let mut snapshots =
+            ReadSnapshotManager::new(self.provider(), DEFAULT_MAX_READ_SNAPSHOT_AGE);
+        let mut invalid_signature = false;
+        snapshots.for_each_chunk(
+            start..=end,
+            TRACE_FILTER_BLOCKS_PER_SNAPSHOT,
+            |snapshot, chunk| {
+                for block in snapshot.block_range(chunk)? {
+                    let mut transaction_indices = HashSet::new();
+                    let mut highest_matching_index = 0;
+                    for (tx_idx, tx) in block.body.iter().enumerate() {
+                        let Some(from) = tx.recover_signer() else {
+                            invalid_signature = true;
+                            return Ok(())
+                        };
+                        let to = tx.to();
+                        if matcher.matches(from, to) {
+                            let idx = tx_idx as u64;
+                            transaction_indices.insert(idx);
+                            highest_matching_index = idx;
+                        }
+                    }
+                    if !transaction_indices.is_empty() {
+                        target_blocks.push((
+                            block.number,
+                            transaction_indices,
+                            highest_matching_index,
+                        ));
+                    }
                 }
-            }
-            if !transaction_indices.is_empty() {
-                target_blocks.push((block.number, transaction_indices, highest_matching_index));
-            }
+                Ok(())
+            },
+        )?;
+        // don't keep the read transaction open while tracing
+        drop(snapshots);
+        if invalid_signature {
+            return Err(BlockError::InvalidSignature.into())
         }
 
         // trace all relevant blocks
@@ -343,17 +386,7 @@ where
         &self,
         block_id: BlockId,
     ) -> EthResult<Option<Vec<LocalizedTransactionTrace>>> {
-        let traces = self.inner.eth_api.trace_block_with(
-            block_id,
-            TracingInspectorConfig::default_parity(),
-            |tx_info, inspector, res, _, _| {
-                let traces = inspector
-                    .with_transaction_gas_used(res.gas_used())
-                    .into_parity_builder()
-                    .into_localized_transaction_traces(tx_info);
-                Ok(traces)
-            },
-        );
+        let traces = self.trace_block_transactions(block_id);
 
         let block = self.inner.eth_api.block_by_id(block_id);
         let (maybe_traces, maybe_block) = futures::try_join!(traces, block)?;
@@ -398,6 +431,88 @@ where
         Ok(maybe_traces)
     }
 
+    /// Returns the traces of all transactions in the block.
+    ///
+    /// Blocks with at least the configured number of transactions are traced in parallel, see
+    /// [trace_transactions_parallel].
+    async fn trace_block_transactions(
+        &self,
+        block_id: BlockId,
+    ) -> EthResult<Option<Vec<Vec<LocalizedTransactionTrace>>>> {
+        let ((cfg, block_env, _), block) = futures::try_join!(
+            self.inner.eth_api.evm_env_at(block_id),
+            self.inner.eth_api.block_by_id_with_senders(block_id),
+        )?;
+        let Some(block) = block else { return Ok(None) };
+        if block.body.is_empty() {
+            // nothing to trace
+            return Ok(Some(Vec::new()))
+        }
+
+        let parallel =
+            self.parallel_tracing_min_transactions.is_some_and(|min| block.body.len() >= min);
+        self.trace_sealed_block(block, cfg, block_env, parallel).await.map(Some)
+    }
+
+    /// Traces all transactions of the block, either one after another or in parallel.
+    async fn trace_sealed_block(
+        &self,
+        block: SealedBlockWithSenders,
+        cfg: CfgEnvWithHandlerCfg,
+        block_env: BlockEnv,
+        parallel: bool,
+    ) -> EthResult<Vec<Vec<LocalizedTransactionTrace>>> {
+        let this = self.clone();
+        self.inner
+            .eth_api
+            .spawn_blocking(move || {
+                // we need to get the state of the parent block because we're replaying this block
+                // on top of its parent block's state
+                let state_at = block.parent_hash;
+                let block_hash = block.hash();
+                let block_number = block_env.number.saturating_to::<u64>();
+                let base_fee = block_env.basefee.saturating_to::<u64>();
+
+                let (tx_infos, envs): (Vec<_>, Vec<_>) = block
+                    .into_transactions_ecrecovered()
+                    .enumerate()
+                    .map(|(idx, tx)| {
+                        let tx_info = TransactionInfo {
+                            hash: Some(tx.hash()),
+                            index: Some(idx as u64),
+                            block_hash: Some(block_hash),
+                            block_number: Some(block_number),
+                            base_fee: Some(base_fee),
+                        };
+                        let env = EnvWithHandlerCfg::new_with_cfg_env(
+                            cfg.clone(),
+                            block_env.clone(),
+                            tx_env_with_recovered(&tx),
+                        );
+                        (tx_info, env)
+                    })
+                    .unzip();
+
+                let trace =
+                    |index: usize, env: EnvWithHandlerCfg, db: &mut SubState<StateProviderBox>| {
+                        let mut inspector =
+                            TracingInspector::new(TracingInspectorConfig::default_parity());
+                        let (res, _) = this.eth_api().inspect(&mut *db, env, &mut inspector)?;
+                        let traces = inspector
+                            .with_transaction_gas_used(res.result.gas_used())
+                            .into_parity_builder()
+                            .into_localized_transaction_traces(tx_infos[index].clone());
+                        Ok((traces, res.state))
+                    };
+                if parallel {
+                    trace_transactions_parallel(this.eth_api(), state_at.into(), envs, trace)
+                } else {
+                    trace_transactions(this.eth_api(), state_at.into(), envs, trace)
+                }
+            })
+            .await
+    }
+
     /// Replays all transactions in a block
     pub async fn replay_block_transactions(
         &self,
@@ -490,7 +605,12 @@ where
 #[async_trait]
 impl<Provider, Eth> TraceApiServer for TraceApi<Provider, Eth>
 where
-    Provider: BlockReader + StateProviderFactory + EvmEnvProvider + ChainSpecProvider + 'static,
+    Provider: BlockReader
+        + StateProviderFactory
+        + EvmEnvProvider
+        + ChainSpecProvider
+        + ReadSnapshotFactory
+        + 'static,
     Eth: EthTransactions + 'static,
 {
     /// Executes the given call and returns a number of possible traces for it.
@@ -613,7 +733,10 @@ impl<Provider, Eth> std::fmt::Debug for TraceApi<Provider, Eth> {
 }
 impl<Provider, Eth> Clone for TraceApi<Provider, Eth> {
     fn clone(&self) -> Self {
-        Self { inner: Arc::clone(&self.inner) }
+        Self {
+            inner: Arc::clone(&self.inner),
+            parallel_tracing_min_transactions: self.parallel_tracing_min_transactions,
+        }
     }
 }
 
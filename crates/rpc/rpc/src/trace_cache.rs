@@ -0,0 +1,405 @@
+//! On-disk cache for block traces.
+
+use parking_lot::Mutex;
+use reth_metrics::{
+    metrics::{Counter, Gauge},
+    Metrics,
+};
+use reth_primitives::{keccak256, B256};
+use reth_rpc_types::trace::geth::{
+    CallFrame, GethDebugBuiltInTracerType, GethDebugTracerType, GethDebugTracingOptions, GethTrace,
+    PreStateFrame, TraceResult,
+};
+use schnellru::{ByLength, LruMap};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+use tracing::{debug, trace};
+
+/// The default maximum size of all cached traces: 1GB.
+pub const DEFAULT_TRACE_CACHE_MAX_SIZE: u64 = 1024 * 1024 * 1024;
+
+/// File extension of cached traces.
+const TRACE_FILE_EXTENSION: &str = "json";
+
+/// Settings for the [TraceCache].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TraceCacheConfig {
+    /// The directory the traces are stored in.
+    pub dir: PathBuf,
+    /// The maximum size of all cached traces in bytes.
+    pub max_size: u64,
+}
+
+impl TraceCacheConfig {
+    /// Creates a new config that stores traces in the given directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into(), max_size: DEFAULT_TRACE_CACHE_MAX_SIZE }
+    }
+
+    /// Configures the maximum size of all cached traces in bytes.
+    pub fn max_size(mut self, max_size: u64) -> Self {
+        self.max_size = max_size;
+        self
+    }
+}
+
+/// A size bounded cache for the traces of entire blocks that persists across restarts.
+///
+/// Traces are keyed by the block hash and the tracing options they were computed with, so the
+/// cached results are exactly what re-executing the block would return. Only the results of the
+/// `callTracer` and `prestateTracer` are cached, because they're deterministic and the most
+/// commonly requested ones. Once the total size exceeds the configured maximum, the least recently
+/// used traces are evicted.
+#[derive(Debug)]
+pub struct TraceCache {
+    /// The directory the traces are stored in.
+    dir: PathBuf,
+    /// The maximum size of all cached traces in bytes.
+    max_size: u64,
+    /// Tracks the size of the cached traces, ordered by recent use.
+    index: Mutex<TraceCacheIndex>,
+    metrics: TraceCacheMetrics,
+}
+
+impl TraceCache {
+    /// Opens the cache in the configured directory, picking up the traces that were cached
+    /// previously.
+    pub fn open(config: TraceCacheConfig) -> io::Result<Self> {
+        let TraceCacheConfig { dir, max_size } = config;
+        fs::create_dir_all(&dir)?;
+
+        let mut files = Vec::new();
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let Some(key) = trace_key_from_path(&path) else {
+                // leftovers of interrupted writes
+                if path.extension().is_some_and(|ext| ext == "tmp") {
+                    let _ = fs::remove_file(&path);
+                }
+                continue
+            };
+            let metadata = fs::metadata(&path)?;
+            files.push((metadata.modified()?, key, metadata.len()));
+        }
+        // the least recently written traces are evicted first
+        files.sort_unstable_by_key(|(modified, _, _)| *modified);
+
+        let cache = Self {
+            dir,
+            max_size,
+            index: Mutex::new(TraceCacheIndex::default()),
+            metrics: TraceCacheMetrics::default(),
+        };
+        let evicted = {
+            let mut index = cache.index.lock();
+            for (_, key, size) in files {
+                index.insert(key, size);
+            }
+            index.evict(max_size)
+        };
+        cache.remove_files(evicted);
+        cache.update_size_metrics();
+
+        Ok(cache)
+    }
+
+    /// Returns the cached traces of the block with the given hash, if they were computed with the
+    /// same options before.
+    pub fn get(
+        &self,
+        block_hash: B256,
+        opts: &GethDebugTracingOptions,
+    ) -> Option<Vec<TraceResult>> {
+        let tracer = CachedTracer::from_opts(opts)?;
+        let key = trace_key(block_hash, opts);
+        if self.index.lock().get(&key).is_none() {
+            self.metrics.misses_total.increment(1);
+            return None
+        }
+
+        match self.read(key, tracer) {
+            Ok(traces) => {
+                trace!(target: "rpc::trace_cache", ?block_hash, "Serving cached block traces");
+                self.metrics.hits_total.increment(1);
+                Some(traces)
+            }
+            Err(err) => {
+                debug!(target: "rpc::trace_cache", ?block_hash, %err, "Failed to read cached block traces");
+                self.metrics.misses_total.increment(1);
+                self.index.lock().remove(&key);
+                self.remove_files([key]);
+                self.update_size_metrics();
+                None
+            }
+        }
+    }
+
+    /// Caches the traces of the block with the given hash that were computed with the given
+    /// options.
+    ///
+    /// Traces of tracers that aren't cached, and results that contain errors are ignored.
+    pub fn insert(
+        &self,
+        block_hash: B256,
+        opts: &GethDebugTracingOptions,
+        traces: &[TraceResult],
+    ) -> io::Result<()> {
+        if CachedTracer::from_opts(opts).is_none() {
+            return Ok(())
+        }
+
+        let mut cached = Vec::with_capacity(traces.len());
+        for trace in traces {
+            let TraceResult::Success { result, tx_hash } = trace else { return Ok(()) };
+            cached.push(CachedTrace { tx_hash: *tx_hash, result });
+        }
+        let data = serde_json::to_vec(&cached)?;
+        let size = data.len() as u64;
+        if size > self.max_size {
+            return Ok(())
+        }
+
+        // write to a temporary file first, so readers never observe partially written traces
+        let key = trace_key(block_hash, opts);
+        let path = self.trace_path(key);
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, data)?;
+        fs::rename(&tmp_path, &path)?;
+
+        let evicted = {
+            let mut index = self.index.lock();
+            index.insert(key, size);
+            index.evict(self.max_size)
+        };
+        self.metrics.evicted_total.increment(evicted.len() as u64);
+        self.remove_files(evicted);
+        self.update_size_metrics();
+
+        Ok(())
+    }
+
+    /// Reads and decodes the traces of the given tracer.
+    fn read(&self, key: B256, tracer: CachedTracer) -> io::Result<Vec<TraceResult>> {
+        let data = fs::read(self.trace_path(key))?;
+        let traces = match tracer {
+            CachedTracer::Call => decode_traces::<CallFrame>(&data, GethTrace::CallTracer)?,
+            CachedTracer::PreState => {
+                decode_traces::<PreStateFrame>(&data, GethTrace::PreStateTracer)?
+            }
+        };
+        Ok(traces)
+    }
+
+    /// Removes the trace files of the given keys.
+    fn remove_files(&self, keys: impl IntoIterator<Item = B256>) {
+        for key in keys {
+            if let Err(err) = fs::remove_file(self.trace_path(key)) {
+                debug!(target: "rpc::trace_cache", ?key, %err, "Failed to remove cached block traces");
+            }
+        }
+    }
+
+    fn update_size_metrics(&self) {
+        let index = self.index.lock();
+        self.metrics.entries.set(index.entries.len() as f64);
+        self.metrics.size_bytes.set(index.total_size as f64);
+    }
+
+    /// Returns the path of the file that holds the traces with the given key.
+    fn trace_path(&self, key: B256) -> PathBuf {
+        self.dir.join(format!("{key:x}.{TRACE_FILE_EXTENSION}"))
+    }
+}
+
+/// The tracers whose results are cached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CachedTracer {
+    /// The `callTracer`.
+    Call,
+    /// The `prestateTracer`.
+    PreState,
+}
+
+impl CachedTracer {
+    /// Returns the tracer configured in the options, if its results are cached.
+    fn from_opts(opts: &GethDebugTracingOptions) -> Option<Self> {
+        match opts.tracer.as_ref()? {
+            GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::CallTracer) => {
+                Some(Self::Call)
+            }
+            GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::PreStateTracer) => {
+                Some(Self::PreState)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The trace of a single transaction as it's stored on disk.
+///
+/// [GethTrace] is untagged, so the frames are decoded as the type of the requested tracer.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedTrace<T> {
+    tx_hash: Option<B256>,
+    result: T,
+}
+
+/// Decodes stored traces with frames of type `T`.
+fn decode_traces<T: DeserializeOwned>(
+    data: &[u8],
+    into_trace: impl Fn(T) -> GethTrace,
+) -> io::Result<Vec<TraceResult>> {
+    let traces: Vec<CachedTrace<T>> = serde_json::from_slice(data)?;
+    Ok(traces
+        .into_iter()
+        .map(|trace| TraceResult::Success {
+            result: into_trace(trace.result),
+            tx_hash: trace.tx_hash,
+        })
+        .collect())
+}
+
+/// Returns the key of the traces of the given block computed with the given options.
+///
+/// The timeout doesn't affect the results, so it's not part of the key.
+fn trace_key(block_hash: B256, opts: &GethDebugTracingOptions) -> B256 {
+    let opts = GethDebugTracingOptions { timeout: None, ..opts.clone() };
+    let mut preimage = block_hash.to_vec();
+    preimage.extend(serde_json::to_vec(&opts).expect("tracing options are serializable"));
+    keccak256(preimage)
+}
+
+/// Parses the key from the path of a trace file.
+fn trace_key_from_path(path: &Path) -> Option<B256> {
+    if path.extension()? != TRACE_FILE_EXTENSION {
+        return None
+    }
+    path.file_stem()?.to_str()?.parse().ok()
+}
+
+/// The sizes of all cached traces, ordered by recent use.
+#[derive(Debug)]
+struct TraceCacheIndex {
+    entries: LruMap<B256, u64>,
+    total_size: u64,
+}
+
+impl Default for TraceCacheIndex {
+    fn default() -> Self {
+        Self { entries: LruMap::new(ByLength::new(u32::MAX)), total_size: 0 }
+    }
+}
+
+impl TraceCacheIndex {
+    /// Returns the size of the entry and marks it as most recently used.
+    fn get(&mut self, key: &B256) -> Option<u64> {
+        self.entries.get(key).copied()
+    }
+
+    fn insert(&mut self, key: B256, size: u64) {
+        self.remove(&key);
+        self.entries.insert(key, size);
+        self.total_size += size;
+    }
+
+    fn remove(&mut self, key: &B256) {
+        if let Some(size) = self.entries.remove(key) {
+            self.total_size -= size;
+        }
+    }
+
+    /// Evicts the least recently used entries until the total size is within the given maximum
+    /// and returns their keys.
+    fn evict(&mut self, max_size: u64) -> Vec<B256> {
+        let mut evicted = Vec::new();
+        while self.total_size > max_size {
+            let Some((key, size)) = self.entries.pop_oldest() else { break };
+            self.total_size -= size;
+            evicted.push(key);
+        }
+        evicted
+    }
+}
+
+/// Metrics for the [TraceCache].
+#[derive(Metrics)]
+#[metrics(scope = "rpc.trace_cache")]
+struct TraceCacheMetrics {
+    /// The number of block traces served from the cache
+    hits_total: Counter,
+    /// The number of block trace requests that weren't cached
+    misses_total: Counter,
+    /// The number of block traces evicted from the cache
+    evicted_total: Counter,
+    /// The number of cached block traces
+    entries: Gauge,
+    /// The total size of all cached block traces in bytes
+    size_bytes: Gauge,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::U256;
+    use reth_rpc_types::trace::geth::GethDebugTracerConfig;
+
+    fn call_tracer_opts() -> GethDebugTracingOptions {
+        GethDebugTracingOptions {
+            tracer: Some(GethDebugBuiltInTracerType::CallTracer.into()),
+            ..Default::default()
+        }
+    }
+
+    fn call_traces(gas: u64) -> Vec<TraceResult> {
+        vec![TraceResult::Success {
+            result: GethTrace::CallTracer(CallFrame { gas: U256::from(gas), ..Default::default() }),
+            tx_hash: Some(B256::with_last_byte(1)),
+        }]
+    }
+
+    #[test]
+    fn caches_and_evicts_block_traces() {
+        let dir = tempfile::tempdir().unwrap();
+        let opts = call_tracer_opts();
+        let traces = call_traces(21_000);
+        let (first, second, third) =
+            (B256::with_last_byte(1), B256::with_last_byte(2), B256::with_last_byte(3));
+
+        // size the cache so it holds exactly two traces
+        let cache = TraceCache::open(TraceCacheConfig::new(dir.path())).unwrap();
+        cache.insert(first, &opts, &traces).unwrap();
+        let max_size = cache.index.lock().total_size * 2;
+        drop(cache);
+        let cache = TraceCache::open(TraceCacheConfig::new(dir.path()).max_size(max_size)).unwrap();
+        assert_eq!(cache.get(first, &opts), Some(traces.clone()));
+
+        // different tracer configs are cached separately, and other tracers aren't cached at all
+        let with_logs = GethDebugTracingOptions {
+            tracer_config: GethDebugTracerConfig(serde_json::json!({ "withLog": true })),
+            ..call_tracer_opts()
+        };
+        assert_eq!(cache.get(first, &with_logs), None);
+        let four_byte = GethDebugTracingOptions {
+            tracer: Some(GethDebugBuiltInTracerType::FourByteTracer.into()),
+            ..Default::default()
+        };
+        cache.insert(first, &four_byte, &traces).unwrap();
+        assert_eq!(cache.get(first, &four_byte), None);
+
+        // `first` was used most recently, so `second` is evicted
+        cache.insert(second, &opts, &traces).unwrap();
+        cache.get(first, &opts).unwrap();
+        cache.insert(third, &opts, &traces).unwrap();
+        assert_eq!(cache.get(second, &opts), None);
+
+        // cached traces survive a restart
+        drop(cache);
+        let cache = TraceCache::open(TraceCacheConfig::new(dir.path()).max_size(max_size)).unwrap();
+        assert_eq!(cache.get(first, &opts), Some(traces.clone()));
+        assert_eq!(cache.get(third, &opts), Some(traces));
+    }
+}
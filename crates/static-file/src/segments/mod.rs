@@ -16,8 +16,7 @@ use reth_interfaces::provider::ProviderResult;
 use reth_nippy_jar::NippyJar;
 use reth_primitives::{
     static_file::{
-        find_fixed_range, Compression, Filters, InclusionFilter, PerfectHashingFunction,
-        SegmentConfig, SegmentHeader,
+        Compression, Filters, InclusionFilter, PerfectHashingFunction, SegmentConfig, SegmentHeader,
     },
     BlockNumber, StaticFileSegment,
 };
@@ -73,7 +72,11 @@ pub(crate) fn prepare_jar<DB: Database, const COLUMNS: usize>(
 
     let mut nippy_jar = NippyJar::new(
         COLUMNS,
-        &directory.as_ref().join(segment.filename(&find_fixed_range(*block_range.end())).as_str()),
+        &directory.as_ref().join(
+            segment
+                .filename(&provider.static_file_provider().find_fixed_range(*block_range.end()))
+                .as_str(),
+        ),
         SegmentHeader::new(block_range.clone().into(), Some(block_range.into()), tx_range, segment),
     );
 
@@ -13,7 +13,7 @@ use crate::{
 };
 use eyre::Context;
 use metrics::{gauge, Label};
-use reth_interfaces::db::LogLevel;
+use reth_interfaces::db::{LogLevel, SyncMode as DatabaseSyncMode};
 use reth_libmdbx::{
     DatabaseFlags, Environment, EnvironmentFlags, Geometry, MaxReadTransactionDuration, Mode,
     PageSize, SyncMode, RO, RW,
@@ -66,6 +66,17 @@ pub struct DatabaseArguments {
     log_level: Option<LogLevel>,
     /// Maximum duration of a read transaction. If [None], the default value is used.
     max_read_transaction_duration: Option<MaxReadTransactionDuration>,
+    /// Maximum size of the database in bytes. If [None], the default value is used.
+    max_size: Option<usize>,
+    /// Step in bytes by which the database file grows. If [None], the default value is used.
+    growth_step: Option<usize>,
+    /// Sync mode of read-write environments. If [None], [SyncMode::Durable] is used.
+    sync_mode: Option<DatabaseSyncMode>,
+    /// Enable OS read-ahead for the database file. If [None], read-ahead is disabled.
+    ///
+    /// Read-ahead improves performance of linear scans, but worsens it for random access, which
+    /// is the access pattern of the node outside of the initial sync.
+    read_ahead: Option<bool>,
     /// Open environment in exclusive/monopolistic mode. If [None], the default value is used.
     ///
     /// This can be used as a replacement for `MDB_NOLOCK`, which don't supported by MDBX. In this
@@ -96,6 +107,10 @@ impl DatabaseArguments {
             client_version,
             log_level: None,
             max_read_transaction_duration: None,
+            max_size: None,
+            growth_step: None,
+            sync_mode: None,
+            read_ahead: None,
             exclusive: None,
         }
     }
@@ -115,6 +130,30 @@ impl DatabaseArguments {
         self
     }
 
+    /// Set the maximum size of the database in bytes.
+    pub fn with_max_size(mut self, max_size: Option<usize>) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Set the step in bytes by which the database file grows.
+    pub fn with_growth_step(mut self, growth_step: Option<usize>) -> Self {
+        self.growth_step = growth_step;
+        self
+    }
+
+    /// Set the sync mode of read-write environments.
+    pub fn with_sync_mode(mut self, sync_mode: Option<DatabaseSyncMode>) -> Self {
+        self.sync_mode = sync_mode;
+        self
+    }
+
+    /// Set whether the OS read-ahead is enabled for the database file.
+    pub fn with_read_ahead(mut self, read_ahead: Option<bool>) -> Self {
+        self.read_ahead = read_ahead;
+        self
+    }
+
     /// Set the mdbx exclusive flag.
     pub fn with_exclusive(mut self, exclusive: Option<bool>) -> Self {
         self.exclusive = exclusive;
@@ -254,7 +293,12 @@ impl DatabaseEnv {
             DatabaseEnvKind::RW => {
                 // enable writemap mode in RW mode
                 inner_env.write_map();
-                Mode::ReadWrite { sync_mode: SyncMode::Durable }
+                let sync_mode = match args.sync_mode.unwrap_or_default() {
+                    DatabaseSyncMode::Durable => SyncMode::Durable,
+                    DatabaseSyncMode::NoMetaSync => SyncMode::NoMetaSync,
+                    DatabaseSyncMode::SafeNoSync => SyncMode::SafeNoSync,
+                };
+                Mode::ReadWrite { sync_mode }
             }
         };
 
@@ -263,10 +307,10 @@ impl DatabaseEnv {
         debug_assert!(Tables::ALL.len() <= 256, "number of tables exceed max dbs");
         inner_env.set_max_dbs(256);
         inner_env.set_geometry(Geometry {
-            // Maximum database size of 4 terabytes
-            size: Some(0..(4 * TERABYTE)),
-            // We grow the database in increments of 4 gigabytes
-            growth_step: Some(4 * GIGABYTE as isize),
+            // Maximum database size of 4 terabytes by default
+            size: Some(0..args.max_size.unwrap_or(4 * TERABYTE)),
+            // We grow the database in increments of 4 gigabytes by default
+            growth_step: Some(args.growth_step.unwrap_or(4 * GIGABYTE) as isize),
             // The database never shrinks
             shrink_threshold: None,
             page_size: Some(PageSize::Set(default_page_size())),
@@ -309,9 +353,9 @@ impl DatabaseEnv {
         }
         inner_env.set_flags(EnvironmentFlags {
             mode,
-            // We disable readahead because it improves performance for linear scans, but
-            // worsens it for random access (which is our access pattern outside of sync)
-            no_rdahead: true,
+            // We disable readahead by default because it improves performance for linear scans,
+            // but worsens it for random access (which is our access pattern outside of sync)
+            no_rdahead: !args.read_ahead.unwrap_or_default(),
             coalesce: true,
             exclusive: args.exclusive.unwrap_or_default(),
             ..Default::default()
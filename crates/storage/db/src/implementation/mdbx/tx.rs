@@ -142,6 +142,7 @@ impl<K: TransactionKind> Tx<K> {
 
             let (result, commit_latency, close_duration) = run(self);
             let open_duration = metrics_handler.start.elapsed();
+            metrics_handler.record_long_read_transaction(open_duration);
             metrics_handler.env_metrics.record_closed_transaction(
                 metrics_handler.transaction_mode(),
                 outcome,
@@ -255,16 +256,27 @@ impl<K: TransactionKind> MetricsHandler<K> {
             }
         }
     }
+
+    /// Records the long read transaction metric if the read transaction was open for longer than
+    /// [LONG_TRANSACTION_DURATION].
+    fn record_long_read_transaction(&self, open_duration: Duration) {
+        if self.transaction_mode().is_read_only() && open_duration >= self.long_transaction_duration
+        {
+            self.env_metrics.record_long_read_transaction();
+        }
+    }
 }
 
 impl<K: TransactionKind> Drop for MetricsHandler<K> {
     fn drop(&mut self) {
         if !self.close_recorded {
             self.log_backtrace_on_long_read_transaction();
+            let open_duration = self.start.elapsed();
+            self.record_long_read_transaction(open_duration);
             self.env_metrics.record_closed_transaction(
                 self.transaction_mode(),
                 TransactionOutcome::Drop,
-                self.start.elapsed(),
+                open_duration,
                 None,
                 None,
             );
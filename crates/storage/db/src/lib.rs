@@ -67,6 +67,7 @@
 pub mod abstraction;
 
 mod implementation;
+pub mod lockfile;
 mod metrics;
 pub mod static_file;
 pub mod tables;
@@ -81,6 +82,7 @@ pub mod mdbx {
 }
 
 pub use abstraction::*;
+pub use lockfile::{StorageLock, StorageLockError};
 pub use reth_interfaces::db::{DatabaseError, DatabaseWriteOperation};
 pub use tables::*;
 pub use utils::is_database_empty;
@@ -0,0 +1,122 @@
+//! Storage lock, guarding a data directory against concurrent use by multiple processes.
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{Read, Seek, Write},
+    path::{Path, PathBuf},
+};
+
+/// Name of the lock file inside the locked directory.
+const LOCK_FILE_NAME: &str = "reth.lock";
+
+/// Errors of [StorageLock].
+#[derive(Debug, thiserror::Error)]
+pub enum StorageLockError {
+    /// The directory is locked by another process, most likely a running node.
+    #[error("{} is in use by another process (pid {}), stop the node first", .path.display(), .pid.as_deref().unwrap_or("unknown"))]
+    Taken {
+        /// The locked directory.
+        path: PathBuf,
+        /// The process id of the lock holder, as it wrote it into the lock file.
+        pid: Option<String>,
+    },
+    /// The lock file couldn't be opened or locked.
+    #[error("failed to lock {}: {source}", .path.display())]
+    Io {
+        /// The lock file.
+        path: PathBuf,
+        /// The underlying error.
+        source: std::io::Error,
+    },
+}
+
+/// An exclusive advisory lock on a data directory.
+///
+/// The node holds the lock while it's running, and offline tools that modify the data directory
+/// acquire it to refuse to run next to a node. The lock is released when it's dropped or the
+/// process exits, so a crashed node never leaves a stale lock behind.
+///
+/// Locking is only supported on unix, other platforms always acquire the lock.
+#[derive(Debug)]
+pub struct StorageLock {
+    /// The locked lock file, which is unlocked when it's closed.
+    _file: File,
+}
+
+impl StorageLock {
+    /// Tries to acquire the lock on the directory at `path`, without blocking.
+    ///
+    /// Returns [StorageLockError::Taken] if another process holds the lock.
+    pub fn try_acquire(path: &Path) -> Result<Self, StorageLockError> {
+        let lock_path = path.join(LOCK_FILE_NAME);
+        let io_err = |source| StorageLockError::Io { path: lock_path.clone(), source };
+
+        std::fs::create_dir_all(path).map_err(io_err)?;
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&lock_path)
+            .map_err(io_err)?;
+
+        if !try_lock_exclusive(&file).map_err(io_err)? {
+            let mut pid = String::new();
+            let pid = file.read_to_string(&mut pid).ok().map(|_| pid.trim().to_string());
+            return Err(StorageLockError::Taken {
+                path: path.to_path_buf(),
+                pid: pid.filter(|pid| !pid.is_empty()),
+            })
+        }
+
+        // The process id is only informational, for the error of other processes.
+        file.set_len(0).map_err(io_err)?;
+        file.rewind().map_err(io_err)?;
+        write!(file, "{}", std::process::id()).map_err(io_err)?;
+
+        Ok(Self { _file: file })
+    }
+}
+
+/// Tries to place an exclusive lock on `file`, returning `false` if it's locked by someone else.
+#[cfg(unix)]
+fn try_lock_exclusive(file: &File) -> std::io::Result<bool> {
+    use std::os::unix::io::AsRawFd;
+
+    // SAFETY: the file descriptor is valid for the lifetime of `file`.
+    if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) } == 0 {
+        return Ok(true)
+    }
+
+    let err = std::io::Error::last_os_error();
+    if err.raw_os_error() == Some(libc::EWOULDBLOCK) {
+        Ok(false)
+    } else {
+        Err(err)
+    }
+}
+
+/// Tries to place an exclusive lock on `file`, returning `false` if it's locked by someone else.
+#[cfg(not(unix))]
+fn try_lock_exclusive(_file: &File) -> std::io::Result<bool> {
+    Ok(true)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lock_is_exclusive_until_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let lock = StorageLock::try_acquire(dir.path()).unwrap();
+        let err = StorageLock::try_acquire(dir.path()).unwrap_err();
+        assert!(
+            matches!(err, StorageLockError::Taken { pid: Some(pid), .. } if pid == std::process::id().to_string())
+        );
+
+        drop(lock);
+        StorageLock::try_acquire(dir.path()).unwrap();
+    }
+}
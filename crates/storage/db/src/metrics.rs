@@ -123,6 +123,15 @@ impl DatabaseEnvMetrics {
             .record_open();
     }
 
+    /// Record a read-only transaction that was open for longer than the long transaction
+    /// threshold.
+    pub(crate) fn record_long_read_transaction(&self) {
+        self.transactions
+            .get(&TransactionMode::ReadOnly)
+            .expect("transaction mode metric handle not found")
+            .record_long();
+    }
+
     /// Record metrics for closing a database transactions.
     pub(crate) fn record_closed_transaction(
         &self,
@@ -263,6 +272,9 @@ impl Labels {
 pub(crate) struct TransactionMetrics {
     /// Total number of currently open database transactions
     open_total: Gauge,
+    /// Total number of database transactions that were open for longer than the long
+    /// transaction threshold
+    long_total: Counter,
 }
 
 impl TransactionMetrics {
@@ -273,6 +285,10 @@ impl TransactionMetrics {
     pub(crate) fn record_close(&self) {
         self.open_total.decrement(1.0);
     }
+
+    pub(crate) fn record_long(&self) {
+        self.long_total.increment(1);
+    }
 }
 
 #[derive(Metrics, Clone)]
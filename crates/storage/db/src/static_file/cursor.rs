@@ -19,6 +19,12 @@ impl<'a> StaticFileCursor<'a> {
         ))
     }
 
+    /// Enables read-ahead on the cursor, prefetching the data of the next `window` rows when it's
+    /// iterated sequentially. See [`NippyJarCursor::with_read_ahead`].
+    pub fn with_read_ahead(self, window: u64) -> Self {
+        Self(self.0.with_read_ahead(window))
+    }
+
     /// Returns the current `BlockNumber` or `TxNumber` of the cursor depending on the kind of
     /// static file segment.
     pub fn number(&self) -> Option<u64> {
@@ -149,6 +149,21 @@ impl Environment {
         f(self.env_ptr())
     }
 
+    /// Copies the environment to a new database file at `dest`, which must not exist yet.
+    ///
+    /// The copy is made from a single read transaction, so it's consistent even if the environment
+    /// is written to concurrently. If `compact` is set, free pages are omitted and the pages are
+    /// renumbered sequentially.
+    pub fn copy(&self, dest: impl AsRef<Path>, compact: bool) -> Result<()> {
+        let dest = CString::new(path_to_bytes(dest)).map_err(|_| Error::Invalid)?;
+        let mut flags = ffi::MDBX_CP_FORCE_DYNAMIC_SIZE;
+        if compact {
+            flags |= ffi::MDBX_CP_COMPACT;
+        }
+        mdbx_result(unsafe { ffi::mdbx_env_copy(self.env_ptr(), dest.as_ptr(), flags) })?;
+        Ok(())
+    }
+
     /// Flush the environment data buffers to disk.
     pub fn sync(&self, force: bool) -> Result<bool> {
         mdbx_result(unsafe { ffi::mdbx_env_sync_ex(self.env_ptr(), force, false) })
@@ -298,6 +313,20 @@ pub(crate) struct EnvPtr(pub(crate) *mut ffi::MDBX_env);
 unsafe impl Send for EnvPtr {}
 unsafe impl Sync for EnvPtr {}
 
+#[cfg(unix)]
+fn path_to_bytes<P: AsRef<Path>>(path: P) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+    path.as_ref().as_os_str().as_bytes().to_vec()
+}
+
+#[cfg(windows)]
+fn path_to_bytes<P: AsRef<Path>>(path: P) -> Vec<u8> {
+    // On Windows, could use std::os::windows::ffi::OsStrExt to encode_wide(),
+    // but we end up with a Vec<u16> instead of a Vec<u8>, so that doesn't
+    // really help.
+    path.as_ref().to_string_lossy().to_string().into_bytes()
+}
+
 /// Environment statistics.
 ///
 /// Contains information about the size and layout of an MDBX environment or database.
@@ -363,6 +392,26 @@ impl GeometryInfo {
     pub fn min(&self) -> u64 {
         self.0.lower
     }
+
+    /// Upper limit for the datafile size.
+    pub fn max(&self) -> u64 {
+        self.0.upper
+    }
+
+    /// Current datafile size.
+    pub fn current(&self) -> u64 {
+        self.0.current
+    }
+
+    /// Shrink threshold for the datafile.
+    pub fn shrink_threshold(&self) -> u64 {
+        self.0.shrink
+    }
+
+    /// Growth step for the datafile.
+    pub fn growth_step(&self) -> u64 {
+        self.0.grow
+    }
 }
 
 /// Environment information.
@@ -679,20 +728,6 @@ impl EnvironmentBuilder {
                     ))?;
                 }
 
-                #[cfg(unix)]
-                fn path_to_bytes<P: AsRef<Path>>(path: P) -> Vec<u8> {
-                    use std::os::unix::ffi::OsStrExt;
-                    path.as_ref().as_os_str().as_bytes().to_vec()
-                }
-
-                #[cfg(windows)]
-                fn path_to_bytes<P: AsRef<Path>>(path: P) -> Vec<u8> {
-                    // On Windows, could use std::os::windows::ffi::OsStrExt to encode_wide(),
-                    // but we end up with a Vec<u16> instead of a Vec<u8>, so that doesn't
-                    // really help.
-                    path.as_ref().to_string_lossy().to_string().into_bytes()
-                }
-
                 let path = match CString::new(path_to_bytes(path)) {
                     Ok(path) => path,
                     Err(_) => return Err(Error::Invalid),
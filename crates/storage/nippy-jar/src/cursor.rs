@@ -7,6 +7,10 @@ use std::{ops::Range, sync::Arc};
 use sucds::int_vectors::Access;
 use zstd::bulk::Decompressor;
 
+/// Number of consecutive sequential reads after which a cursor with read-ahead enabled starts
+/// prefetching.
+const SEQUENTIAL_READS_BEFORE_READ_AHEAD: u64 = 4;
+
 /// Simple cursor implementation to retrieve data from [`NippyJar`].
 #[derive(Clone)]
 pub struct NippyJarCursor<'a, H = ()> {
@@ -18,6 +22,21 @@ pub struct NippyJarCursor<'a, H = ()> {
     internal_buffer: Vec<u8>,
     /// Cursor row position.
     row: u64,
+    /// Read-ahead state, if enabled.
+    read_ahead: Option<ReadAhead>,
+}
+
+/// Read-ahead state of a [`NippyJarCursor`].
+#[derive(Debug, Clone)]
+struct ReadAhead {
+    /// Number of rows to prefetch at once.
+    window: u64,
+    /// Row that is read next if the cursor is iterated sequentially.
+    next_row: u64,
+    /// Number of consecutive sequential reads.
+    sequential_reads: u64,
+    /// Rows below this one have already been prefetched.
+    prefetched_until: u64,
 }
 
 impl<'a, H: NippyJarHeader> std::fmt::Debug for NippyJarCursor<'a, H> {
@@ -35,6 +54,7 @@ impl<'a, H: NippyJarHeader> NippyJarCursor<'a, H> {
             // Makes sure that we have enough buffer capacity to decompress any row of data.
             internal_buffer: Vec::with_capacity(max_row_size),
             row: 0,
+            read_ahead: None,
         })
     }
 
@@ -49,9 +69,25 @@ impl<'a, H: NippyJarHeader> NippyJarCursor<'a, H> {
             // Makes sure that we have enough buffer capacity to decompress any row of data.
             internal_buffer: Vec::with_capacity(max_row_size),
             row: 0,
+            read_ahead: None,
         })
     }
 
+    /// Enables read-ahead on the cursor.
+    ///
+    /// Once the cursor detects that rows are being read in increasing order, it hints the OS to
+    /// page in the data of the next `window` rows, so they're already in memory by the time they
+    /// get decompressed. Random reads are unaffected.
+    pub fn with_read_ahead(mut self, window: u64) -> Self {
+        self.read_ahead = (window > 0).then_some(ReadAhead {
+            window,
+            next_row: 0,
+            sequential_reads: 0,
+            prefetched_until: 0,
+        });
+        self
+    }
+
     /// Returns a reference to the related [`NippyJar`]
     pub fn jar(&self) -> &NippyJar<H> {
         self.jar
@@ -111,6 +147,8 @@ impl<'a, H: NippyJarHeader> NippyJarCursor<'a, H> {
             return Ok(None)
         }
 
+        self.read_ahead();
+
         let mut row = Vec::with_capacity(self.jar.columns);
 
         // Retrieve all column values from the row
@@ -185,6 +223,8 @@ impl<'a, H: NippyJarHeader> NippyJarCursor<'a, H> {
             return Ok(None)
         }
 
+        self.read_ahead();
+
         let columns = self.jar.columns;
         let mut row = Vec::with_capacity(columns);
 
@@ -205,6 +245,44 @@ impl<'a, H: NippyJarHeader> NippyJarCursor<'a, H> {
         ))
     }
 
+    /// Prefetches the data of the next rows if read-ahead is enabled and the cursor is being read
+    /// sequentially.
+    fn read_ahead(&mut self) {
+        let Some(read_ahead) = &mut self.read_ahead else { return };
+
+        if self.row == read_ahead.next_row {
+            read_ahead.sequential_reads += 1;
+        } else {
+            read_ahead.sequential_reads = 0;
+            read_ahead.prefetched_until = 0;
+        }
+        read_ahead.next_row = self.row + 1;
+
+        // Only prefetch the next window once half of the current one has been read.
+        if read_ahead.sequential_reads < SEQUENTIAL_READS_BEFORE_READ_AHEAD ||
+            self.row + read_ahead.window / 2 < read_ahead.prefetched_until
+        {
+            return
+        }
+
+        let rows = self.jar.rows as u64;
+        let start = self.row.max(read_ahead.prefetched_until);
+        let end = (self.row + read_ahead.window).min(rows);
+        if start >= end {
+            return
+        }
+
+        let columns = self.jar.columns as u64;
+        let data_start = self.reader.offset((start * columns) as usize) as usize;
+        let data_end = if end == rows {
+            self.reader.size()
+        } else {
+            self.reader.offset((end * columns) as usize) as usize
+        };
+        self.reader.prefetch(data_start..data_end);
+        read_ahead.prefetched_until = end;
+    }
+
     /// Takes the column index and reads the range value for the corresponding column.
     fn read_value(
         &mut self,
@@ -14,11 +14,13 @@ use serde::{Deserialize, Serialize};
 use std::{
     error::Error as StdError,
     fs::File,
+    io::{Read, Seek, SeekFrom},
     ops::Range,
     path::{Path, PathBuf},
 };
 use sucds::{int_vectors::PrefixSummedEliasFano, Serializable};
 use tracing::*;
+use xxhash_rust::xxh3::Xxh3;
 
 pub mod filter;
 use filter::{Cuckoo, InclusionFilter, InclusionFilters};
@@ -207,6 +209,12 @@ impl<H: NippyJarHeader> NippyJar<H> {
         &self.user_header
     }
 
+    /// Gets a mutable reference to the user header. Changes are persisted by
+    /// [`Self::freeze_config`].
+    pub fn user_header_mut(&mut self) -> &mut H {
+        &mut self.user_header
+    }
+
     /// Gets total columns in jar.
     pub fn columns(&self) -> usize {
         self.columns
@@ -297,6 +305,14 @@ impl<H: NippyJarHeader> NippyJar<H> {
         Ok(())
     }
 
+    /// Computes the XXH3 checksum of the data file.
+    ///
+    /// It's the same checksum that [`NippyJarWriter::data_checksum`] returns for the data written
+    /// through it.
+    pub fn data_checksum(&self) -> Result<u64, NippyJarError> {
+        Ok(hash_data_file(self.data_path())?.digest())
+    }
+
     /// Returns a [`DataReader`] of the data and offset file
     pub fn open_data_reader(&self) -> Result<DataReader, NippyJarError> {
         DataReader::new(self.data_path())
@@ -427,11 +443,34 @@ impl<H: NippyJarHeader> NippyJar<H> {
     }
 
     /// Writes all necessary configuration to file.
-    fn freeze_config(&mut self) -> Result<(), NippyJarError> {
+    pub fn freeze_config(&mut self) -> Result<(), NippyJarError> {
         Ok(bincode::serialize_into(File::create(self.config_path())?, &self)?)
     }
 }
 
+/// Feeds the contents of the data file at `path` into a new [`Xxh3`] hasher.
+fn hash_data_file(path: &Path) -> Result<Xxh3, NippyJarError> {
+    let mut hasher = Xxh3::new();
+    hash_data_range(&mut hasher, path, 0, u64::MAX)?;
+    Ok(hasher)
+}
+
+/// Feeds up to `len` bytes of the data file at `path`, starting at `offset`, into `hasher`.
+fn hash_data_range(hasher: &mut Xxh3, path: &Path, offset: u64, len: u64) -> std::io::Result<()> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(offset))?;
+    let mut file = file.take(len);
+    let mut buf = vec![0; 1 << 20];
+    loop {
+        let read = file.read(&mut buf)?;
+        if read == 0 {
+            break
+        }
+        hasher.update(&buf[..read]);
+    }
+    Ok(())
+}
+
 impl<H: NippyJarHeader> InclusionFilter for NippyJar<H> {
     fn add(&mut self, element: &[u8]) -> Result<(), NippyJarError> {
         self.filter.as_mut().ok_or(NippyJarError::FilterMissing)?.add(element)
@@ -545,6 +584,20 @@ impl DataReader {
     pub fn size(&self) -> usize {
         self.data_mmap.len()
     }
+
+    /// Hints the OS that the provided data range is going to be read soon, so it can be paged in
+    /// ahead of time.
+    ///
+    /// This is only a hint: failures are ignored, and it's a no-op on non-unix platforms.
+    pub fn prefetch(&self, range: Range<usize>) {
+        #[cfg(unix)]
+        if !range.is_empty() && range.end <= self.data_mmap.len() {
+            let _ =
+                self.data_mmap.advise_range(memmap2::Advice::WillNeed, range.start, range.len());
+        }
+        #[cfg(not(unix))]
+        let _ = range;
+    }
 }
 
 #[cfg(test)]
@@ -913,6 +966,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_read_ahead() {
+        let (col1, col2) = test_data(None);
+        let num_rows = col1.len() as u64;
+        let file_path = tempfile::NamedTempFile::new().unwrap();
+
+        let nippy = NippyJar::new_without_header(2, file_path.path()).with_lz4();
+        nippy.freeze(vec![clone_with_result(&col1), clone_with_result(&col2)], num_rows).unwrap();
+
+        let loaded_nippy = NippyJar::load_without_header(file_path.path()).unwrap();
+        let mut cursor = NippyJarCursor::new(&loaded_nippy).unwrap().with_read_ahead(8);
+
+        // Sequential reads, which trigger prefetching
+        for row_num in 0..col1.len() {
+            let row = cursor.row_by_number(row_num).unwrap().unwrap();
+            assert_eq!((row[0], row[1]), (col1[row_num].as_slice(), col2[row_num].as_slice()));
+        }
+        assert!(cursor.next_row().unwrap().is_none());
+
+        // Random reads, which reset the read-ahead state
+        let mut rows = (0..col1.len()).collect::<Vec<_>>();
+        rows.shuffle(&mut rand::thread_rng());
+        for row_num in rows {
+            let row = cursor.row_by_number_with_cols(row_num, 0b10).unwrap().unwrap();
+            assert_eq!(row[0], col2[row_num].as_slice());
+        }
+    }
+
     #[test]
     fn test_selectable_column_values() {
         let (col1, col2) = test_data(None);
@@ -1064,6 +1145,78 @@ mod tests {
         test_append_consistency_partial_commit(file_path.path(), &col1, &col2);
     }
 
+    #[test]
+    fn test_writer_data_checksum() {
+        let (col1, col2) = test_data(None);
+        let file_path = tempfile::NamedTempFile::new().unwrap();
+        let checksum_of = |data: &[&Vec<u8>]| {
+            let mut hasher = Xxh3::new();
+            data.iter().for_each(|value| hasher.update(value));
+            hasher.digest()
+        };
+
+        let mut nippy = NippyJar::new_without_header(2, file_path.path());
+        nippy.freeze_config().unwrap();
+        let mut writer = NippyJarWriter::new(nippy).unwrap();
+        writer.append_column(Some(Ok(&col1[0]))).unwrap();
+        writer.append_column(Some(Ok(&col2[0]))).unwrap();
+        writer.commit().unwrap();
+        assert_eq!(writer.data_checksum().unwrap(), checksum_of(&[&col1[0], &col2[0]]));
+
+        // Reopened writers hash the existing data from disk and keep appending to it
+        let nippy = NippyJar::load_without_header(file_path.path()).unwrap();
+        let mut writer = NippyJarWriter::new(nippy).unwrap();
+        writer.append_column(Some(Ok(&col1[1]))).unwrap();
+        writer.append_column(Some(Ok(&col2[1]))).unwrap();
+        writer.commit().unwrap();
+        let checksum = checksum_of(&[&col1[0], &col2[0], &col1[1], &col2[1]]);
+        assert_eq!(writer.data_checksum().unwrap(), checksum);
+        assert_eq!(
+            NippyJar::load_without_header(file_path.path()).unwrap().data_checksum().unwrap(),
+            checksum
+        );
+
+        // Pruned data is no longer part of the checksum
+        writer.prune_rows(1).unwrap();
+        writer.append_column(Some(Ok(&col1[2]))).unwrap();
+        writer.append_column(Some(Ok(&col2[2]))).unwrap();
+        writer.commit().unwrap();
+        let checksum = checksum_of(&[&col1[0], &col2[0], &col1[2], &col2[2]]);
+        assert_eq!(writer.data_checksum().unwrap(), checksum);
+        assert_eq!(
+            NippyJar::load_without_header(file_path.path()).unwrap().data_checksum().unwrap(),
+            checksum
+        );
+    }
+
+    #[test]
+    fn test_writer_syncs() {
+        let (col1, col2) = test_data(None);
+        let file_path = tempfile::NamedTempFile::new().unwrap();
+
+        let mut nippy = NippyJar::new_without_header(2, file_path.path());
+        nippy.freeze_config().unwrap();
+        let mut writer = NippyJarWriter::new(nippy).unwrap();
+        assert_eq!(writer.take_syncs(), 0);
+
+        // Commits sync the data and offsets files
+        writer.append_column(Some(Ok(&col1[0]))).unwrap();
+        writer.append_column(Some(Ok(&col2[0]))).unwrap();
+        writer.commit().unwrap();
+        assert_eq!(writer.take_syncs(), 2);
+        assert_eq!(writer.take_syncs(), 0);
+
+        // Pruning syncs both files as well
+        writer.prune_rows(1).unwrap();
+        writer.commit().unwrap();
+        assert_eq!(writer.take_syncs(), 4);
+
+        // Reopening heals and commits the existing files
+        let nippy = NippyJar::load_without_header(file_path.path()).unwrap();
+        let mut writer = NippyJarWriter::new(nippy).unwrap();
+        assert_eq!(writer.take_syncs(), 2);
+    }
+
     #[test]
     fn test_pruner() {
         let (col1, col2) = test_data(None);
@@ -1,10 +1,15 @@
-use crate::{compression::Compression, ColumnResult, NippyJar, NippyJarError, NippyJarHeader};
+use crate::{
+    compression::Compression, hash_data_range, ColumnResult, NippyJar, NippyJarError,
+    NippyJarHeader,
+};
 use std::{
     cmp::Ordering,
     fs::{File, OpenOptions},
     io::{BufWriter, Read, Seek, SeekFrom, Write},
     path::Path,
+    thread::JoinHandle,
 };
+use xxhash_rust::xxh3::Xxh3;
 
 /// Size of one offset in bytes.
 const OFFSET_SIZE_BYTES: u64 = 8;
@@ -39,6 +44,37 @@ pub struct NippyJarWriter<H: NippyJarHeader = ()> {
     offsets: Vec<u64>,
     /// Column where writer is going to write next.
     column: usize,
+    /// XXH3 hasher of the data file.
+    data_hasher: DataHasher,
+    /// Number of files synced to disk since [`Self::take_syncs`] was last called.
+    syncs: u64,
+}
+
+/// [`Xxh3`] hasher of a data file.
+enum DataHasher {
+    /// Fed with every column as it's appended.
+    Live(Xxh3),
+    /// Hashes the first `len` bytes of the data file on a background thread, since the data file
+    /// had content that wasn't written by this writer when it was opened or truncated.
+    ///
+    /// The columns appended in the meantime are read back from disk once it's done.
+    Pending {
+        /// Thread hashing the data file.
+        handle: JoinHandle<std::io::Result<Xxh3>>,
+        /// Length of the data file when the thread was started.
+        len: u64,
+    },
+}
+
+impl std::fmt::Debug for DataHasher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Live(hasher) => f.debug_tuple("Live").field(&hasher.digest()).finish(),
+            Self::Pending { len, .. } => {
+                f.debug_struct("Pending").field("len", len).finish_non_exhaustive()
+            }
+        }
+    }
 }
 
 impl<H: NippyJarHeader> NippyJarWriter<H> {
@@ -58,6 +94,8 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
             uncompressed_row_size: 0,
             offsets: Vec::with_capacity(1_000_000),
             column: 0,
+            data_hasher: DataHasher::Live(Xxh3::new()),
+            syncs: 0,
         };
 
         // If we are opening a previously created jar, we need to check its consistency, and make
@@ -66,6 +104,7 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
             writer.check_consistency_and_heal()?;
             writer.commit()?;
         }
+        writer.reset_data_hasher()?;
 
         Ok(writer)
     }
@@ -85,11 +124,89 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
         self.jar.rows()
     }
 
+    /// Returns the number of files synced to disk since the last call, and resets it.
+    ///
+    /// Every [`Self::commit`] syncs the data and offsets files, and pruning rows syncs them as
+    /// well.
+    pub fn take_syncs(&mut self) -> u64 {
+        std::mem::take(&mut self.syncs)
+    }
+
     /// Consumes the writer and returns the associated [`NippyJar`].
     pub fn into_jar(self) -> NippyJar<H> {
         self.jar
     }
 
+    /// Returns the XXH3 checksum of the data file, as computed by [`NippyJar::data_checksum`].
+    ///
+    /// The checksum is kept up to date while appending. If the data file was opened non-empty or
+    /// truncated, its existing content is hashed on a background thread, and this only blocks
+    /// until that's done.
+    pub fn data_checksum(&mut self) -> Result<u64, NippyJarError> {
+        self.data_file.flush()?;
+        self.catch_up_data_hasher(true)?;
+
+        match &self.data_hasher {
+            DataHasher::Live(hasher) => Ok(hasher.digest()),
+            DataHasher::Pending { .. } => unreachable!("data hasher caught up"),
+        }
+    }
+
+    /// Starts a new data file hasher if the data file is empty, and otherwise starts hashing its
+    /// content on a background thread.
+    fn reset_data_hasher(&mut self) -> Result<(), NippyJarError> {
+        let len = self.data_file.get_ref().metadata()?.len();
+        self.data_hasher = if len == 0 {
+            DataHasher::Live(Xxh3::new())
+        } else {
+            let path = self.jar.data_path().to_path_buf();
+            let handle = std::thread::spawn(move || {
+                let mut hasher = Xxh3::new();
+                hash_data_range(&mut hasher, &path, 0, len)?;
+                Ok(hasher)
+            });
+            DataHasher::Pending { handle, len }
+        };
+        Ok(())
+    }
+
+    /// Switches a pending data file hasher to hashing columns as they're appended, once its
+    /// background thread is done. The columns appended in the meantime are read back from disk, so
+    /// the data file needs to be flushed.
+    ///
+    /// Waits for the background thread if `wait` is set, and otherwise leaves the hasher pending
+    /// if it's not done.
+    fn catch_up_data_hasher(&mut self, wait: bool) -> Result<(), NippyJarError> {
+        match &self.data_hasher {
+            DataHasher::Pending { handle, .. } if wait || handle.is_finished() => {}
+            _ => return Ok(()),
+        }
+        let DataHasher::Pending { handle, len } =
+            std::mem::replace(&mut self.data_hasher, DataHasher::Live(Xxh3::new()))
+        else {
+            unreachable!("checked above")
+        };
+
+        let end = self.data_file.get_ref().metadata()?.len();
+        let result = handle.join().unwrap_or_else(|err| std::panic::resume_unwind(err)).and_then(
+            |mut hasher| {
+                hash_data_range(&mut hasher, self.jar.data_path(), len, end.saturating_sub(len))?;
+                Ok(hasher)
+            },
+        );
+        match result {
+            Ok(hasher) => {
+                self.data_hasher = DataHasher::Live(hasher);
+                Ok(())
+            }
+            Err(err) => {
+                // Starts over, so that a later call can still compute the checksum
+                self.reset_data_hasher()?;
+                Err(err.into())
+            }
+        }
+    }
+
     fn create_or_open_files(
         data: &Path,
         offsets: &Path,
@@ -272,10 +389,17 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
         let len = if let Some(compression) = &self.jar.compressor {
             let before = self.tmp_buf.len();
             let len = compression.compress_to(value, &mut self.tmp_buf)?;
-            self.data_file.write_all(&self.tmp_buf[before..before + len])?;
+            let compressed = &self.tmp_buf[before..before + len];
+            self.data_file.write_all(compressed)?;
+            if let DataHasher::Live(hasher) = &mut self.data_hasher {
+                hasher.update(compressed);
+            }
             len
         } else {
             self.data_file.write_all(value)?;
+            if let DataHasher::Live(hasher) = &mut self.data_hasher {
+                hasher.update(value);
+            }
             value.len()
         };
 
@@ -356,9 +480,11 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
 
         self.offsets_file.get_ref().sync_all()?;
         self.data_file.get_ref().sync_all()?;
+        self.syncs += 2;
 
         self.offsets_file.seek(SeekFrom::End(0))?;
         self.data_file.seek(SeekFrom::End(0))?;
+        self.reset_data_hasher()?;
 
         self.jar.rows = self.jar.rows.saturating_sub(num_rows);
         if self.jar.rows == 0 {
@@ -384,6 +510,8 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
     pub fn commit(&mut self) -> Result<(), NippyJarError> {
         self.data_file.flush()?;
         self.data_file.get_ref().sync_all()?;
+        self.syncs += 1;
+        self.catch_up_data_hasher(false)?;
 
         self.commit_offsets()?;
 
@@ -396,6 +524,7 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
     #[cfg(feature = "test-utils")]
     pub fn commit_without_sync_all(&mut self) -> Result<(), NippyJarError> {
         self.data_file.flush()?;
+        self.catch_up_data_hasher(false)?;
 
         self.commit_offsets_without_sync_all()?;
 
@@ -409,6 +538,7 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
     pub(crate) fn commit_offsets(&mut self) -> Result<(), NippyJarError> {
         self.commit_offsets_inner()?;
         self.offsets_file.get_ref().sync_all()?;
+        self.syncs += 1;
 
         Ok(())
     }
@@ -0,0 +1,151 @@
+//! Aggregation of the priority fees paid to block beneficiaries.
+
+use crate::{
+    providers::{ReadSnapshotManager, DEFAULT_MAX_READ_SNAPSHOT_AGE},
+    ReadSnapshotFactory,
+};
+use reth_interfaces::provider::{ProviderError, ProviderResult};
+use reth_primitives::{Address, BlockNumber, U256};
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+/// The number of blocks read with a single read snapshot by [beneficiary_fees].
+pub const BENEFICIARY_FEES_BLOCKS_PER_SNAPSHOT: u64 = 100;
+
+/// The number of blocks, gas used and priority fees of a block beneficiary.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BeneficiaryFees {
+    /// The block beneficiary.
+    pub beneficiary: Address,
+    /// The number of blocks of the beneficiary.
+    pub blocks: u64,
+    /// The total gas used by the blocks of the beneficiary.
+    pub gas_used: u64,
+    /// The total priority fees paid to the beneficiary, in wei.
+    pub fees: U256,
+}
+
+/// Aggregates the number of blocks, gas used and priority fees per block beneficiary in the given
+/// block range, ordered by beneficiary.
+///
+/// The priority fee of every transaction is derived from its effective tip and the gas it used
+/// according to its receipt, so this fails if the receipts in the range were pruned. The range is
+/// read in chunks with a [ReadSnapshotManager], so large ranges don't keep a single read
+/// transaction open.
+pub fn beneficiary_fees<F: ReadSnapshotFactory>(
+    factory: &F,
+    range: RangeInclusive<BlockNumber>,
+) -> ProviderResult<Vec<BeneficiaryFees>> {
+    let mut fees_by_beneficiary = BTreeMap::<Address, BeneficiaryFees>::new();
+    let mut snapshots = ReadSnapshotManager::new(factory, DEFAULT_MAX_READ_SNAPSHOT_AGE);
+    snapshots.for_each_chunk(range, BENEFICIARY_FEES_BLOCKS_PER_SNAPSHOT, |provider, chunk| {
+        let expected = chunk.clone().count();
+        let blocks = provider.block_range(chunk.clone())?;
+        if blocks.len() != expected {
+            let missing = *chunk.start() + blocks.len() as u64;
+            return Err(ProviderError::BlockNotFound(missing.into()))
+        }
+
+        for block in blocks {
+            let receipts = match provider.receipts_by_block(block.number.into())? {
+                Some(receipts) => receipts,
+                None if block.body.is_empty() => Vec::new(),
+                None => return Err(ProviderError::ReceiptNotFound(block.body[0].hash().into())),
+            };
+            if receipts.len() != block.body.len() {
+                return Err(ProviderError::BlockReceiptsMismatch {
+                    block: block.number,
+                    transactions: block.body.len(),
+                    receipts: receipts.len(),
+                })
+            }
+
+            let mut fees = U256::ZERO;
+            let mut cumulative_gas_used = 0;
+            for (transaction, receipt) in block.body.iter().zip(&receipts) {
+                let gas_used = receipt.cumulative_gas_used - cumulative_gas_used;
+                cumulative_gas_used = receipt.cumulative_gas_used;
+
+                let tip =
+                    transaction.effective_tip_per_gas(block.base_fee_per_gas).unwrap_or_default();
+                fees += U256::from(tip) * U256::from(gas_used);
+            }
+
+            let entry = fees_by_beneficiary.entry(block.beneficiary).or_insert_with(|| {
+                BeneficiaryFees { beneficiary: block.beneficiary, ..Default::default() }
+            });
+            entry.blocks += 1;
+            entry.gas_used += block.gas_used;
+            entry.fees += fees;
+        }
+        Ok(())
+    })?;
+    Ok(fees_by_beneficiary.into_values().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::create_test_provider_factory, BlockWriter};
+    use reth_db::{tables, transaction::DbTxMut};
+    use reth_interfaces::test_utils::generators::{self, random_block_range};
+    use reth_primitives::{Receipt, B256};
+
+    const GAS_PER_TX: u64 = 21_000;
+
+    #[test]
+    fn aggregates_fees_per_beneficiary() {
+        let factory = create_test_provider_factory();
+        let mut rng = generators::rng();
+        let blocks = random_block_range(&mut rng, 0..=250, B256::ZERO, 1..3);
+
+        let provider_rw = factory.provider_rw().unwrap();
+        let mut tx_num = 0;
+        let mut expected = BTreeMap::<Address, BeneficiaryFees>::new();
+        for block in &blocks {
+            provider_rw.insert_block(block.clone().try_seal_with_senders().unwrap(), None).unwrap();
+
+            let entry = expected.entry(block.beneficiary).or_insert_with(|| BeneficiaryFees {
+                beneficiary: block.beneficiary,
+                ..Default::default()
+            });
+            entry.blocks += 1;
+            entry.gas_used += block.gas_used;
+            for (index, transaction) in block.body.iter().enumerate() {
+                let receipt = Receipt {
+                    cumulative_gas_used: (index as u64 + 1) * GAS_PER_TX,
+                    ..Default::default()
+                };
+                provider_rw.tx_ref().put::<tables::Receipts>(tx_num, receipt).unwrap();
+                tx_num += 1;
+
+                let tip =
+                    transaction.effective_tip_per_gas(block.base_fee_per_gas).unwrap_or_default();
+                entry.fees += U256::from(tip) * U256::from(GAS_PER_TX);
+            }
+        }
+        provider_rw.commit().unwrap();
+
+        assert_eq!(
+            beneficiary_fees(&factory, 0..=250).unwrap(),
+            expected.into_values().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            beneficiary_fees(&factory, 0..=251),
+            Err(ProviderError::BlockNotFound(251.into()))
+        );
+
+        // a block whose receipts don't cover all of its transactions
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.tx_ref().delete::<tables::Receipts>(tx_num - 1, None).unwrap();
+        provider_rw.commit().unwrap();
+        let last = &blocks[250];
+        assert_eq!(
+            beneficiary_fees(&factory, 250..=250),
+            Err(ProviderError::BlockReceiptsMismatch {
+                block: 250,
+                transactions: last.body.len(),
+                receipts: last.body.len() - 1,
+            })
+        );
+    }
+}
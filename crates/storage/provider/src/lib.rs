@@ -36,6 +36,9 @@ pub use chain::{Chain, DisplayBlocksChain};
 pub mod bundle_state;
 pub use bundle_state::{BundleStateWithReceipts, OriginalValuesKnown, StateChanges, StateReverts};
 
+pub mod fees;
+pub use fees::{beneficiary_fees, BeneficiaryFees};
+
 pub(crate) fn to_range<R: std::ops::RangeBounds<u64>>(bounds: R) -> std::ops::Range<u64> {
     let start = match bounds.start_bound() {
         std::ops::Bound::Included(&v) => v,
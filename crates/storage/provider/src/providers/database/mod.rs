@@ -1,11 +1,15 @@
 use crate::{
-    providers::{state::latest::LatestStateProvider, StaticFileProvider},
+    providers::{
+        state::latest::LatestStateProvider, CanonicalHeaderCache, StaticFileProvider,
+        DEFAULT_CANONICAL_HEADER_CACHE_SIZE,
+    },
     to_range,
     traits::{BlockSource, ReceiptProvider},
     BlockHashReader, BlockNumReader, BlockReader, ChainSpecProvider, DatabaseProviderFactory,
     EvmEnvProvider, HeaderProvider, HeaderSyncGap, HeaderSyncGapProvider, HeaderSyncMode,
-    ProviderError, PruneCheckpointReader, StageCheckpointReader, StateProviderBox,
-    TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    ProviderError, PruneCheckpointReader, ReadSnapshotFactory, StageCheckpointReader,
+    StateProviderBox, StaticFileProviderFactory, TransactionVariant, TransactionsProvider,
+    WithdrawalsProvider,
 };
 use reth_db::{database::Database, init_db, models::StoredBlockBodyIndices, DatabaseEnv};
 use reth_evm::ConfigureEvmEnv;
@@ -22,13 +26,16 @@ use std::{
     ops::{RangeBounds, RangeInclusive},
     path::{Path, PathBuf},
     sync::Arc,
+    time::Duration,
 };
 use tracing::trace;
 
 mod metrics;
 mod provider;
+mod read_snapshot;
 
 pub use provider::{DatabaseProvider, DatabaseProviderRO, DatabaseProviderRW};
+pub use read_snapshot::{ReadSnapshotManager, DEFAULT_MAX_READ_SNAPSHOT_AGE};
 use reth_db::mdbx::DatabaseArguments;
 
 /// A common provider that fetches data from a database or static file.
@@ -42,6 +49,8 @@ pub struct ProviderFactory<DB> {
     chain_spec: Arc<ChainSpec>,
     /// Static File Provider
     static_file_provider: StaticFileProvider,
+    /// Cache of recent canonical header hashes and numbers, shared by all clones.
+    header_cache: CanonicalHeaderCache,
 }
 
 impl<DB> ProviderFactory<DB> {
@@ -55,6 +64,7 @@ impl<DB> ProviderFactory<DB> {
             db,
             chain_spec,
             static_file_provider: StaticFileProvider::new(static_files_path)?,
+            header_cache: CanonicalHeaderCache::new(DEFAULT_CANONICAL_HEADER_CACHE_SIZE),
         })
     }
 
@@ -64,6 +74,44 @@ impl<DB> ProviderFactory<DB> {
         self
     }
 
+    /// Limits the number of static files the static file provider keeps loaded.
+    pub fn with_static_files_max_open_jars(mut self, max_open_jars: usize) -> Self {
+        self.static_file_provider = self.static_file_provider.with_max_open_jars(max_open_jars);
+        self
+    }
+
+    /// Logs static file commits that take longer than `threshold` as slow.
+    pub fn with_static_files_slow_commit_threshold(mut self, threshold: Duration) -> Self {
+        self.static_file_provider = self.static_file_provider.with_slow_commit_threshold(threshold);
+        self
+    }
+
+    /// Sets the number of blocks stored in each static file.
+    ///
+    /// Returns an error if static files with a different number of blocks already exist.
+    pub fn with_static_files_blocks_per_file(
+        mut self,
+        blocks_per_file: u64,
+    ) -> ProviderResult<Self> {
+        self.static_file_provider =
+            self.static_file_provider.with_blocks_per_file(blocks_per_file)?;
+        Ok(self)
+    }
+
+    /// Sets the number of recent canonical headers whose hash and number are cached.
+    pub fn with_header_cache_size(mut self, size: usize) -> Self {
+        self.header_cache = CanonicalHeaderCache::new(size);
+        self
+    }
+
+    /// Returns the cache of recent canonical header hashes and numbers.
+    ///
+    /// The cache isn't used by the factory itself, it's shared with the components built on top
+    /// of it, which keep it up to date with the canonical head.
+    pub fn header_cache(&self) -> &CanonicalHeaderCache {
+        &self.header_cache
+    }
+
     /// Returns reference to the underlying database.
     pub fn db_ref(&self) -> &DB {
         &self.db
@@ -94,6 +142,7 @@ impl ProviderFactory<DatabaseEnv> {
             db: init_db(path, args).map_err(|e| RethError::Custom(e.to_string()))?,
             chain_spec,
             static_file_provider: StaticFileProvider::new(static_files_path)?,
+            header_cache: CanonicalHeaderCache::new(DEFAULT_CANONICAL_HEADER_CACHE_SIZE),
         })
     }
 }
@@ -111,6 +160,12 @@ impl<DB: Database> ProviderFactory<DB> {
         ))
     }
 
+    /// Returns a [ReadSnapshotManager] for long-running scans, which replaces its read
+    /// transaction once it's older than `max_age`.
+    pub fn read_snapshots(&self, max_age: Duration) -> ReadSnapshotManager<'_, Self> {
+        ReadSnapshotManager::new(self, max_age)
+    }
+
     /// Returns a provider with a created `DbTxMut` inside, which allows fetching and updating
     /// data from the database using different types of providers. Example: [`HeaderProvider`]
     /// [`BlockHashReader`].  This may fail if the inner read/write database transaction fails to
@@ -161,6 +216,20 @@ impl<DB: Database> DatabaseProviderFactory<DB> for ProviderFactory<DB> {
     }
 }
 
+impl<DB: Database> StaticFileProviderFactory for ProviderFactory<DB> {
+    fn static_file_provider(&self) -> StaticFileProvider {
+        self.static_file_provider.clone()
+    }
+}
+
+impl<DB: Database> ReadSnapshotFactory for ProviderFactory<DB> {
+    type Snapshot = DatabaseProviderRO<DB>;
+
+    fn read_snapshot(&self) -> ProviderResult<Self::Snapshot> {
+        self.provider()
+    }
+}
+
 impl<DB: Database> HeaderSyncGapProvider for ProviderFactory<DB> {
     fn sync_gap(
         &self,
@@ -553,16 +622,20 @@ impl<DB: Database> PruneCheckpointReader for ProviderFactory<DB> {
 mod tests {
     use super::ProviderFactory;
     use crate::{
-        providers::StaticFileWriter, test_utils::create_test_provider_factory, BlockHashReader,
-        BlockNumReader, BlockWriter, HeaderSyncGapProvider, HeaderSyncMode, TransactionsProvider,
+        providers::StaticFileWriter, test_utils::create_test_provider_factory,
+        AccountHistoryReader, BlockHashReader, BlockNumReader, BlockWriter, HeaderSyncGapProvider,
+        HeaderSyncMode, TransactionsProvider,
     };
     use alloy_rlp::Decodable;
     use assert_matches::assert_matches;
     use rand::Rng;
     use reth_db::{
         mdbx::DatabaseArguments,
+        models::{AccountBeforeTx, ShardedKey},
         tables,
         test_utils::{create_test_static_files_dir, ERROR_TEMPDIR},
+        transaction::{DbTx, DbTxMut},
+        BlockNumberList,
     };
     use reth_interfaces::{
         provider::ProviderError,
@@ -573,8 +646,8 @@ mod tests {
         RethError,
     };
     use reth_primitives::{
-        hex_literal::hex, ChainSpecBuilder, PruneMode, PruneModes, SealedBlock, StaticFileSegment,
-        TxNumber, B256, U256,
+        address, hex_literal::hex, Account, ChainSpecBuilder, PruneMode, PruneModes, SealedBlock,
+        StaticFileSegment, TxNumber, B256, U256,
     };
     use std::{ops::RangeInclusive, sync::Arc};
     use tokio::sync::watch;
@@ -736,4 +809,41 @@ mod tests {
         assert_eq!(gap.local_head, head);
         assert_eq!(gap.target.tip(), consensus_tip.into());
     }
+
+    #[test]
+    fn account_history_lookup() {
+        let factory = create_test_provider_factory();
+        let address = address!("0000000000000000000000000000000000000001");
+        let account = |nonce| Account { nonce, balance: U256::from(nonce), bytecode_hash: None };
+
+        let tx = factory.provider_rw().unwrap().into_tx();
+        tx.put::<tables::AccountsHistory>(
+            ShardedKey { key: address, highest_block_number: 3 },
+            BlockNumberList::new([1, 3]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::AccountsHistory>(
+            ShardedKey { key: address, highest_block_number: u64::MAX },
+            BlockNumberList::new([7]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::AccountChangeSets>(1, AccountBeforeTx { address, info: None }).unwrap();
+        tx.put::<tables::AccountChangeSets>(3, AccountBeforeTx { address, info: Some(account(1)) })
+            .unwrap();
+        tx.put::<tables::AccountChangeSets>(7, AccountBeforeTx { address, info: Some(account(3)) })
+            .unwrap();
+        tx.put::<tables::PlainAccountState>(address, account(7)).unwrap();
+        tx.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(
+            provider.account_history(address, 0..=10, usize::MAX).unwrap(),
+            vec![(1, Some(account(1))), (3, Some(account(3))), (7, Some(account(7)))]
+        );
+        assert_eq!(
+            provider.account_history(address, 2..=10, 1).unwrap(),
+            vec![(3, Some(account(3)))]
+        );
+        assert_eq!(provider.account_history(address, 4..=6, usize::MAX).unwrap(), vec![]);
+    }
 }
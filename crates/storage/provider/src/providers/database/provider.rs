@@ -3,7 +3,8 @@ use crate::{
     providers::{database::metrics, static_file::StaticFileWriter, StaticFileProvider},
     to_range,
     traits::{
-        AccountExtReader, BlockSource, ChangeSetReader, ReceiptProvider, StageCheckpointWriter,
+        AccountExtReader, AccountHistoryReader, BlockSource, ChangeSetReader, ReceiptProvider,
+        StageCheckpointWriter,
     },
     AccountReader, BlockExecutionWriter, BlockHashReader, BlockNumReader, BlockReader, BlockWriter,
     Chain, EvmEnvProvider, HashingWriter, HeaderProvider, HeaderSyncGap, HeaderSyncGapProvider,
@@ -1069,6 +1070,61 @@ impl<TX: DbTx> AccountExtReader for DatabaseProvider<TX> {
     }
 }
 
+impl<TX: DbTx> AccountHistoryReader for DatabaseProvider<TX> {
+    fn account_history(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Option<Account>)>> {
+        // Collect the blocks in which the account changed from the history index, alongside the
+        // first change after the last collected block.
+        let mut changed_blocks = Vec::new();
+        let mut next_change = None;
+        let mut history_cursor = self.tx.cursor_read::<tables::AccountsHistory>()?;
+        'shards: for entry in history_cursor.walk(Some(ShardedKey::new(address, *range.start())))? {
+            let (key, blocks) = entry?;
+            if key.key != address {
+                break
+            }
+
+            for block_number in blocks.iter() {
+                if block_number < *range.start() {
+                    continue
+                }
+                if block_number > *range.end() || changed_blocks.len() == limit {
+                    next_change = Some(block_number);
+                    break 'shards
+                }
+                changed_blocks.push(block_number);
+            }
+        }
+
+        // The state of the account after a block is its state before the next change, or the
+        // plain state if it didn't change since.
+        let mut changeset_cursor = self.tx.cursor_dup_read::<tables::AccountChangeSets>()?;
+        let mut history = Vec::with_capacity(changed_blocks.len());
+        for (idx, block_number) in changed_blocks.iter().enumerate() {
+            let account = match changed_blocks.get(idx + 1).copied().or(next_change) {
+                Some(next_block_number) => {
+                    changeset_cursor
+                        .seek_by_key_subkey(next_block_number, address)?
+                        .filter(|acc| acc.address == address)
+                        .ok_or(ProviderError::AccountChangesetNotFound {
+                            block_number: next_block_number,
+                            address,
+                        })?
+                        .info
+                }
+                None => self.tx.get::<tables::PlainAccountState>(address)?,
+            };
+            history.push((*block_number, account));
+        }
+
+        Ok(history)
+    }
+}
+
 impl<TX: DbTx> ChangeSetReader for DatabaseProvider<TX> {
     fn account_block_changeset(
         &self,
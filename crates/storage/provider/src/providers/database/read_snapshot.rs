@@ -0,0 +1,172 @@
+use crate::ReadSnapshotFactory;
+use metrics::Counter;
+use reth_interfaces::provider::ProviderResult;
+use reth_metrics::Metrics;
+use reth_primitives::BlockNumber;
+use std::{
+    fmt,
+    ops::RangeInclusive,
+    time::{Duration, Instant},
+};
+use tracing::trace;
+
+/// The default maximum age of a read transaction handed out by the [ReadSnapshotManager].
+pub const DEFAULT_MAX_READ_SNAPSHOT_AGE: Duration = Duration::from_secs(10);
+
+/// Hands out read-only providers for long-running scans while capping the age of the underlying
+/// read transaction.
+///
+/// MDBX can't reuse pages that are still visible to an open read transaction, so long scans that
+/// keep a single transaction open make the database grow and are eventually aborted by the read
+/// transaction timeout. Instead, the manager transparently replaces its snapshot with a fresh read
+/// transaction once the current one is older than the configured maximum age.
+///
+/// NOTE: Consecutive chunks may observe different database snapshots, so this should only be used
+/// for data that doesn't change once written, e.g. canonical blocks below the finalized tip.
+pub struct ReadSnapshotManager<'a, F: ReadSnapshotFactory> {
+    /// The factory used to open fresh read transactions.
+    factory: &'a F,
+    /// The maximum age of a read transaction before it's replaced.
+    max_age: Duration,
+    /// The current provider and the time its read transaction was opened.
+    current: Option<(F::Snapshot, Instant)>,
+    /// The number of read transactions replaced by this manager.
+    refreshes: u64,
+    metrics: ReadSnapshotMetrics,
+}
+
+impl<'a, F: ReadSnapshotFactory> ReadSnapshotManager<'a, F> {
+    /// Creates a new [ReadSnapshotManager] that replaces read transactions older than `max_age`.
+    pub fn new(factory: &'a F, max_age: Duration) -> Self {
+        Self {
+            factory,
+            max_age,
+            current: None,
+            refreshes: 0,
+            metrics: ReadSnapshotMetrics::default(),
+        }
+    }
+
+    /// Returns the number of read transactions that were replaced because they exceeded the
+    /// maximum age.
+    pub const fn refreshes(&self) -> u64 {
+        self.refreshes
+    }
+
+    /// Returns a provider whose read transaction is younger than the configured maximum age,
+    /// opening a fresh read transaction if necessary.
+    pub fn provider(&mut self) -> ProviderResult<&F::Snapshot> {
+        let expired =
+            self.current.as_ref().map_or(true, |(_, opened)| opened.elapsed() >= self.max_age);
+        if expired {
+            if let Some((_, opened)) = self.current.take() {
+                trace!(target: "providers::db", age = ?opened.elapsed(), "Refreshing read snapshot");
+                self.refreshes += 1;
+                self.metrics.refreshed_total.increment(1);
+            }
+            self.current = Some((self.factory.read_snapshot()?, Instant::now()));
+        }
+        Ok(&self.current.as_ref().expect("snapshot was just opened").0)
+    }
+
+    /// Walks the given block range in chunks of at most `chunk_size` blocks, calling `f` with a
+    /// provider for each chunk.
+    ///
+    /// The read transaction is checked between chunks and replaced if it exceeded the maximum age,
+    /// so a single chunk should be small enough to be processed well within that age.
+    pub fn for_each_chunk<G>(
+        &mut self,
+        range: RangeInclusive<BlockNumber>,
+        chunk_size: u64,
+        mut f: G,
+    ) -> ProviderResult<()>
+    where
+        G: FnMut(&F::Snapshot, RangeInclusive<BlockNumber>) -> ProviderResult<()>,
+    {
+        let chunk_size = chunk_size.max(1);
+        let (mut start, end) = range.into_inner();
+        while start <= end {
+            let chunk_end = start.saturating_add(chunk_size - 1).min(end);
+            f(self.provider()?, start..=chunk_end)?;
+            self.metrics.chunks_total.increment(1);
+
+            if chunk_end == BlockNumber::MAX {
+                break
+            }
+            start = chunk_end + 1;
+        }
+        Ok(())
+    }
+}
+
+impl<F: ReadSnapshotFactory> fmt::Debug for ReadSnapshotManager<'_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadSnapshotManager")
+            .field("max_age", &self.max_age)
+            .field("opened", &self.current.as_ref().map(|(_, opened)| opened))
+            .field("refreshes", &self.refreshes)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Metrics)]
+#[metrics(scope = "storage.providers.read_snapshots")]
+/// Read snapshot manager metrics
+struct ReadSnapshotMetrics {
+    /// The number of read transactions that were replaced because they exceeded the maximum age
+    refreshed_total: Counter,
+    /// The number of processed chunks of chunked scans
+    chunks_total: Counter,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::create_test_provider_factory;
+
+    #[test]
+    fn chunks_range_and_refreshes_snapshot() {
+        let factory = create_test_provider_factory();
+
+        let mut manager = ReadSnapshotManager::new(&factory, Duration::ZERO);
+        let mut chunks = Vec::new();
+        manager
+            .for_each_chunk(0..=10, 4, |_, chunk| {
+                chunks.push(chunk);
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(chunks, vec![0..=3, 4..=7, 8..=10]);
+
+        // a snapshot that didn't reach its maximum age is reused
+        let mut manager = ReadSnapshotManager::new(&factory, Duration::from_secs(60));
+        manager.provider().unwrap();
+        let opened = manager.current.as_ref().unwrap().1;
+        manager.provider().unwrap();
+        assert_eq!(manager.current.as_ref().unwrap().1, opened);
+    }
+
+    #[test]
+    fn scan_longer_than_max_age_renews_snapshot() {
+        let factory = create_test_provider_factory();
+
+        let mut manager = factory.read_snapshots(Duration::from_millis(20));
+        manager
+            .for_each_chunk(0..=4, 1, |_, _| {
+                std::thread::sleep(Duration::from_millis(30));
+                Ok(())
+            })
+            .unwrap();
+        // every chunk after the first one exceeded the maximum age of its predecessor's snapshot
+        assert_eq!(manager.refreshes(), 4);
+
+        let mut manager = factory.read_snapshots(Duration::from_secs(60));
+        manager
+            .for_each_chunk(0..=4, 1, |_, _| {
+                std::thread::sleep(Duration::from_millis(30));
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(manager.refreshes(), 0);
+    }
+}
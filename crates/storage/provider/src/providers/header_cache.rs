@@ -0,0 +1,253 @@
+use parking_lot::Mutex;
+use reth_interfaces::provider::ProviderResult;
+use reth_primitives::{BlockHash, BlockNumber, SealedHeader};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+/// The default number of recent canonical header hashes and numbers that are cached.
+pub const DEFAULT_CANONICAL_HEADER_CACHE_SIZE: usize = 10_000;
+
+/// Bounded cache of the hash ↔ number mappings of recent canonical headers.
+///
+/// When full, the lowest block numbers are evicted first. Entries above the fork block are
+/// invalidated when the canonical head changes to a block that isn't a descendant of the cached
+/// chain.
+///
+/// Clones share the same entries, so the cache can be shared between all components that look up
+/// canonical headers.
+#[derive(Debug, Clone)]
+pub struct CanonicalHeaderCache {
+    inner: Arc<Mutex<CanonicalHeaderCacheInner>>,
+}
+
+impl CanonicalHeaderCache {
+    /// Creates a new cache that holds up to `capacity` headers.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(CanonicalHeaderCacheInner {
+                hashes: BTreeMap::new(),
+                numbers: HashMap::new(),
+                capacity: capacity.max(1),
+                generation: 0,
+            })),
+        }
+    }
+
+    /// Returns the canonical hash of the block number, fetching and caching it if it isn't cached.
+    pub fn hash_or_fetch(
+        &self,
+        number: BlockNumber,
+        fetch: impl FnOnce(BlockNumber) -> ProviderResult<Option<BlockHash>>,
+    ) -> ProviderResult<Option<BlockHash>> {
+        let generation = {
+            let inner = self.inner.lock();
+            if let Some(hash) = inner.hashes.get(&number) {
+                return Ok(Some(*hash))
+            }
+            inner.generation
+        };
+
+        let hash = fetch(number)?;
+        if let Some(hash) = hash {
+            self.inner.lock().insert_if_current(generation, number, hash);
+        }
+        Ok(hash)
+    }
+
+    /// Returns the number of the canonical block hash, fetching and caching it if it isn't cached.
+    pub fn number_or_fetch(
+        &self,
+        hash: BlockHash,
+        fetch: impl FnOnce(BlockHash) -> ProviderResult<Option<BlockNumber>>,
+    ) -> ProviderResult<Option<BlockNumber>> {
+        let generation = {
+            let inner = self.inner.lock();
+            if let Some(number) = inner.numbers.get(&hash) {
+                return Ok(Some(*number))
+            }
+            inner.generation
+        };
+
+        let number = fetch(hash)?;
+        if let Some(number) = number {
+            self.inner.lock().insert_if_current(generation, number, hash);
+        }
+        Ok(number)
+    }
+
+    /// Updates the cache for a new canonical head.
+    ///
+    /// Blocks above the head are dropped. If the head doesn't extend the cached parent, the
+    /// remaining blocks are checked against `canonical_hash` from the highest one down, until one
+    /// of them is still canonical. All blocks above it were replaced by the new chain and are
+    /// dropped, while the ones below it are part of the same chain and are kept.
+    ///
+    /// `canonical_hash` is called without holding the lock of the cache.
+    pub fn on_canonical_head(
+        &self,
+        head: &SealedHeader,
+        canonical_hash: impl Fn(BlockNumber) -> ProviderResult<Option<BlockHash>>,
+    ) {
+        let (generation, candidates) = {
+            let mut inner = self.inner.lock();
+            // values fetched before the head changed must not be cached anymore
+            inner.generation += 1;
+
+            for (_, hash) in inner.hashes.split_off(&(head.number + 1)) {
+                inner.numbers.remove(&hash);
+            }
+
+            let extends_cached_parent = head
+                .number
+                .checked_sub(1)
+                .map_or(true, |parent| inner.hashes.get(&parent) == Some(&head.parent_hash));
+            let candidates = if extends_cached_parent {
+                Vec::new()
+            } else {
+                inner.hashes.range(..head.number).rev().map(|(n, h)| (*n, *h)).collect()
+            };
+            (inner.generation, candidates)
+        };
+
+        // The lowest block number from which entries below the head have to be dropped.
+        let mut invalid_from = None;
+        if !candidates.is_empty() {
+            invalid_from = Some(0);
+            for (number, hash) in candidates {
+                match canonical_hash(number) {
+                    Ok(Some(canonical)) if canonical == hash => {
+                        invalid_from = Some(number + 1);
+                        break
+                    }
+                    // replaced by the new chain
+                    Ok(Some(_)) => continue,
+                    // unknown, so nothing below can be verified either
+                    Ok(None) | Err(_) => break,
+                }
+            }
+        }
+
+        let mut inner = self.inner.lock();
+        if inner.generation != generation {
+            // a more recent head is being applied, which verifies the entries on its own
+            return
+        }
+
+        if let Some(invalid_from) = invalid_from {
+            let mut invalid = inner.hashes.split_off(&invalid_from);
+            let mut retained = invalid.split_off(&head.number);
+            inner.hashes.append(&mut retained);
+            for (_, hash) in invalid {
+                inner.numbers.remove(&hash);
+            }
+        }
+
+        inner.insert(head.number, head.hash());
+    }
+}
+
+#[derive(Debug)]
+struct CanonicalHeaderCacheInner {
+    /// Canonical block hashes by number.
+    hashes: BTreeMap<BlockNumber, BlockHash>,
+    /// Canonical block numbers by hash.
+    numbers: HashMap<BlockHash, BlockNumber>,
+    /// The maximum number of cached headers.
+    capacity: usize,
+    /// Incremented whenever the canonical head changes, so that values fetched before the change
+    /// are not cached.
+    generation: u64,
+}
+
+impl CanonicalHeaderCacheInner {
+    fn insert_if_current(&mut self, generation: u64, number: BlockNumber, hash: BlockHash) {
+        if self.generation == generation {
+            self.insert(number, hash);
+        }
+    }
+
+    fn insert(&mut self, number: BlockNumber, hash: BlockHash) {
+        if let Some(previous) = self.hashes.insert(number, hash) {
+            self.numbers.remove(&previous);
+        }
+        self.numbers.insert(hash, number);
+
+        while self.hashes.len() > self.capacity {
+            if let Some((_, hash)) = self.hashes.pop_first() {
+                self.numbers.remove(&hash);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives::{Header, B256};
+
+    #[test]
+    fn invalidates_replaced_blocks() {
+        let cache = CanonicalHeaderCache::new(10);
+        let hash = B256::with_last_byte;
+        let not_called = |_| -> ProviderResult<Option<BlockHash>> { unreachable!() };
+
+        for number in 1..=3 {
+            assert_eq!(
+                cache.hash_or_fetch(number, |n| Ok(Some(hash(n as u8)))),
+                Ok(Some(hash(number as u8)))
+            );
+        }
+        assert_eq!(cache.hash_or_fetch(2, not_called), Ok(Some(hash(2))));
+        assert_eq!(cache.number_or_fetch(hash(3), |_| unreachable!()), Ok(Some(3)));
+
+        // extending the cached chain keeps all entries
+        let head = Header { number: 4, parent_hash: hash(3), ..Default::default() }.seal_slow();
+        cache.on_canonical_head(&head, not_called);
+        assert_eq!(cache.hash_or_fetch(1, not_called), Ok(Some(hash(1))));
+        assert_eq!(cache.hash_or_fetch(4, not_called), Ok(Some(head.hash())));
+
+        // a head that doesn't extend the cached chain drops all entries that were replaced
+        let head = Header { number: 3, parent_hash: hash(12), ..Default::default() }.seal_slow();
+        cache.on_canonical_head(&head, |number| Ok(Some(hash(number as u8 + 10))));
+        assert_eq!(cache.number_or_fetch(hash(3), |_| Ok(None)), Ok(None));
+        assert_eq!(cache.hash_or_fetch(1, |_| Ok(Some(hash(11)))), Ok(Some(hash(11))));
+        assert_eq!(cache.hash_or_fetch(3, not_called), Ok(Some(head.hash())));
+    }
+
+    #[test]
+    fn invalidates_entries_above_fork_block() {
+        let cache = CanonicalHeaderCache::new(10);
+        let hash = B256::with_last_byte;
+        let not_called = |_| -> ProviderResult<Option<BlockHash>> { unreachable!() };
+
+        for number in 1..=5 {
+            cache.hash_or_fetch(number, |n| Ok(Some(hash(n as u8)))).unwrap();
+        }
+
+        // reorg with fork block 3 to a new head 7, whose parent isn't cached
+        let canonical_hash = |number: BlockNumber| {
+            Ok(Some(if number <= 3 { hash(number as u8) } else { hash(number as u8 + 100) }))
+        };
+        let head = Header { number: 7, parent_hash: hash(106), ..Default::default() }.seal_slow();
+        cache.on_canonical_head(&head, canonical_hash);
+
+        for number in 1..=3 {
+            assert_eq!(cache.hash_or_fetch(number, not_called), Ok(Some(hash(number as u8))));
+        }
+        for number in 4..=5 {
+            assert_eq!(cache.number_or_fetch(hash(number as u8), |_| Ok(None)), Ok(None));
+            assert_eq!(
+                cache.hash_or_fetch(number, |n| canonical_hash(n)),
+                Ok(Some(hash(number as u8 + 100)))
+            );
+        }
+        assert_eq!(cache.hash_or_fetch(7, not_called), Ok(Some(head.hash())));
+
+        // unknown entries can't be verified, so everything below them is dropped
+        let head = Header { number: 8, parent_hash: hash(50), ..Default::default() }.seal_slow();
+        cache.on_canonical_head(&head, |_| Ok(None));
+        assert_eq!(cache.number_or_fetch(hash(1), |_| Ok(None)), Ok(None));
+    }
+}
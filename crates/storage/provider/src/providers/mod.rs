@@ -1,10 +1,11 @@
 use crate::{
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    BlockSource, BlockchainTreePendingStateProvider, BundleStateDataProvider, CanonChainTracker,
-    CanonStateNotifications, CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader,
-    DatabaseProviderFactory, EvmEnvProvider, HeaderProvider, ProviderError, PruneCheckpointReader,
-    ReceiptProvider, ReceiptProviderIdExt, StageCheckpointReader, StateProviderBox,
-    StateProviderFactory, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    AccountHistoryReader, AccountReader, BlockHashReader, BlockIdReader, BlockNumReader,
+    BlockReader, BlockReaderIdExt, BlockSource, BlockchainTreePendingStateProvider,
+    BundleStateDataProvider, CanonChainTracker, CanonStateNotifications, CanonStateSubscriptions,
+    ChainSpecProvider, ChangeSetReader, DatabaseProviderFactory, EvmEnvProvider, HeaderProvider,
+    ProviderError, PruneCheckpointReader, ReadSnapshotFactory, ReceiptProvider,
+    ReceiptProviderIdExt, StageCheckpointReader, StateProviderBox, StateProviderFactory,
+    StaticFileProviderFactory, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
 };
 use reth_db::{
     database::Database,
@@ -43,8 +44,10 @@ pub use database::*;
 
 mod static_file;
 pub use static_file::{
-    StaticFileJarProvider, StaticFileProvider, StaticFileProviderRW, StaticFileProviderRWRefMut,
-    StaticFileWriter,
+    StaticFileChecksum, StaticFileChecksumStatus, StaticFileJarProvider, StaticFileProvider,
+    StaticFileProviderRW, StaticFileProviderRWRefMut, StaticFileSegmentStats,
+    StaticFileSnapshotEntry, StaticFileSnapshotFile, StaticFileWriter,
+    DEFAULT_SLOW_COMMIT_THRESHOLD, FILE_DESCRIPTORS_PER_STATIC_FILE,
 };
 
 mod state;
@@ -59,6 +62,9 @@ pub use bundle_state_provider::BundleStateProvider;
 mod chain_info;
 use chain_info::ChainInfoTracker;
 
+mod header_cache;
+pub use header_cache::{CanonicalHeaderCache, DEFAULT_CANONICAL_HEADER_CACHE_SIZE};
+
 mod consistent_view;
 pub use consistent_view::{ConsistentDbView, ConsistentViewError};
 
@@ -188,7 +194,9 @@ where
     Tree: Send + Sync,
 {
     fn block_hash(&self, number: u64) -> ProviderResult<Option<B256>> {
-        self.database.block_hash(number)
+        self.database
+            .header_cache()
+            .hash_or_fetch(number, |number| self.database.block_hash(number))
     }
 
     fn canonical_hashes_range(
@@ -218,7 +226,7 @@ where
     }
 
     fn block_number(&self, hash: B256) -> ProviderResult<Option<BlockNumber>> {
-        self.database.block_number(hash)
+        self.database.header_cache().number_or_fetch(hash, |hash| self.database.block_number(hash))
     }
 }
 
@@ -746,7 +754,7 @@ where
 
 impl<DB, Tree> CanonChainTracker for BlockchainProvider<DB, Tree>
 where
-    DB: Send + Sync,
+    DB: Database,
     Tree: Send + Sync,
     Self: BlockReader,
 {
@@ -768,6 +776,10 @@ where
     }
 
     fn set_canonical_head(&self, header: SealedHeader) {
+        // the header cache is shared with the blockchain tree through the provider factory
+        self.database
+            .header_cache()
+            .on_canonical_head(&header, |number| self.database.block_hash(number));
         self.chain_info.set_canonical_head(header);
     }
 
@@ -896,6 +908,43 @@ where
     }
 }
 
+impl<DB, Tree> AccountHistoryReader for BlockchainProvider<DB, Tree>
+where
+    DB: Database,
+    Tree: Sync + Send,
+{
+    fn account_history(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Option<Account>)>> {
+        self.database.provider()?.account_history(address, range, limit)
+    }
+}
+
+impl<DB, Tree> StaticFileProviderFactory for BlockchainProvider<DB, Tree>
+where
+    DB: Send + Sync,
+    Tree: Send + Sync,
+{
+    fn static_file_provider(&self) -> StaticFileProvider {
+        self.database.static_file_provider()
+    }
+}
+
+impl<DB, Tree> ReadSnapshotFactory for BlockchainProvider<DB, Tree>
+where
+    DB: Database,
+    Tree: Send + Sync,
+{
+    type Snapshot = DatabaseProviderRO<DB>;
+
+    fn read_snapshot(&self) -> ProviderResult<Self::Snapshot> {
+        self.database.provider()
+    }
+}
+
 impl<DB, Tree> AccountReader for BlockchainProvider<DB, Tree>
 where
     DB: Database + Sync + Send,
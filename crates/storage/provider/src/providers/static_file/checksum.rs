@@ -0,0 +1,153 @@
+use reth_interfaces::provider::{ProviderError, ProviderResult};
+use reth_nippy_jar::NippyJar;
+use reth_primitives::{
+    fs::FsPathError,
+    static_file::{SegmentHeader, SegmentRangeInclusive},
+    StaticFileSegment, B256,
+};
+use sha2::{Digest, Sha256};
+use std::{fs::File, io::Read, path::Path};
+
+/// Result of verifying the checksum of a static file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaticFileChecksumStatus {
+    /// The static file matches its checksum.
+    Valid,
+    /// The static file doesn't match its checksum.
+    Mismatch {
+        /// The checksum recorded when the static file was completed.
+        expected: u64,
+        /// The checksum of the static file on disk.
+        got: u64,
+    },
+    /// The static file has no checksum, because it's not complete yet.
+    Missing,
+}
+
+/// Checksum verification result of a single static file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaticFileChecksum {
+    /// Segment of the static file.
+    pub segment: StaticFileSegment,
+    /// Block range of the static file.
+    pub block_range: SegmentRangeInclusive,
+    /// Verification result.
+    pub status: StaticFileChecksumStatus,
+}
+
+/// Computes the SHA-256 checksum of a single file.
+pub(crate) fn compute_file_checksum(path: &Path) -> ProviderResult<B256> {
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0; 1 << 20];
+    let mut file = File::open(path).map_err(|err| FsPathError::open(err, path))?;
+    loop {
+        let read = file.read(&mut buf).map_err(|err| FsPathError::read(err, path))?;
+        if read == 0 {
+            break
+        }
+        hasher.update(&buf[..read]);
+    }
+
+    Ok(B256::from_slice(&hasher.finalize()))
+}
+
+/// Verifies the data file of the static file at `data_path` against the checksum recorded in its
+/// [`SegmentHeader`] when it was completed.
+pub(crate) fn verify_checksum(data_path: &Path) -> ProviderResult<StaticFileChecksumStatus> {
+    let jar = NippyJar::<SegmentHeader>::load(data_path)
+        .map_err(|e| ProviderError::NippyJar(e.to_string()))?;
+    let Some(expected) = jar.user_header().checksum() else {
+        return Ok(StaticFileChecksumStatus::Missing)
+    };
+
+    let got = jar.data_checksum().map_err(|e| ProviderError::NippyJar(e.to_string()))?;
+    Ok(if got == expected {
+        StaticFileChecksumStatus::Valid
+    } else {
+        StaticFileChecksumStatus::Mismatch { expected, got }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{providers::StaticFileWriter, test_utils::create_test_provider_factory};
+    use reth_primitives::{Header, U256};
+    use std::{fs::OpenOptions, io::Write};
+
+    #[test]
+    fn detects_modified_static_file() {
+        let factory = create_test_provider_factory().with_static_files_blocks_per_file(10).unwrap();
+        let static_file_provider = factory.static_file_provider();
+        let data_path = |block| {
+            static_file_provider.directory().join(
+                StaticFileSegment::Headers.filename(&static_file_provider.find_fixed_range(block)),
+            )
+        };
+
+        // The first static file is completed when the first block of the next one is appended
+        {
+            let mut writer =
+                static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+            for number in 0..10 {
+                let header = Header { number, ..Default::default() };
+                let hash = header.hash_slow();
+                writer.append_header(header, U256::ZERO, hash).unwrap();
+            }
+            writer.commit().unwrap();
+            assert_eq!(verify_checksum(&data_path(0)), Ok(StaticFileChecksumStatus::Missing));
+
+            let header = Header { number: 10, ..Default::default() };
+            let hash = header.hash_slow();
+            writer.append_header(header, U256::ZERO, hash).unwrap();
+            writer.commit().unwrap();
+        }
+        assert_eq!(verify_checksum(&data_path(0)), Ok(StaticFileChecksumStatus::Valid));
+        assert_eq!(verify_checksum(&data_path(10)), Ok(StaticFileChecksumStatus::Missing));
+
+        let expected = NippyJar::<SegmentHeader>::load(&data_path(0))
+            .unwrap()
+            .user_header()
+            .checksum()
+            .unwrap();
+        OpenOptions::new().append(true).open(data_path(0)).unwrap().write_all(&[0]).unwrap();
+        let got = NippyJar::<SegmentHeader>::load(&data_path(0)).unwrap().data_checksum().unwrap();
+        assert_eq!(
+            static_file_provider.verify_checksums().unwrap(),
+            vec![
+                StaticFileChecksum {
+                    segment: StaticFileSegment::Headers,
+                    block_range: SegmentRangeInclusive::new(0, 9),
+                    status: StaticFileChecksumStatus::Mismatch { expected, got },
+                },
+                StaticFileChecksum {
+                    segment: StaticFileSegment::Headers,
+                    block_range: SegmentRangeInclusive::new(10, 10),
+                    status: StaticFileChecksumStatus::Missing,
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn truncation_clears_checksum() {
+        let factory = create_test_provider_factory().with_static_files_blocks_per_file(10).unwrap();
+        let static_file_provider = factory.static_file_provider();
+        let data_path = static_file_provider
+            .directory()
+            .join(StaticFileSegment::Headers.filename(&static_file_provider.find_fixed_range(0)));
+
+        let mut writer = static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+        for number in 0..11 {
+            let header = Header { number, ..Default::default() };
+            let hash = header.hash_slow();
+            writer.append_header(header, U256::ZERO, hash).unwrap();
+        }
+        writer.commit().unwrap();
+        assert_eq!(verify_checksum(&data_path), Ok(StaticFileChecksumStatus::Valid));
+
+        writer.prune_headers(2).unwrap();
+        writer.commit().unwrap();
+        assert_eq!(verify_checksum(&data_path), Ok(StaticFileChecksumStatus::Missing));
+    }
+}
@@ -0,0 +1,346 @@
+use memmap2::Mmap;
+use parking_lot::Mutex;
+use reth_db::static_file::{HeaderMask, StaticFileCursor, TransactionMask};
+use reth_interfaces::provider::{ProviderError, ProviderResult};
+use reth_nippy_jar::{DataReader, NippyJar};
+use reth_primitives::{
+    fs::FsPathError,
+    static_file::{SegmentHeader, SegmentRangeInclusive},
+    StaticFileSegment, TransactionSignedNoHash, B256,
+};
+use std::{
+    collections::HashMap,
+    fs::File,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// Size of the index header: the checksum of the static file the index was built from.
+const HEADER_SIZE: usize = 8;
+
+/// Size of an encoded index entry: the hash followed by the row number.
+const ENTRY_SIZE: usize = 40;
+
+/// Returns the path of the hash index of the static file at `data_path`, if the segment is
+/// indexed.
+fn hash_index_path(segment: StaticFileSegment, data_path: &Path) -> Option<PathBuf> {
+    let extension = match segment {
+        StaticFileSegment::Headers => "bhi",
+        StaticFileSegment::Transactions => "txi",
+        StaticFileSegment::Receipts => return None,
+    };
+    Some(data_path.with_extension(extension))
+}
+
+/// Returns the range of rows of the static file that are indexed by hash: the block numbers of a
+/// headers static file, and the transaction numbers of a transactions static file.
+fn indexed_rows(header: &SegmentHeader) -> Option<SegmentRangeInclusive> {
+    match header.segment() {
+        StaticFileSegment::Headers => header.block_range().copied(),
+        StaticFileSegment::Transactions => header.tx_range().copied(),
+        StaticFileSegment::Receipts => None,
+    }
+}
+
+/// Reads the hash of the given row of a headers or transactions static file.
+fn read_hash(
+    segment: StaticFileSegment,
+    cursor: &mut StaticFileCursor<'_>,
+    row: u64,
+) -> ProviderResult<Option<B256>> {
+    Ok(match segment {
+        StaticFileSegment::Headers => cursor.get_one::<HeaderMask<B256>>(row.into())?,
+        StaticFileSegment::Transactions => cursor
+            .get_one::<TransactionMask<TransactionSignedNoHash>>(row.into())?
+            .map(|tx| tx.hash()),
+        StaticFileSegment::Receipts => None,
+    })
+}
+
+/// Index of the hashes stored in a static file: block hashes to block numbers in a headers static
+/// file, and transaction hashes to transaction numbers in a transactions static file.
+///
+/// Completed static files persist their index next to them, sorted by hash, and it's searched in
+/// place without reading it into memory. The index records the checksum of the static file, so
+/// an index that outlived a truncation of its static file is never used. Completed static files
+/// are never indexed in memory, and are scanned with [`HashIndex::scan`] until their index is
+/// written.
+///
+/// Incomplete static files have their index built in memory when it's first used, and extended
+/// with the rows of every commit afterwards.
+#[derive(Debug)]
+pub enum HashIndex {
+    /// Index persisted next to a completed static file.
+    Persisted(Mmap),
+    /// Index of a static file that's still being written.
+    InMemory {
+        /// Row numbers by hash.
+        entries: HashMap<B256, u64>,
+        /// The highest indexed row, if any.
+        row_end: Option<u64>,
+    },
+}
+
+impl HashIndex {
+    /// Builds an in-memory index by reading all hashes of the static file.
+    pub fn build(jar: &NippyJar<SegmentHeader>, reader: Arc<DataReader>) -> ProviderResult<Self> {
+        let mut index = Self::InMemory { entries: HashMap::new(), row_end: None };
+        index.extend(jar, reader)?;
+        Ok(index)
+    }
+
+    /// Brings an in-memory index up to date with the static file, only reading the rows that
+    /// were appended since it was last extended.
+    ///
+    /// Rows that were truncated from the static file are removed from the index, and the index
+    /// is rebuilt if the static file no longer holds the last indexed row.
+    pub fn extend(
+        &mut self,
+        jar: &NippyJar<SegmentHeader>,
+        reader: Arc<DataReader>,
+    ) -> ProviderResult<()> {
+        let Self::InMemory { entries, row_end } = self else { return Ok(()) };
+        let segment = jar.user_header().segment();
+        let Some(rows) = indexed_rows(jar.user_header()) else {
+            entries.clear();
+            *row_end = None;
+            return Ok(())
+        };
+
+        let mut cursor = StaticFileCursor::new(jar, reader)?;
+        if let Some(end) = *row_end {
+            let indexed_hash =
+                entries.iter().find_map(|(hash, row)| (*row == end).then_some(*hash));
+            if end > rows.end() {
+                entries.retain(|_, row| *row <= rows.end());
+                *row_end = Some(rows.end());
+            } else if end < rows.start() || read_hash(segment, &mut cursor, end)? != indexed_hash {
+                // The static file was rewritten since it was indexed
+                entries.clear();
+                *row_end = None;
+            }
+        }
+
+        let start = row_end.map_or(rows.start(), |end| end + 1);
+        entries.reserve((rows.end() + 1).saturating_sub(start) as usize);
+        for row in start..=rows.end() {
+            if let Some(hash) = read_hash(segment, &mut cursor, row)? {
+                entries.insert(hash, row);
+            }
+        }
+        *row_end = Some(rows.end());
+        Ok(())
+    }
+
+    /// Returns the row number of the given hash in the static file, by reading all of its hashes
+    /// until it's found. Used for completed static files whose index hasn't been written yet.
+    pub fn scan(
+        jar: &NippyJar<SegmentHeader>,
+        reader: Arc<DataReader>,
+        hash: &B256,
+    ) -> ProviderResult<Option<u64>> {
+        let segment = jar.user_header().segment();
+        let Some(rows) = indexed_rows(jar.user_header()) else { return Ok(None) };
+
+        let mut cursor = StaticFileCursor::new(jar, reader)?;
+        for row in rows.start()..=rows.end() {
+            if read_hash(segment, &mut cursor, row)?.as_ref() == Some(hash) {
+                return Ok(Some(row))
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the row number of the given hash, if it's in the index.
+    pub fn get(&self, hash: &B256) -> Option<u64> {
+        match self {
+            Self::Persisted(mmap) => {
+                let entries = &mmap[HEADER_SIZE..];
+                let entry = |index: usize| &entries[index * ENTRY_SIZE..(index + 1) * ENTRY_SIZE];
+
+                let (mut low, mut high) = (0, entries.len() / ENTRY_SIZE);
+                while low < high {
+                    let mid = low + (high - low) / 2;
+                    let (entry_hash, row) = entry(mid).split_at(32);
+                    match entry_hash.cmp(hash.as_slice()) {
+                        std::cmp::Ordering::Less => low = mid + 1,
+                        std::cmp::Ordering::Greater => high = mid,
+                        std::cmp::Ordering::Equal => {
+                            return Some(u64::from_le_bytes(row.try_into().expect("qed")))
+                        }
+                    }
+                }
+                None
+            }
+            Self::InMemory { entries, .. } => entries.get(hash).copied(),
+        }
+    }
+
+    /// Builds the index of the completed static file at `data_path` and writes it next to it.
+    ///
+    /// A static file that was completed before checksums were recorded gets its checksum
+    /// backfilled first, see [`Self::backfill_checksum`].
+    ///
+    /// Does nothing for segments that aren't indexed and for incomplete static files.
+    pub fn write(data_path: &Path, truncations: &Mutex<u64>) -> ProviderResult<()> {
+        let jar = NippyJar::<SegmentHeader>::load(data_path)
+            .map_err(|e| ProviderError::NippyJar(e.to_string()))?;
+        let segment = jar.user_header().segment();
+        let Some(path) = hash_index_path(segment, data_path) else { return Ok(()) };
+        if !jar.user_header().is_complete() {
+            return Ok(())
+        }
+        let checksum = match jar.user_header().checksum() {
+            Some(checksum) => checksum,
+            None => match Self::backfill_checksum(data_path, truncations)? {
+                Some(checksum) => checksum,
+                None => return Ok(()),
+            },
+        };
+        let reader =
+            Arc::new(jar.open_data_reader().map_err(|e| ProviderError::NippyJar(e.to_string()))?);
+
+        let mut entries = Vec::new();
+        if let Some(rows) = indexed_rows(jar.user_header()) {
+            let mut cursor = StaticFileCursor::new(&jar, reader)?;
+            entries.reserve((rows.end() - rows.start() + 1) as usize);
+            for row in rows.start()..=rows.end() {
+                if let Some(hash) = read_hash(segment, &mut cursor, row)? {
+                    entries.push((hash, row));
+                }
+            }
+        }
+        entries.sort_unstable();
+        Self::persist(&path, checksum, &entries)
+    }
+
+    /// Computes the checksum of the completed static file at `data_path` and records it in its
+    /// header.
+    ///
+    /// `truncations` counts the static files that were truncated or deleted, and is locked while
+    /// doing so. The checksum is only recorded if no static file was truncated or deleted while
+    /// computing it, since it may not match the static file anymore. Returns `None` in that case,
+    /// and if the static file is no longer complete.
+    fn backfill_checksum(
+        data_path: &Path,
+        truncations: &Mutex<u64>,
+    ) -> ProviderResult<Option<u64>> {
+        let load = || {
+            NippyJar::<SegmentHeader>::load(data_path)
+                .map_err(|e| ProviderError::NippyJar(e.to_string()))
+        };
+
+        let truncated = *truncations.lock();
+        let checksum =
+            load()?.data_checksum().map_err(|e| ProviderError::NippyJar(e.to_string()))?;
+
+        let truncations = truncations.lock();
+        let mut jar = load()?;
+        if *truncations != truncated || !jar.user_header().is_complete() {
+            return Ok(None)
+        }
+        if let Some(checksum) = jar.user_header().checksum() {
+            // Recorded by the writer that completed it in the meantime
+            return Ok(Some(checksum))
+        }
+        jar.user_header_mut().set_checksum(Some(checksum));
+        jar.freeze_config().map_err(|e| ProviderError::NippyJar(e.to_string()))?;
+
+        Ok(Some(checksum))
+    }
+
+    /// Writes the sorted `entries` of a static file with the given checksum to `path`.
+    ///
+    /// The index is written to a temporary file first, so that an interrupted write doesn't
+    /// leave a partial index behind.
+    fn persist(path: &Path, checksum: u64, entries: &[(B256, u64)]) -> ProviderResult<()> {
+        let mut buf = Vec::with_capacity(HEADER_SIZE + entries.len() * ENTRY_SIZE);
+        buf.extend_from_slice(&checksum.to_le_bytes());
+        for (hash, row) in entries {
+            buf.extend_from_slice(hash.as_slice());
+            buf.extend_from_slice(&row.to_le_bytes());
+        }
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        reth_primitives::fs::write(&tmp_path, buf)?;
+        reth_primitives::fs::rename(tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Maps the persisted index of the static file at `data_path`, which has the given header.
+    ///
+    /// Returns `None` if the static file has no index, if the index is malformed, or if it was
+    /// built from a static file with a different checksum.
+    pub fn load(data_path: &Path, header: &SegmentHeader) -> ProviderResult<Option<Self>> {
+        let Some(checksum) = header.checksum() else { return Ok(None) };
+        let Some(path) = hash_index_path(header.segment(), data_path) else { return Ok(None) };
+        if !path.exists() {
+            return Ok(None)
+        }
+
+        let file = File::open(&path).map_err(|err| FsPathError::open(err, &path))?;
+        // SAFETY: the index is only written to a temporary file which is then renamed, so a mapped
+        // index is never modified.
+        let mmap = unsafe { Mmap::map(&file) }.map_err(|err| FsPathError::read(err, &path))?;
+        if mmap.len() < HEADER_SIZE ||
+            (mmap.len() - HEADER_SIZE) % ENTRY_SIZE != 0 ||
+            mmap[..HEADER_SIZE] != checksum.to_le_bytes()
+        {
+            return Ok(None)
+        }
+
+        Ok(Some(Self::Persisted(mmap)))
+    }
+
+    /// Deletes the index of the static file at `data_path`, if there's any.
+    pub fn delete(segment: StaticFileSegment, data_path: &Path) -> ProviderResult<()> {
+        if let Some(path) = hash_index_path(segment, data_path) {
+            if path.exists() {
+                reth_primitives::fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn persists_sorted_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let data_path = dir.path().join("static_file_headers_0_499999");
+        let mut header = SegmentHeader::new(
+            (0..=499_999).into(),
+            Some((0..=99).into()),
+            None,
+            StaticFileSegment::Headers,
+        );
+        header.set_checksum(Some(1));
+
+        let mut entries = (0..100).map(|number| (B256::random(), number)).collect::<Vec<_>>();
+        entries.sort_unstable();
+        let path = hash_index_path(StaticFileSegment::Headers, &data_path).unwrap();
+        HashIndex::persist(&path, 1, &entries).unwrap();
+
+        let loaded = HashIndex::load(&data_path, &header).unwrap().unwrap();
+        assert!(matches!(loaded, HashIndex::Persisted(_)));
+        for (hash, number) in entries {
+            assert_eq!(loaded.get(&hash), Some(number));
+        }
+        assert_eq!(loaded.get(&B256::random()), None);
+        assert_eq!(loaded.get(&B256::ZERO), None);
+        assert_eq!(loaded.get(&B256::repeat_byte(0xff)), None);
+
+        // an index of a static file with another checksum, e.g. one that was truncated since
+        header.set_checksum(Some(2));
+        assert!(HashIndex::load(&data_path, &header).unwrap().is_none());
+        header.set_checksum(None);
+        assert!(HashIndex::load(&data_path, &header).unwrap().is_none());
+
+        header.set_checksum(Some(1));
+        HashIndex::delete(StaticFileSegment::Headers, &data_path).unwrap();
+        assert!(HashIndex::load(&data_path, &header).unwrap().is_none());
+    }
+}
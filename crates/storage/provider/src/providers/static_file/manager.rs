@@ -1,6 +1,8 @@
 use super::{
-    metrics::StaticFileProviderMetrics, LoadedJar, StaticFileJarProvider, StaticFileProviderRW,
-    StaticFileProviderRWRefMut, BLOCKS_PER_STATIC_FILE,
+    checksum::{self, StaticFileChecksum},
+    metrics::StaticFileProviderMetrics,
+    HashIndex, LoadedJar, StaticFileJarProvider, StaticFileProviderRW, StaticFileProviderRWRefMut,
+    TxHashFilter,
 };
 use crate::{
     to_range, BlockHashReader, BlockNumReader, BlockReader, BlockSource, HeaderProvider,
@@ -8,7 +10,8 @@ use crate::{
     TransactionsProviderExt, WithdrawalsProvider,
 };
 use dashmap::{mapref::entry::Entry as DashMapEntry, DashMap};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, MutexGuard, RwLock};
+use rayon::prelude::*;
 use reth_db::{
     codecs::CompactU256,
     models::StoredBlockBodyIndices,
@@ -19,8 +22,12 @@ use reth_db::{
 use reth_interfaces::provider::{ProviderError, ProviderResult};
 use reth_nippy_jar::NippyJar;
 use reth_primitives::{
+    fs::FsPathError,
     keccak256,
-    static_file::{find_fixed_range, HighestStaticFiles, SegmentHeader, SegmentRangeInclusive},
+    static_file::{
+        find_fixed_range, HighestStaticFiles, SegmentHeader, SegmentRangeInclusive,
+        BLOCKS_PER_STATIC_FILE,
+    },
     Address, Block, BlockHash, BlockHashOrNumber, BlockNumber, BlockWithSenders, ChainInfo, Header,
     Receipt, SealedBlock, SealedBlockWithSenders, SealedHeader, StaticFileSegment, TransactionMeta,
     TransactionSigned, TransactionSignedNoHash, TxHash, TxNumber, Withdrawal, Withdrawals, B256,
@@ -30,15 +37,70 @@ use std::{
     collections::{hash_map::Entry, BTreeMap, HashMap},
     ops::{Deref, Range, RangeBounds, RangeInclusive},
     path::{Path, PathBuf},
-    sync::{mpsc, Arc},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc, Arc,
+    },
+    time::Duration,
 };
-use tracing::warn;
+use strum::IntoEnumIterator;
+use tracing::{trace, warn};
 
 /// Alias type for a map that can be queried for block ranges from a transaction
 /// segment respectively. It uses `TxNumber` to represent the transaction end of a static file
 /// range.
 type SegmentRanges = HashMap<StaticFileSegment, BTreeMap<TxNumber, SegmentRangeInclusive>>;
 
+/// The number of file descriptors a loaded static file holds open, for its data and offsets
+/// files.
+pub const FILE_DESCRIPTORS_PER_STATIC_FILE: usize = 2;
+
+/// A static file included in a snapshot of static files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaticFileSnapshotEntry {
+    /// Segment of the static file.
+    pub segment: StaticFileSegment,
+    /// Block range of the data in the static file.
+    pub block_range: SegmentRangeInclusive,
+    /// Transaction range of the data in the static file, if it has any transactions.
+    pub tx_range: Option<SegmentRangeInclusive>,
+    /// All files that make up the static file, including its configuration and sidecar files.
+    pub files: Vec<StaticFileSnapshotFile>,
+}
+
+/// A single file of a [StaticFileSnapshotEntry].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaticFileSnapshotFile {
+    /// Path of the file.
+    pub path: PathBuf,
+    /// SHA-256 checksum of the file.
+    pub checksum: B256,
+}
+
+/// Default duration after which a static file commit is logged as slow.
+pub const DEFAULT_SLOW_COMMIT_THRESHOLD: Duration = Duration::from_secs(1);
+
+/// Number of rows prefetched at once by cursors of range requests.
+const RANGE_READ_AHEAD_ROWS: u64 = 1024;
+
+/// Statistics of the static files of a segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaticFileSegmentStats {
+    /// The segment.
+    pub segment: StaticFileSegment,
+    /// Number of static files.
+    pub files: usize,
+    /// Total size of the static files in bytes, including their index, offsets and config files.
+    pub size: u64,
+    /// Total number of rows, i.e. headers, transactions or receipts.
+    pub rows: usize,
+    /// Lowest and highest block stored in the static files.
+    pub block_range: SegmentRangeInclusive,
+    /// Lowest and highest transaction stored in the static files, if the segment stores
+    /// transaction data.
+    pub tx_range: Option<SegmentRangeInclusive>,
+}
+
 /// [`StaticFileProvider`] manages all existing [`StaticFileJarProvider`].
 #[derive(Debug, Default, Clone)]
 pub struct StaticFileProvider(pub(crate) Arc<StaticFileProviderInner>);
@@ -61,7 +123,7 @@ impl Deref for StaticFileProvider {
 }
 
 /// [`StaticFileProviderInner`] manages all existing [`StaticFileJarProvider`].
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct StaticFileProviderInner {
     /// Maintains a map which allows for concurrent access to different `NippyJars`, over different
     /// segments and ranges.
@@ -77,12 +139,26 @@ pub struct StaticFileProviderInner {
     load_filters: bool,
     /// Maintains a map of StaticFile writers for each [`StaticFileSegment`]
     writers: DashMap<StaticFileSegment, StaticFileProviderRW>,
+    /// Maximum number of jars that are kept loaded. If exceeded, the least recently used jars are
+    /// closed.
+    max_open_jars: Option<usize>,
+    /// Monotonic counter used to track the order in which jars were accessed.
+    access_tick: AtomicU64,
+    /// Number of blocks stored in each static file.
+    blocks_per_file: u64,
+    /// Commits taking longer than this are logged as slow.
+    slow_commit_threshold: Duration,
+    /// Number of static files truncated or deleted so far, locked while doing so. See
+    /// [`StaticFileProviderInner::begin_truncation`].
+    truncations: Mutex<u64>,
     metrics: Option<Arc<StaticFileProviderMetrics>>,
 }
 
 impl StaticFileProviderInner {
     /// Creates a new [`StaticFileProviderInner`].
     fn new(path: impl AsRef<Path>) -> ProviderResult<Self> {
+        let blocks_per_file =
+            blocks_per_file_on_disk(path.as_ref())?.unwrap_or(BLOCKS_PER_STATIC_FILE);
         let provider = Self {
             map: Default::default(),
             writers: Default::default(),
@@ -90,11 +166,142 @@ impl StaticFileProviderInner {
             static_files_tx_index: Default::default(),
             path: path.as_ref().to_path_buf(),
             load_filters: false,
+            max_open_jars: None,
+            access_tick: AtomicU64::new(0),
+            blocks_per_file,
+            slow_commit_threshold: DEFAULT_SLOW_COMMIT_THRESHOLD,
+            truncations: Default::default(),
             metrics: None,
         };
 
         Ok(provider)
     }
+
+    /// Returns the number of blocks stored in each static file.
+    pub fn blocks_per_file(&self) -> u64 {
+        self.blocks_per_file
+    }
+
+    /// Returns the fixed block range of the static file the block belongs to.
+    pub fn find_fixed_range(&self, block: BlockNumber) -> SegmentRangeInclusive {
+        find_fixed_range(block, self.blocks_per_file)
+    }
+
+    /// Returns the duration after which a static file commit is logged as slow.
+    pub fn slow_commit_threshold(&self) -> Duration {
+        self.slow_commit_threshold
+    }
+
+    /// Counts a truncation or deletion of a static file, and returns a guard to hold while doing
+    /// it.
+    ///
+    /// Checksums of completed static files that are backfilled in the background are neither
+    /// recorded while the guard is held, nor if a static file was truncated or deleted while they
+    /// were computed.
+    pub(crate) fn begin_truncation(&self) -> MutexGuard<'_, u64> {
+        let mut truncations = self.truncations.lock();
+        *truncations += 1;
+        truncations
+    }
+
+    /// Writes the hash index of the completed static file at `data_path`, and logs any failure.
+    pub(crate) fn write_hash_index(&self, data_path: &Path) {
+        if let Err(err) = HashIndex::write(data_path, &self.truncations) {
+            warn!(target: "provider::static_file", path = ?data_path, %err, "Failed to write hash index");
+        }
+    }
+
+    /// Writes the hash indexes of the completed static files of `segment` that don't have a
+    /// valid one, e.g. because they were completed before indexes were written.
+    ///
+    /// The last static file of the segment is left to its writer.
+    pub(crate) fn write_missing_hash_indexes(
+        &self,
+        segment: StaticFileSegment,
+    ) -> ProviderResult<()> {
+        let static_files =
+            iter_static_files(&self.path).map_err(|e| ProviderError::NippyJar(e.to_string()))?;
+        let Some((_, ranges)) = static_files.get(&segment).and_then(|ranges| ranges.split_last())
+        else {
+            return Ok(())
+        };
+
+        for (block_range, _) in ranges {
+            let fixed_range = self.find_fixed_range(block_range.start());
+            let data_path = self.path.join(segment.filename(&fixed_range));
+            let jar = NippyJar::<SegmentHeader>::load(&data_path)
+                .map_err(|e| ProviderError::NippyJar(e.to_string()))?;
+            if jar.user_header().is_complete() &&
+                HashIndex::load(&data_path, jar.user_header())?.is_none()
+            {
+                self.write_hash_index(&data_path);
+                // The cached jar may predate a backfilled checksum, which its index is loaded with
+                self.map.remove(&(fixed_range.end(), segment));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for StaticFileProviderInner {
+    fn default() -> Self {
+        Self {
+            map: Default::default(),
+            writers: Default::default(),
+            static_files_max_block: Default::default(),
+            static_files_tx_index: Default::default(),
+            path: Default::default(),
+            load_filters: false,
+            max_open_jars: None,
+            access_tick: AtomicU64::new(0),
+            blocks_per_file: BLOCKS_PER_STATIC_FILE,
+            slow_commit_threshold: DEFAULT_SLOW_COMMIT_THRESHOLD,
+            truncations: Default::default(),
+            metrics: None,
+        }
+    }
+}
+
+/// Returns the paths of all files that make up the static file at `data_path`: the data file
+/// itself and every file that shares its name with an additional extension.
+fn static_file_paths(data_path: &Path) -> ProviderResult<Vec<PathBuf>> {
+    let (Some(dir), Some(name)) = (data_path.parent(), data_path.file_name()) else {
+        return Ok(Vec::new())
+    };
+    let name = name.to_string_lossy();
+    let prefix = format!("{name}.");
+
+    let mut paths = reth_primitives::fs::read_dir(dir)?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+            file_name == name || file_name.starts_with(&prefix)
+        })
+        .map(|entry| entry.path())
+        .collect::<Vec<_>>();
+    paths.sort_unstable();
+
+    Ok(paths)
+}
+
+/// Returns the number of blocks per static file of the static files in `path`, if there are any.
+fn blocks_per_file_on_disk(path: &Path) -> ProviderResult<Option<u64>> {
+    if !path.exists() {
+        return Ok(None)
+    }
+
+    for entry in reth_primitives::fs::read_dir(path)? {
+        let entry = entry.map_err(|err| FsPathError::read_dir(err, path))?;
+        if let Some((_, fixed_range)) =
+            StaticFileSegment::parse_filename(&entry.file_name().to_string_lossy())
+        {
+            return Ok(Some(fixed_range.end() - fixed_range.start() + 1))
+        }
+    }
+
+    Ok(None)
 }
 
 impl StaticFileProvider {
@@ -114,41 +321,419 @@ impl StaticFileProvider {
         Self(Arc::new(provider))
     }
 
-    /// Reports metrics for the static files.
-    pub fn report_metrics(&self) -> ProviderResult<()> {
-        let Some(metrics) = &self.metrics else { return Ok(()) };
+    /// Keeps at most `max_open_jars` static files loaded, closing the least recently used ones
+    /// when the limit is reached.
+    ///
+    /// Every loaded static file holds [`FILE_DESCRIPTORS_PER_STATIC_FILE`] file descriptors and
+    /// memory maps, which can exhaust the file descriptor limit on large archives.
+    pub fn with_max_open_jars(self, max_open_jars: usize) -> Self {
+        let mut provider =
+            Arc::try_unwrap(self.0).expect("should be called when initializing only");
+        provider.max_open_jars = Some(max_open_jars.max(1));
+        Self(Arc::new(provider))
+    }
+
+    /// Logs a warning with diagnostics when a static file commit takes longer than `threshold`.
+    pub fn with_slow_commit_threshold(self, threshold: Duration) -> Self {
+        let mut provider =
+            Arc::try_unwrap(self.0).expect("should be called when initializing only");
+        provider.slow_commit_threshold = threshold;
+        Self(Arc::new(provider))
+    }
+
+    /// Stores `blocks_per_file` blocks in each static file.
+    ///
+    /// Smaller files allow unwinding with a finer granularity, while larger files keep the number
+    /// of files low on chains with fast block times. Returns an error if static files with a
+    /// different number of blocks already exist.
+    pub fn with_blocks_per_file(self, blocks_per_file: u64) -> ProviderResult<Self> {
+        let mut provider =
+            Arc::try_unwrap(self.0).expect("should be called when initializing only");
+        let blocks_per_file = blocks_per_file.max(1);
+        if let Some(on_disk) = blocks_per_file_on_disk(&provider.path)? {
+            if on_disk != blocks_per_file {
+                return Err(ProviderError::StaticFileBlocksPerFileMismatch {
+                    configured: blocks_per_file,
+                    on_disk,
+                })
+            }
+        }
+        provider.blocks_per_file = blocks_per_file;
+        Ok(Self(Arc::new(provider)))
+    }
+
+    /// Returns the maximum number of file descriptors held open by loaded static files, based on
+    /// the configured limit or the number of static files on disk.
+    pub fn max_open_file_descriptors(&self) -> ProviderResult<usize> {
+        let jars = match self.max_open_jars {
+            Some(max_open_jars) => max_open_jars,
+            None => iter_static_files(&self.path)
+                .map_err(|e| ProviderError::NippyJar(e.to_string()))?
+                .values()
+                .map(|ranges| ranges.len())
+                .sum(),
+        };
+        Ok(jars * FILE_DESCRIPTORS_PER_STATIC_FILE)
+    }
 
+    /// Returns statistics of the static files of every segment that has static files, ordered by
+    /// segment.
+    pub fn segment_stats(&self) -> ProviderResult<Vec<StaticFileSegmentStats>> {
         let static_files =
             iter_static_files(&self.path).map_err(|e| ProviderError::NippyJar(e.to_string()))?;
+
+        let mut stats = Vec::with_capacity(static_files.len());
         for (segment, ranges) in static_files {
-            let mut entries = 0;
+            let (
+                Some((first_block_range, first_tx_range)),
+                Some((last_block_range, last_tx_range)),
+            ) = (ranges.first(), ranges.last())
+            else {
+                continue
+            };
+
+            let mut rows = 0;
             let mut size = 0;
-
             for (block_range, _) in &ranges {
-                let fixed_block_range = find_fixed_range(block_range.start());
+                let fixed_block_range = self.find_fixed_range(block_range.start());
                 let jar_provider = self
                     .get_segment_provider(segment, || Some(fixed_block_range), None)?
                     .ok_or(ProviderError::MissingStaticFileBlock(segment, block_range.start()))?;
 
-                entries += jar_provider.rows();
-
-                let data_size = reth_primitives::fs::metadata(jar_provider.data_path())
-                    .map(|metadata| metadata.len())
-                    .unwrap_or_default();
-                let index_size = reth_primitives::fs::metadata(jar_provider.index_path())
-                    .map(|metadata| metadata.len())
-                    .unwrap_or_default();
-                let offsets_size = reth_primitives::fs::metadata(jar_provider.offsets_path())
-                    .map(|metadata| metadata.len())
-                    .unwrap_or_default();
-                let config_size = reth_primitives::fs::metadata(jar_provider.config_path())
-                    .map(|metadata| metadata.len())
-                    .unwrap_or_default();
-
-                size += data_size + index_size + offsets_size + config_size;
+                rows += jar_provider.rows();
+                size += [
+                    jar_provider.data_path().to_path_buf(),
+                    jar_provider.index_path(),
+                    jar_provider.offsets_path(),
+                    jar_provider.config_path(),
+                ]
+                .iter()
+                .map(|path| {
+                    reth_primitives::fs::metadata(path)
+                        .map(|metadata| metadata.len())
+                        .unwrap_or_default()
+                })
+                .sum::<u64>();
+            }
+
+            stats.push(StaticFileSegmentStats {
+                segment,
+                files: ranges.len(),
+                size,
+                rows,
+                block_range: SegmentRangeInclusive::new(
+                    first_block_range.start(),
+                    last_block_range.end(),
+                ),
+                tx_range: first_tx_range
+                    .zip(*last_tx_range)
+                    .map(|(first, last)| SegmentRangeInclusive::new(first.start(), last.end())),
+            });
+        }
+        stats.sort_unstable_by_key(|stats| stats.segment);
+
+        Ok(stats)
+    }
+
+    /// Reports metrics for the static files.
+    pub fn report_metrics(&self) -> ProviderResult<()> {
+        let Some(metrics) = &self.metrics else { return Ok(()) };
+
+        for StaticFileSegmentStats { segment, files, size, rows, .. } in self.segment_stats()? {
+            metrics.record_segment(segment, size, files, rows);
+        }
+
+        Ok(())
+    }
+
+    /// Verifies all static files against the checksums recorded when they were completed.
+    ///
+    /// Files are returned ordered by segment and block range.
+    pub fn verify_checksums(&self) -> ProviderResult<Vec<StaticFileChecksum>> {
+        let mut static_files = iter_static_files(&self.path)
+            .map_err(|e| ProviderError::NippyJar(e.to_string()))?
+            .into_iter()
+            .flat_map(|(segment, ranges)| {
+                ranges.into_iter().map(move |(block_range, _)| (segment, block_range))
+            })
+            .collect::<Vec<_>>();
+        static_files.sort_unstable_by_key(|(segment, block_range)| (*segment, block_range.start()));
+
+        static_files
+            .into_iter()
+            .map(|(segment, block_range)| {
+                let fixed_block_range = self.find_fixed_range(block_range.start());
+                let path = self.path.join(segment.filename(&fixed_block_range));
+                Ok(StaticFileChecksum {
+                    segment,
+                    block_range,
+                    status: checksum::verify_checksum(&path)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the static files of `segments` that hold data of any block in `block_range`, to be
+    /// packaged into a snapshot.
+    ///
+    /// Static files are always included as a whole, so the snapshot may cover more blocks than
+    /// requested. The highest static file of each segment is never included, since a running
+    /// node may still be appending to it. Entries are ordered by segment and block range.
+    pub fn snapshot_entries(
+        &self,
+        segments: &[StaticFileSegment],
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<StaticFileSnapshotEntry>> {
+        let mut entries = Vec::new();
+        for (segment, ranges) in
+            iter_static_files(&self.path).map_err(|e| ProviderError::NippyJar(e.to_string()))?
+        {
+            if !segments.contains(&segment) {
+                continue
+            }
+
+            let head_range = ranges
+                .iter()
+                .map(|(range, _)| self.find_fixed_range(range.start()))
+                .max_by_key(|range| range.end());
+
+            for (range, tx_range) in ranges {
+                if range.end() < *block_range.start() || range.start() > *block_range.end() {
+                    continue
+                }
+
+                let fixed_block_range = self.find_fixed_range(range.start());
+                if Some(fixed_block_range) == head_range {
+                    continue
+                }
+
+                let files =
+                    static_file_paths(&self.path.join(segment.filename(&fixed_block_range)))?
+                        .into_iter()
+                        .map(|path| {
+                            let checksum = checksum::compute_file_checksum(&path)?;
+                            Ok(StaticFileSnapshotFile { path, checksum })
+                        })
+                        .collect::<ProviderResult<_>>()?;
+                entries.push(StaticFileSnapshotEntry {
+                    segment,
+                    block_range: range,
+                    tx_range,
+                    files,
+                });
+            }
+        }
+        entries.sort_unstable_by_key(|entry| (entry.segment, entry.block_range.start()));
+
+        Ok(entries)
+    }
+
+    /// Imports the static files of a snapshot whose files were extracted to `source`.
+    ///
+    /// The snapshot is validated before any file is moved: every file has to match its checksum,
+    /// the header of every static file has to match its entry, and each segment has to continue
+    /// right after the existing static files without gaps. The imported files are moved into the
+    /// static files directory and indexed.
+    pub fn import_snapshot(
+        &self,
+        source: &Path,
+        entries: &[StaticFileSnapshotEntry],
+    ) -> ProviderResult<()> {
+        let invalid = |msg: String| Err(ProviderError::InvalidStaticFileSnapshot(msg));
+
+        for segment in StaticFileSegment::iter() {
+            let mut next_block = self.get_highest_static_file_block(segment).map_or(0, |b| b + 1);
+            let mut next_tx = self.get_highest_static_file_tx(segment).map_or(0, |tx| tx + 1);
+
+            for entry in entries.iter().filter(|entry| entry.segment == segment) {
+                if entry.block_range.start() != next_block {
+                    return invalid(format!(
+                        "{segment} static file {} doesn't start at the next block {next_block}",
+                        entry.block_range
+                    ))
+                }
+                next_block = entry.block_range.end() + 1;
+
+                if let Some(tx_range) = entry.tx_range {
+                    if tx_range.start() != next_tx {
+                        return invalid(format!(
+                            "{segment} static file {} doesn't start at the next transaction {next_tx}",
+                            entry.block_range
+                        ))
+                    }
+                    next_tx = tx_range.end() + 1;
+                }
+
+                let fixed_block_range = self.find_fixed_range(entry.block_range.start());
+                let data_file_name = segment.filename(&fixed_block_range);
+                let data_path = source.join(&data_file_name);
+                let sidecar_prefix = format!("{data_file_name}.");
+                let mut has_data_file = false;
+                for file in &entry.files {
+                    let file_name = file.path.file_name().map(|name| name.to_string_lossy());
+                    match file_name.as_deref() {
+                        Some(name) if name == data_file_name => has_data_file = true,
+                        Some(name) if name.starts_with(&sidecar_prefix) => {}
+                        _ => {
+                            return invalid(format!(
+                                "{} doesn't belong to {segment} static file {}",
+                                file.path.display(),
+                                entry.block_range
+                            ))
+                        }
+                    }
+
+                    if !file.path.exists() {
+                        return invalid(format!("missing {}", file.path.display()))
+                    }
+                    let got = checksum::compute_file_checksum(&file.path)?;
+                    if got != file.checksum {
+                        return invalid(format!(
+                            "{} doesn't match its checksum: expected {}, got {got}",
+                            file.path.display(),
+                            file.checksum
+                        ))
+                    }
+                }
+                if !has_data_file {
+                    return invalid(format!("missing {segment} static file {}", entry.block_range))
+                }
+
+                // The static file has to hold exactly what the entry claims.
+                let jar = NippyJar::<SegmentHeader>::load(&data_path)
+                    .map_err(|e| ProviderError::NippyJar(e.to_string()))?;
+                let header = jar.user_header();
+                if header.segment() != segment ||
+                    header.expected_block_start() != fixed_block_range.start() ||
+                    header.expected_block_end() != fixed_block_range.end() ||
+                    header.block_range() != Some(&entry.block_range) ||
+                    header.tx_range() != entry.tx_range.as_ref()
+                {
+                    return invalid(format!(
+                        "{segment} static file {} doesn't match its header: block range {:?}, transaction range {:?}",
+                        entry.block_range,
+                        header.block_range(),
+                        header.tx_range()
+                    ))
+                }
+            }
+        }
+
+        for entry in entries {
+            // An empty static file may already exist for the block range, whose sidecar files
+            // must not be mixed with the imported ones.
+            let fixed_block_range = self.find_fixed_range(entry.block_range.start());
+            for path in
+                static_file_paths(&self.path.join(entry.segment.filename(&fixed_block_range)))?
+            {
+                reth_primitives::fs::remove_file(path)?;
+            }
+            self.remove_cached_provider(entry.segment, fixed_block_range.end());
+
+            for file in &entry.files {
+                let Some(file_name) = file.path.file_name() else {
+                    return invalid(format!("invalid file path {}", file.path.display()))
+                };
+                reth_primitives::fs::rename(&file.path, self.path.join(file_name))?;
+            }
+        }
+
+        self.initialize_index()
+    }
+
+    /// Copies the contents of all static files to `target`, which lays them out with its own
+    /// number of blocks per static file.
+    ///
+    /// Rewriting static files into a new directory packs them densely, e.g. to merge many small
+    /// files into fewer large ones. Static files don't store which transactions belong to which
+    /// block, so `block_body_indices` has to provide them.
+    ///
+    /// Each static file is read sequentially with a cursor over its own block and transaction
+    /// ranges. Blocks of transaction based segments that aren't in any static file, or whose
+    /// transactions aren't, are copied as empty blocks. Transactions of a block that only lie
+    /// partially in a static file can't be laid out continuously and return an error.
+    pub fn copy_to(
+        &self,
+        target: &StaticFileProvider,
+        block_body_indices: impl Fn(BlockNumber) -> ProviderResult<Option<StoredBlockBodyIndices>>,
+    ) -> ProviderResult<()> {
+        let mut static_files =
+            iter_static_files(&self.path).map_err(|e| ProviderError::NippyJar(e.to_string()))?;
+
+        for segment in StaticFileSegment::iter() {
+            let Some(ranges) = static_files.remove(&segment) else { continue };
+            let mut writer = target.latest_writer(segment)?;
+            let mut next_block =
+                target.get_highest_static_file_block(segment).map_or(0, |block| block + 1);
+
+            for (block_range, tx_range) in ranges {
+                let jar_provider = self.get_or_create_jar_provider(
+                    segment,
+                    &self.find_fixed_range(block_range.start()),
+                )?;
+                let mut cursor = jar_provider.cursor()?;
+
+                match segment {
+                    StaticFileSegment::Headers => {
+                        for block in block_range.start()..=block_range.end() {
+                            let (header, td, hash) = cursor
+                                .get_three::<HeaderMask<Header, CompactU256, BlockHash>>(
+                                    block.into(),
+                                )?
+                                .ok_or(ProviderError::MissingStaticFileBlock(segment, block))?;
+                            writer.append_header(header, td.into(), hash)?;
+                        }
+                    }
+                    StaticFileSegment::Transactions | StaticFileSegment::Receipts => {
+                        // Blocks before this static file have no data in any static file
+                        for block in next_block..block_range.start() {
+                            writer.increment_block(segment, block)?;
+                        }
+
+                        for block in block_range.start()..=block_range.end() {
+                            writer.increment_block(segment, block)?;
+
+                            let Some(tx_range) = tx_range else { continue };
+                            let indices = block_body_indices(block)?
+                                .ok_or(ProviderError::BlockBodyIndicesNotFound(block))?;
+                            let tx_nums = indices.first_tx_num().max(tx_range.start())..
+                                indices.next_tx_num().min(tx_range.end() + 1);
+
+                            for tx_num in tx_nums {
+                                // The target has no way to represent a gap in the transactions
+                                // of a static file
+                                if let Some(tx_end) = writer.user_header().tx_end() {
+                                    if tx_num != tx_end + 1 {
+                                        return Err(ProviderError::MissingStaticFileTx(
+                                            segment,
+                                            tx_end + 1,
+                                        ))
+                                    }
+                                }
+
+                                let missing =
+                                    || ProviderError::MissingStaticFileTx(segment, tx_num);
+                                if segment == StaticFileSegment::Transactions {
+                                    let tx = cursor
+                                        .get_one::<TransactionMask<TransactionSignedNoHash>>(
+                                            tx_num.into(),
+                                        )?
+                                        .ok_or_else(missing)?;
+                                    writer.append_transaction(tx_num, tx)?;
+                                } else {
+                                    let receipt = cursor
+                                        .get_one::<ReceiptMask<Receipt>>(tx_num.into())?
+                                        .ok_or_else(missing)?;
+                                    writer.append_receipt(tx_num, receipt)?;
+                                }
+                            }
+                        }
+                    }
+                }
+                next_block = block_range.end() + 1;
             }
 
-            metrics.record_segment(segment, size, ranges.len(), entries);
+            writer.commit()?;
         }
 
         Ok(())
@@ -186,7 +771,7 @@ impl StaticFileProvider {
 
     /// Gets the [`StaticFileJarProvider`] of the requested segment and block or transaction.
     ///
-    /// `fn_range` should make sure the range goes through `find_fixed_range`.
+    /// `fn_range` should make sure the range goes through [`Self::find_fixed_range`].
     pub fn get_segment_provider(
         &self,
         segment: StaticFileSegment,
@@ -252,7 +837,11 @@ impl StaticFileProvider {
             jar
         };
 
+        let truncation = self.begin_truncation();
+        TxHashFilter::delete(jar.data_path())?;
+        HashIndex::delete(segment, jar.data_path())?;
         jar.delete().map_err(|e| ProviderError::NippyJar(e.to_string()))?;
+        drop(truncation);
 
         let mut segment_max_block = None;
         if fixed_block_range.start() > 0 {
@@ -264,8 +853,10 @@ impl StaticFileProvider {
     }
 
     /// Given a segment and block range it returns a cached
-    /// [`StaticFileJarProvider`]. TODO(joshie): we should check the size and pop N if there's too
-    /// many.
+    /// [`StaticFileJarProvider`].
+    ///
+    /// If a maximum number of open jars is configured and reached, the least recently used jars
+    /// are closed before a new one is loaded.
     fn get_or_create_jar_provider(
         &self,
         segment: StaticFileSegment,
@@ -274,7 +865,9 @@ impl StaticFileProvider {
         let key = (fixed_block_range.end(), segment);
 
         // Avoid using `entry` directly to avoid a write lock in the common case.
+        let tick = self.access_tick.fetch_add(1, Ordering::Relaxed);
         let mut provider: StaticFileJarProvider<'_> = if let Some(jar) = self.map.get(&key) {
+            jar.touch(tick);
             jar.into()
         } else {
             let path = self.path.join(segment.filename(fixed_block_range));
@@ -284,7 +877,14 @@ impl StaticFileProvider {
                 jar.load_filters().map_err(|e| ProviderError::NippyJar(e.to_string()))?;
             }
 
-            self.map.entry(key).insert(LoadedJar::new(jar)?).downgrade().into()
+            self.close_least_recently_used_jars();
+            if let Some(metrics) = &self.metrics {
+                metrics.record_open_jars(self.map.len() + 1);
+            }
+
+            let jar = LoadedJar::new(jar)?;
+            jar.touch(tick);
+            self.map.entry(key).insert(jar).downgrade().into()
         };
 
         if let Some(metrics) = &self.metrics {
@@ -293,6 +893,40 @@ impl StaticFileProvider {
         Ok(provider)
     }
 
+    /// Closes the least recently used jars, so that loading another jar doesn't exceed the
+    /// configured maximum number of open jars.
+    ///
+    /// Jars that are currently in use are skipped.
+    fn close_least_recently_used_jars(&self) {
+        let Some(max_open_jars) = self.max_open_jars else { return };
+        let open_jars = self.map.len();
+        if open_jars < max_open_jars {
+            return
+        }
+
+        let mut jars =
+            self.map.iter().map(|jar| (*jar.key(), jar.last_access())).collect::<Vec<_>>();
+        jars.sort_unstable_by_key(|(_, last_access)| *last_access);
+
+        let to_close = open_jars + 1 - max_open_jars;
+        let mut closed = 0;
+        for (key, _) in jars {
+            if closed == to_close {
+                break
+            }
+            // Don't block on jars that are currently in use
+            if let Some(DashMapEntry::Occupied(entry)) = self.map.try_entry(key) {
+                entry.remove();
+                closed += 1;
+            }
+        }
+
+        trace!(target: "provider::static_file", closed, max_open_jars, "Closed least recently used static files");
+        if let Some(metrics) = &self.metrics {
+            metrics.record_closed_jars(closed);
+        }
+    }
+
     /// Gets a static file segment's block range from the provider inner block
     /// index.
     fn get_segment_ranges_from_block(
@@ -304,7 +938,7 @@ impl StaticFileProvider {
             .read()
             .get(&segment)
             .filter(|max| **max >= block)
-            .map(|_| find_fixed_range(block))
+            .map(|_| self.find_fixed_range(block))
     }
 
     /// Gets a static file segment's fixed block range from the provider inner
@@ -328,7 +962,7 @@ impl StaticFileProvider {
             }
             let tx_start = static_files_rev_iter.peek().map(|(tx_end, _)| *tx_end + 1).unwrap_or(0);
             if tx_start <= tx {
-                return Some(find_fixed_range(block_range.end()))
+                return Some(self.find_fixed_range(block_range.end()))
             }
         }
         None
@@ -352,7 +986,7 @@ impl StaticFileProvider {
             Some(segment_max_block) => {
                 // Update the max block for the segment
                 max_block.insert(segment, segment_max_block);
-                let fixed_range = find_fixed_range(segment_max_block);
+                let fixed_range = self.find_fixed_range(segment_max_block);
 
                 let jar = NippyJar::<SegmentHeader>::load(
                     &self.path.join(segment.filename(&fixed_range)),
@@ -398,8 +1032,22 @@ impl StaticFileProvider {
                     }
                 }
 
-                // Update the cached provider.
-                self.map.insert((fixed_range.end(), segment), LoadedJar::new(jar)?);
+                // Update the cached provider. The hash index of the previous provider is
+                // extended instead of being rebuilt from scratch on every commit.
+                let key = (fixed_range.end(), segment);
+                let loaded_jar = LoadedJar::new(jar)?;
+                loaded_jar.touch(self.access_tick.fetch_add(1, Ordering::Relaxed));
+                match self.map.remove(&key) {
+                    Some((_, previous)) => loaded_jar.inherit_hash_index(previous),
+                    None => {
+                        // The jar wasn't loaded, so it counts towards the open jars
+                        self.close_least_recently_used_jars();
+                        if let Some(metrics) = &self.metrics {
+                            metrics.record_open_jars(self.map.len() + 1);
+                        }
+                    }
+                }
+                self.map.insert(key, loaded_jar);
 
                 // Delete any cached provider that no longer has an associated jar.
                 self.map.retain(|(end, seg), _| !(*seg == segment && *end > fixed_range.end()));
@@ -478,14 +1126,14 @@ impl StaticFileProvider {
         func: impl Fn(StaticFileJarProvider<'_>) -> ProviderResult<Option<T>>,
     ) -> ProviderResult<Option<T>> {
         if let Some(highest_block) = self.get_highest_static_file_block(segment) {
-            let mut range = find_fixed_range(highest_block);
+            let mut range = self.find_fixed_range(highest_block);
             while range.end() > 0 {
                 if let Some(res) = func(self.get_or_create_jar_provider(segment, &range)?)? {
                     return Ok(Some(res))
                 }
                 range = SegmentRangeInclusive::new(
-                    range.start().saturating_sub(BLOCKS_PER_STATIC_FILE),
-                    range.end().saturating_sub(BLOCKS_PER_STATIC_FILE),
+                    range.start().saturating_sub(self.blocks_per_file),
+                    range.end().saturating_sub(self.blocks_per_file),
                 );
             }
         }
@@ -493,6 +1141,16 @@ impl StaticFileProvider {
         Ok(None)
     }
 
+    /// Returns the number of the block with the given hash, if it's in the static files.
+    ///
+    /// Looks the hash up in the block hash index of every headers static file, from the newest to
+    /// the oldest.
+    fn block_number_by_hash(&self, hash: &BlockHash) -> ProviderResult<Option<BlockNumber>> {
+        self.find_static_file(StaticFileSegment::Headers, |jar_provider| {
+            jar_provider.row_by_hash(hash)
+        })
+    }
+
     /// Fetches data within a specified range across multiple static files.
     ///
     /// This function iteratively retrieves data using `get_fn` for each item in the given range.
@@ -519,8 +1177,10 @@ impl StaticFileProvider {
         };
 
         let mut result = Vec::with_capacity((range.end - range.start).min(100) as usize);
+        // no point in prefetching past the end of the range
+        let read_ahead = (range.end - range.start).min(RANGE_READ_AHEAD_ROWS);
         let mut provider = get_provider(range.start)?;
-        let mut cursor = provider.cursor()?;
+        let mut cursor = provider.cursor()?.with_read_ahead(read_ahead);
 
         // advances number in range
         'outer: for number in range {
@@ -556,7 +1216,7 @@ impl StaticFileProvider {
                             return Err(err)
                         }
                         provider = get_provider(number)?;
-                        cursor = provider.cursor()?;
+                        cursor = provider.cursor()?.with_read_ahead(read_ahead);
                         retrying = true;
                     }
                 }
@@ -566,6 +1226,74 @@ impl StaticFileProvider {
         Ok(result)
     }
 
+    /// Fetches data within a specified range across multiple static files in parallel.
+    ///
+    /// Like [`Self::fetch_range_with_predicate`], but splits the range at static file boundaries
+    /// and reads each static file on the rayon pool. Results are returned in order, up to the
+    /// first item for which `predicate` returns false.
+    pub fn par_fetch_range_with_predicate<T, F, P>(
+        &self,
+        segment: StaticFileSegment,
+        range: Range<u64>,
+        get_fn: F,
+        predicate: P,
+    ) -> ProviderResult<Vec<T>>
+    where
+        T: Send,
+        F: Fn(&mut StaticFileCursor<'_>, u64) -> ProviderResult<Option<T>> + Sync,
+        P: Fn(&T) -> bool + Sync,
+    {
+        let ranges = self.split_range_at_static_files(segment, range.clone());
+        if ranges.len() < 2 {
+            return self.fetch_range_with_predicate(segment, range, get_fn, predicate)
+        }
+
+        let results = ranges
+            .into_par_iter()
+            .map(|range| {
+                let len = range.end - range.start;
+                self.fetch_range_with_predicate(segment, range, &get_fn, &predicate)
+                    .map(|items| (len, items))
+            })
+            .collect::<Vec<_>>();
+
+        let mut result = Vec::new();
+        for chunk in results {
+            let (len, items) = chunk?;
+            let stopped = (items.len() as u64) < len;
+            result.extend(items);
+            // the predicate stopped the fetch, so later static files are ignored
+            if stopped {
+                break
+            }
+        }
+        Ok(result)
+    }
+
+    /// Splits a block or transaction range of a segment into the subranges covered by each
+    /// static file.
+    fn split_range_at_static_files(
+        &self,
+        segment: StaticFileSegment,
+        range: Range<u64>,
+    ) -> Vec<Range<u64>> {
+        let tx_index = self.static_files_tx_index.read();
+        let mut ranges = Vec::new();
+        let mut start = range.start;
+        while start < range.end {
+            let static_file_end = match segment {
+                StaticFileSegment::Headers => Some(self.find_fixed_range(start).end()),
+                StaticFileSegment::Transactions | StaticFileSegment::Receipts => tx_index
+                    .get(&segment)
+                    .and_then(|index| index.range(start..).next().map(|(tx_end, _)| *tx_end)),
+            };
+            let end = static_file_end.map_or(range.end, |end| end.saturating_add(1).min(range.end));
+            ranges.push(start..end);
+            start = end;
+        }
+        ranges
+    }
+
     /// Fetches data within a specified range across multiple static files.
     ///
     /// Returns an iterator over the data
@@ -757,17 +1485,8 @@ impl StaticFileWriter for StaticFileProvider {
 
 impl HeaderProvider for StaticFileProvider {
     fn header(&self, block_hash: &BlockHash) -> ProviderResult<Option<Header>> {
-        self.find_static_file(StaticFileSegment::Headers, |jar_provider| {
-            Ok(jar_provider
-                .cursor()?
-                .get_two::<HeaderMask<Header, BlockHash>>(block_hash.into())?
-                .and_then(|(header, hash)| {
-                    if &hash == block_hash {
-                        return Some(header)
-                    }
-                    None
-                }))
-        })
+        let Some(number) = self.block_number_by_hash(block_hash)? else { return Ok(None) };
+        self.header_by_number(number)
     }
 
     fn header_by_number(&self, num: BlockNumber) -> ProviderResult<Option<Header>> {
@@ -776,12 +1495,8 @@ impl HeaderProvider for StaticFileProvider {
     }
 
     fn header_td(&self, block_hash: &BlockHash) -> ProviderResult<Option<U256>> {
-        self.find_static_file(StaticFileSegment::Headers, |jar_provider| {
-            Ok(jar_provider
-                .cursor()?
-                .get_two::<HeaderMask<CompactU256, BlockHash>>(block_hash.into())?
-                .and_then(|(td, hash)| (&hash == block_hash).then_some(td.0)))
-        })
+        let Some(number) = self.block_number_by_hash(block_hash)? else { return Ok(None) };
+        self.header_td_by_number(number)
     }
 
     fn header_td_by_number(&self, num: BlockNumber) -> ProviderResult<Option<U256>> {
@@ -790,7 +1505,7 @@ impl HeaderProvider for StaticFileProvider {
     }
 
     fn headers_range(&self, range: impl RangeBounds<BlockNumber>) -> ProviderResult<Vec<Header>> {
-        self.fetch_range_with_predicate(
+        self.par_fetch_range_with_predicate(
             StaticFileSegment::Headers,
             to_range(range),
             |cursor, number| cursor.get_one::<HeaderMask<Header>>(number.into()),
@@ -831,7 +1546,7 @@ impl BlockHashReader for StaticFileProvider {
         start: BlockNumber,
         end: BlockNumber,
     ) -> ProviderResult<Vec<B256>> {
-        self.fetch_range_with_predicate(
+        self.par_fetch_range_with_predicate(
             StaticFileSegment::Headers,
             start..end,
             |cursor, number| cursor.get_one::<HeaderMask<BlockHash>>(number.into()),
@@ -861,7 +1576,7 @@ impl ReceiptProvider for StaticFileProvider {
         &self,
         range: impl RangeBounds<TxNumber>,
     ) -> ProviderResult<Vec<Receipt>> {
-        self.fetch_range_with_predicate(
+        self.par_fetch_range_with_predicate(
             StaticFileSegment::Receipts,
             to_range(range),
             |cursor, number| cursor.get_one::<ReceiptMask<Receipt>>(number.into()),
@@ -961,11 +1676,15 @@ impl TransactionsProvider for StaticFileProvider {
 
     fn transaction_by_hash(&self, hash: TxHash) -> ProviderResult<Option<TransactionSigned>> {
         self.find_static_file(StaticFileSegment::Transactions, |jar_provider| {
-            Ok(jar_provider
-                .cursor()?
-                .get_one::<TransactionMask<TransactionSignedNoHash>>((&hash).into())?
-                .map(|tx| tx.with_hash())
-                .and_then(|tx| (tx.hash_ref() == &hash).then_some(tx)))
+            // The filter rules out most static files without touching their hash index
+            if jar_provider.tx_hash_filter().is_some_and(|filter| !filter.contains(&hash)) {
+                return Ok(None)
+            }
+
+            match jar_provider.row_by_hash(&hash)? {
+                Some(tx_num) => jar_provider.transaction_by_id(tx_num),
+                None => Ok(None),
+            }
         })
     }
 
@@ -1002,7 +1721,7 @@ impl TransactionsProvider for StaticFileProvider {
         &self,
         range: impl RangeBounds<TxNumber>,
     ) -> ProviderResult<Vec<TransactionSignedNoHash>> {
-        self.fetch_range_with_predicate(
+        self.par_fetch_range_with_predicate(
             StaticFileSegment::Transactions,
             to_range(range),
             |cursor, number| {
@@ -1044,9 +1763,8 @@ impl BlockNumReader for StaticFileProvider {
         Err(ProviderError::UnsupportedProvider)
     }
 
-    fn block_number(&self, _hash: B256) -> ProviderResult<Option<BlockNumber>> {
-        // Required data not present in static_files
-        Err(ProviderError::UnsupportedProvider)
+    fn block_number(&self, hash: B256) -> ProviderResult<Option<BlockNumber>> {
+        self.block_number_by_hash(&hash)
     }
 }
 
@@ -1154,3 +1872,77 @@ fn calculate_hash(
     tx.transaction.encode_with_signature(&tx.signature, rlp_buf, false);
     Ok((keccak256(rlp_buf), tx_id))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes headers of blocks `0..blocks` to the static file provider, committing every block.
+    fn write_headers(
+        provider: StaticFileProvider,
+        blocks: BlockNumber,
+        mut on_commit: impl FnMut(&StaticFileProvider),
+    ) -> StaticFileProvider {
+        {
+            let mut writer = provider.latest_writer(StaticFileSegment::Headers).unwrap();
+            for number in 0..blocks {
+                let header = Header { number, ..Default::default() };
+                let hash = header.hash_slow();
+                writer.append_header(header, U256::ZERO, hash).unwrap();
+                writer.commit().unwrap();
+                on_commit(&provider);
+            }
+        }
+        provider
+    }
+
+    fn open_jars(provider: &StaticFileProvider) -> Vec<BlockNumber> {
+        let mut jars = provider.map.iter().map(|jar| jar.key().0).collect::<Vec<_>>();
+        jars.sort_unstable();
+        jars
+    }
+
+    #[test]
+    fn closes_least_recently_used_jars() {
+        let dir = tempfile::tempdir().unwrap();
+        let provider =
+            StaticFileProvider::new(dir.path()).unwrap().with_blocks_per_file(10).unwrap();
+        drop(write_headers(provider, 40, |_| {}));
+
+        let provider = StaticFileProvider::new(dir.path()).unwrap().with_max_open_jars(2);
+        provider.header_by_number(5).unwrap();
+        provider.header_by_number(15).unwrap();
+        assert_eq!(open_jars(&provider), vec![9, 19]);
+
+        // the first static file was used more recently, so the second one is closed
+        provider.header_by_number(6).unwrap();
+        provider.header_by_number(25).unwrap();
+        assert_eq!(open_jars(&provider), vec![9, 29]);
+
+        provider.header_by_number(35).unwrap();
+        assert_eq!(open_jars(&provider), vec![29, 39]);
+    }
+
+    #[test]
+    fn update_index_respects_max_open_jars() {
+        let dir = tempfile::tempdir().unwrap();
+        let provider = StaticFileProvider::new(dir.path())
+            .unwrap()
+            .with_blocks_per_file(10)
+            .unwrap()
+            .with_max_open_jars(2);
+
+        let provider = write_headers(provider, 35, |provider| {
+            assert!(provider.map.len() <= 2, "{:?}", open_jars(provider));
+        });
+
+        // the jar of the last commit is the most recently used one
+        let head = provider.map.get(&(39, StaticFileSegment::Headers)).unwrap().last_access();
+        assert!(provider.map.iter().all(|jar| jar.last_access() <= head));
+        assert_ne!(head, 0);
+
+        // so reading an older static file closes the other jar, not the head one
+        provider.header_by_number(5).unwrap();
+        assert_eq!(open_jars(&provider), vec![9, 39]);
+    }
+}
@@ -14,6 +14,8 @@ pub struct StaticFileProviderMetrics {
         (StaticFileSegment, StaticFileProviderOperation),
         StaticFileProviderOperationMetrics,
     >,
+    writers: HashMap<StaticFileSegment, StaticFileWriterMetrics>,
+    jars: StaticFileJarMetrics,
 }
 
 impl Default for StaticFileProviderMetrics {
@@ -39,6 +41,15 @@ impl Default for StaticFileProviderMetrics {
                     )
                 })
                 .collect(),
+            writers: StaticFileSegment::iter()
+                .map(|segment| {
+                    (
+                        segment,
+                        StaticFileWriterMetrics::new_with_labels(&[("segment", segment.as_str())]),
+                    )
+                })
+                .collect(),
+            jars: StaticFileJarMetrics::default(),
         }
     }
 }
@@ -60,6 +71,29 @@ impl StaticFileProviderMetrics {
             .set(entries as f64);
     }
 
+    pub(crate) fn record_writer_commit(
+        &self,
+        segment: StaticFileSegment,
+        bytes_written: u64,
+        fsyncs: u64,
+        slow: bool,
+    ) {
+        let metrics = self.writers.get(&segment).expect("writer metrics should exist");
+        metrics.bytes_written_total.increment(bytes_written);
+        metrics.fsyncs_total.increment(fsyncs);
+        if slow {
+            metrics.slow_commits_total.increment(1);
+        }
+    }
+
+    pub(crate) fn record_open_jars(&self, open: usize) {
+        self.jars.open.set(open as f64);
+    }
+
+    pub(crate) fn record_closed_jars(&self, closed: usize) {
+        self.jars.closed_total.increment(closed as u64);
+    }
+
     pub(crate) fn record_segment_operation(
         &self,
         segment: StaticFileSegment,
@@ -125,3 +159,25 @@ pub(crate) struct StaticFileProviderOperationMetrics {
     /// The time it took to execute the static file jar provider operation that writes data.
     write_duration_seconds: Histogram,
 }
+
+/// Metrics for the static file writer of a segment.
+#[derive(Metrics)]
+#[metrics(scope = "static_files.writer")]
+pub(crate) struct StaticFileWriterMetrics {
+    /// Total number of bytes appended to static files, before compression
+    bytes_written_total: Counter,
+    /// Total number of files synced to disk by the writer
+    fsyncs_total: Counter,
+    /// Total number of commits that took longer than the slow commit threshold
+    slow_commits_total: Counter,
+}
+
+/// Metrics for the loaded static files.
+#[derive(Metrics)]
+#[metrics(scope = "static_files.jars")]
+pub(crate) struct StaticFileJarMetrics {
+    /// The number of static files that are currently loaded
+    open: Gauge,
+    /// Total number of static files that were closed to stay within the open files limit
+    closed_total: Counter,
+}
@@ -1,5 +1,8 @@
 mod manager;
-pub use manager::{StaticFileProvider, StaticFileWriter};
+pub use manager::{
+    StaticFileProvider, StaticFileSegmentStats, StaticFileSnapshotEntry, StaticFileSnapshotFile,
+    StaticFileWriter, DEFAULT_SLOW_COMMIT_THRESHOLD, FILE_DESCRIPTORS_PER_STATIC_FILE,
+};
 
 mod jar;
 pub use jar::StaticFileJarProvider;
@@ -9,12 +12,26 @@ pub use writer::{StaticFileProviderRW, StaticFileProviderRWRefMut};
 
 mod metrics;
 
+mod checksum;
+pub use checksum::{StaticFileChecksum, StaticFileChecksumStatus};
+
+mod tx_hash_filter;
+use tx_hash_filter::TxHashFilter;
+
+mod hash_index;
+use hash_index::HashIndex;
+
 use reth_interfaces::provider::{ProviderError, ProviderResult};
 use reth_nippy_jar::NippyJar;
-use reth_primitives::{static_file::SegmentHeader, StaticFileSegment};
-use std::{ops::Deref, sync::Arc};
-
-const BLOCKS_PER_STATIC_FILE: u64 = 500_000;
+use reth_primitives::{static_file::SegmentHeader, StaticFileSegment, B256};
+use std::{
+    ops::Deref,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, OnceLock,
+    },
+};
+use tracing::warn;
 
 /// Alias type for each specific `NippyJar`.
 type LoadedJarRef<'a> = dashmap::mapref::one::Ref<'a, (u64, StaticFileSegment), LoadedJar>;
@@ -24,6 +41,14 @@ type LoadedJarRef<'a> = dashmap::mapref::one::Ref<'a, (u64, StaticFileSegment),
 pub struct LoadedJar {
     jar: NippyJar<SegmentHeader>,
     mmap_handle: Arc<reth_nippy_jar::DataReader>,
+    /// The tick of the static file provider at which the jar was last accessed.
+    last_access: AtomicU64,
+    /// Filter of the transaction hashes in the jar, loaded on first use.
+    tx_hash_filter: OnceLock<Option<TxHashFilter>>,
+    /// Index of the block or transaction hashes in the jar, loaded or built on first use.
+    ///
+    /// Completed jars only ever hold their persisted index, which is loaded once it's written.
+    hash_index: OnceLock<Option<HashIndex>>,
 }
 
 impl LoadedJar {
@@ -31,7 +56,13 @@ impl LoadedJar {
         match jar.open_data_reader() {
             Ok(data_reader) => {
                 let mmap_handle = Arc::new(data_reader);
-                Ok(Self { jar, mmap_handle })
+                Ok(Self {
+                    jar,
+                    mmap_handle,
+                    last_access: AtomicU64::new(0),
+                    tx_hash_filter: OnceLock::new(),
+                    hash_index: OnceLock::new(),
+                })
             }
             Err(e) => Err(ProviderError::NippyJar(e.to_string())),
         }
@@ -42,9 +73,109 @@ impl LoadedJar {
         self.mmap_handle.clone()
     }
 
+    /// Records an access to the jar at the given tick.
+    fn touch(&self, tick: u64) {
+        self.last_access.store(tick, Ordering::Relaxed);
+    }
+
+    /// Returns the tick at which the jar was last accessed.
+    fn last_access(&self) -> u64 {
+        self.last_access.load(Ordering::Relaxed)
+    }
+
     fn segment(&self) -> StaticFileSegment {
         self.jar.user_header().segment()
     }
+
+    /// Returns the transaction hash filter of a transactions jar.
+    ///
+    /// Returns `None` for other segments and for jars that were written without a filter.
+    fn tx_hash_filter(&self) -> Option<&TxHashFilter> {
+        if self.segment() != StaticFileSegment::Transactions {
+            return None
+        }
+
+        self.tx_hash_filter
+            .get_or_init(|| {
+                TxHashFilter::load(self.jar.data_path()).unwrap_or_else(|err| {
+                    warn!(target: "provider::static_file", path = ?self.jar.data_path(), %err, "Failed to load transaction hash filter");
+                    None
+                })
+            })
+            .as_ref()
+    }
+
+    /// Returns the hash index of a headers or transactions jar.
+    ///
+    /// Completed jars are never indexed in memory, since that would keep all of their hashes in
+    /// memory for as long as they're loaded. Their persisted index is written in the background,
+    /// and loading it is retried until it's there.
+    ///
+    /// Returns `None` for other segments, for completed jars whose index hasn't been written yet,
+    /// and if the index can neither be loaded nor built.
+    fn hash_index(&self) -> Option<&HashIndex> {
+        if self.segment() == StaticFileSegment::Receipts {
+            return None
+        }
+        if let Some(index) = self.hash_index.get() {
+            return index.as_ref()
+        }
+
+        if self.jar.user_header().is_complete() {
+            return match HashIndex::load(self.jar.data_path(), self.jar.user_header()) {
+                Ok(None) => None,
+                Ok(Some(index)) => self.hash_index.get_or_init(|| Some(index)).as_ref(),
+                Err(err) => {
+                    warn!(target: "provider::static_file", path = ?self.jar.data_path(), %err, "Failed to load hash index");
+                    self.hash_index.get_or_init(|| None).as_ref()
+                }
+            }
+        }
+
+        self.hash_index
+            .get_or_init(|| {
+                HashIndex::build(&self.jar, self.mmap_handle())
+                    .map_err(|err| {
+                        warn!(target: "provider::static_file", path = ?self.jar.data_path(), %err, "Failed to build hash index");
+                    })
+                    .ok()
+            })
+            .as_ref()
+    }
+
+    /// Returns the row number of the given hash in a headers or transactions jar: its block
+    /// number or transaction number.
+    ///
+    /// Jars without a hash index are scanned, see [`Self::hash_index`].
+    fn row_by_hash(&self, hash: &B256) -> ProviderResult<Option<u64>> {
+        match self.hash_index() {
+            Some(index) => Ok(index.get(hash)),
+            None => HashIndex::scan(&self.jar, self.mmap_handle(), hash),
+        }
+    }
+
+    /// Carries the in-memory hash index of `previous`, an earlier version of the same jar, over
+    /// to this jar, only indexing the rows that were appended since.
+    ///
+    /// Does nothing if `previous` hasn't built an in-memory index, or if this jar is complete.
+    fn inherit_hash_index(&self, previous: Self) {
+        if self.jar.user_header().is_complete() {
+            return
+        }
+        let Some(Some(mut index @ HashIndex::InMemory { .. })) = previous.hash_index.into_inner()
+        else {
+            return
+        };
+
+        match index.extend(&self.jar, self.mmap_handle()) {
+            Ok(()) => {
+                let _ = self.hash_index.set(Some(index));
+            }
+            Err(err) => {
+                warn!(target: "provider::static_file", path = ?self.jar.data_path(), %err, "Failed to extend hash index");
+            }
+        }
+    }
 }
 
 impl Deref for LoadedJar {
@@ -57,16 +188,27 @@ impl Deref for LoadedJar {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{test_utils::create_test_provider_factory, HeaderProvider};
+    use crate::{
+        test_utils::create_test_provider_factory, BlockNumReader, HeaderProvider, ReceiptProvider,
+        TransactionsProvider,
+    };
     use rand::seq::SliceRandom;
     use reth_db::{
         cursor::DbCursorRO,
-        static_file::create_static_file_T1_T2_T3,
+        models::StoredBlockBodyIndices,
+        static_file::{create_static_file_T1_T2_T3, HeaderMask, StaticFileCursor},
         transaction::{DbTx, DbTxMut},
         CanonicalHeaders, HeaderNumbers, HeaderTerminalDifficulties, Headers, RawTable,
     };
-    use reth_interfaces::test_utils::generators::{self, random_header_range};
-    use reth_primitives::{static_file::find_fixed_range, BlockNumber, B256, U256};
+    use reth_interfaces::{
+        provider::ProviderError,
+        test_utils::generators::{self, random_header_range, random_receipt, random_signed_tx},
+    };
+    use reth_primitives::{
+        static_file::{find_fixed_range, SegmentRangeInclusive, BLOCKS_PER_STATIC_FILE},
+        BlockNumber, Header, TransactionSignedNoHash, B256, U256,
+    };
+    use std::ops::Range;
 
     #[test]
     fn test_snap() {
@@ -83,9 +225,10 @@ mod tests {
         // Data sources
         let factory = create_test_provider_factory();
         let static_files_path = tempfile::tempdir().unwrap();
-        let static_file = static_files_path
-            .path()
-            .join(StaticFileSegment::Headers.filename(&find_fixed_range(*range.end())));
+        let static_file = static_files_path.path().join(
+            StaticFileSegment::Headers
+                .filename(&find_fixed_range(*range.end(), BLOCKS_PER_STATIC_FILE)),
+        );
 
         // Setup data
         let mut headers = random_header_range(
@@ -176,4 +319,463 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_blocks_per_file() {
+        let factory = create_test_provider_factory().with_static_files_blocks_per_file(10).unwrap();
+        let static_file_provider = factory.static_file_provider();
+        {
+            let mut writer =
+                static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+            for number in 0..15 {
+                let header = Header { number, ..Default::default() };
+                let hash = header.hash_slow();
+                writer.append_header(header, U256::ZERO, hash).unwrap();
+            }
+            writer.commit().unwrap();
+        }
+        for range in [0..=9, 10..=19] {
+            let fixed_range = static_file_provider.find_fixed_range(*range.start());
+            assert_eq!(fixed_range, range.into());
+            assert!(static_file_provider
+                .directory()
+                .join(StaticFileSegment::Headers.filename(&fixed_range))
+                .exists());
+        }
+        assert_eq!(
+            static_file_provider.header_by_number(12).unwrap().map(|header| header.number),
+            Some(12)
+        );
+
+        // hashes are found through the index of the completed static file, which is persisted in
+        // the background, and the in-memory index of the incomplete one
+        let index_path = static_file_provider
+            .directory()
+            .join(StaticFileSegment::Headers.filename(&(0..=9).into()))
+            .with_extension("bhi");
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        while !index_path.exists() && std::time::Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert!(index_path.exists());
+        for number in [3, 12] {
+            let hash = Header { number, ..Default::default() }.hash_slow();
+            assert_eq!(static_file_provider.block_number(hash).unwrap(), Some(number));
+            assert_eq!(
+                static_file_provider.header(&hash).unwrap().map(|header| header.number),
+                Some(number)
+            );
+        }
+        assert_eq!(static_file_provider.block_number(B256::random()).unwrap(), None);
+
+        let stats = static_file_provider.segment_stats().unwrap();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(
+            (
+                stats[0].segment,
+                stats[0].files,
+                stats[0].rows,
+                stats[0].block_range,
+                stats[0].tx_range
+            ),
+            (StaticFileSegment::Headers, 2, 15, SegmentRangeInclusive::new(0, 14), None)
+        );
+        assert!(stats[0].size > 0);
+
+        // the block count of existing static files is detected and can't be changed
+        let directory = static_file_provider.directory().to_path_buf();
+        drop((factory, static_file_provider));
+        let static_file_provider = StaticFileProvider::new(&directory).unwrap();
+        assert_eq!(static_file_provider.blocks_per_file(), 10);
+        assert_eq!(
+            static_file_provider.with_blocks_per_file(BLOCKS_PER_STATIC_FILE).unwrap_err(),
+            ProviderError::StaticFileBlocksPerFileMismatch {
+                configured: BLOCKS_PER_STATIC_FILE,
+                on_disk: 10
+            }
+        );
+    }
+
+    #[test]
+    fn test_incremental_block_hash_index() {
+        let factory = create_test_provider_factory().with_static_files_blocks_per_file(10).unwrap();
+        let static_file_provider = factory.static_file_provider();
+        let hash = |number| Header { number, ..Default::default() }.hash_slow();
+        let mut writer = static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+
+        let mut append = |range: Range<u64>| {
+            for number in range {
+                let header = Header { number, ..Default::default() };
+                writer.append_header(header, U256::ZERO, hash(number)).unwrap();
+            }
+            writer.commit().unwrap();
+        };
+
+        // the in-memory index of the head file is built on first use, and extended on every commit
+        append(0..3);
+        assert_eq!(static_file_provider.block_number(hash(2)).unwrap(), Some(2));
+        append(3..6);
+        for number in 0..6 {
+            assert_eq!(static_file_provider.block_number(hash(number)).unwrap(), Some(number));
+        }
+        let jar = static_file_provider.get_segment_provider_from_block(
+            StaticFileSegment::Headers,
+            0,
+            None,
+        );
+        assert!(matches!(
+            jar.unwrap().hash_index(),
+            Some(HashIndex::InMemory { entries, row_end: Some(5) }) if entries.len() == 6
+        ));
+        drop(append);
+
+        // truncated blocks are removed from the index
+        writer.prune_headers(2).unwrap();
+        writer.commit().unwrap();
+        assert_eq!(static_file_provider.block_number(hash(3)).unwrap(), Some(3));
+        for number in 4..6 {
+            assert_eq!(static_file_provider.block_number(hash(number)).unwrap(), None);
+        }
+
+        // blocks appended again at the truncated heights are indexed
+        let header = Header { number: 4, gas_limit: 1, ..Default::default() };
+        let rewritten = header.hash_slow();
+        writer.append_header(header, U256::ZERO, rewritten).unwrap();
+        writer.commit().unwrap();
+        assert_eq!(static_file_provider.block_number(rewritten).unwrap(), Some(4));
+        assert_eq!(static_file_provider.block_number(hash(4)).unwrap(), None);
+    }
+
+    #[test]
+    fn test_completed_static_file_hash_index_backfill() {
+        let factory = create_test_provider_factory().with_static_files_blocks_per_file(10).unwrap();
+        let static_file_provider = factory.static_file_provider();
+        let hash = |number| Header { number, ..Default::default() }.hash_slow();
+        let mut writer = static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+        for number in 0..15 {
+            let header = Header { number, ..Default::default() };
+            writer.append_header(header, U256::ZERO, hash(number)).unwrap();
+        }
+        writer.commit().unwrap();
+        drop(writer);
+
+        // a static file completed before checksums and indexes were written
+        let data_path = static_file_provider
+            .directory()
+            .join(StaticFileSegment::Headers.filename(&(0..=9).into()));
+        let index_path = data_path.with_extension("bhi");
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        while !index_path.exists() && std::time::Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        HashIndex::delete(StaticFileSegment::Headers, &data_path).unwrap();
+        let mut jar = NippyJar::<SegmentHeader>::load(&data_path).unwrap();
+        let checksum = jar.user_header().checksum();
+        assert!(checksum.is_some());
+        jar.user_header_mut().set_checksum(None);
+        jar.freeze_config().unwrap();
+
+        // it's scanned without being indexed in memory
+        let static_file_provider = StaticFileProvider::new(static_file_provider.directory())
+            .unwrap()
+            .with_blocks_per_file(10)
+            .unwrap();
+        assert_eq!(static_file_provider.block_number(hash(3)).unwrap(), Some(3));
+        assert_eq!(static_file_provider.block_number(hash(12)).unwrap(), Some(12));
+        assert_eq!(static_file_provider.block_number(B256::random()).unwrap(), None);
+        let jar = |block| {
+            static_file_provider.get_segment_provider_from_block(
+                StaticFileSegment::Headers,
+                block,
+                None,
+            )
+        };
+        assert!(jar(3).unwrap().hash_index().is_none());
+
+        // its checksum is backfilled before it's indexed
+        static_file_provider.write_missing_hash_indexes(StaticFileSegment::Headers).unwrap();
+        assert_eq!(
+            NippyJar::<SegmentHeader>::load(&data_path).unwrap().user_header().checksum(),
+            checksum
+        );
+        assert!(matches!(jar(3).unwrap().hash_index(), Some(HashIndex::Persisted(_))));
+        assert_eq!(static_file_provider.block_number(hash(3)).unwrap(), Some(3));
+    }
+
+    #[test]
+    fn test_transaction_by_hash() {
+        let factory = create_test_provider_factory().with_static_files_blocks_per_file(10).unwrap();
+        let static_file_provider = factory.static_file_provider();
+        let rng = &mut generators::rng();
+        let transactions = (0..30)
+            .map(|_| TransactionSignedNoHash::from(random_signed_tx(rng)).with_hash())
+            .collect::<Vec<_>>();
+
+        let mut writer =
+            static_file_provider.latest_writer(StaticFileSegment::Transactions).unwrap();
+        for block in 0..15 {
+            writer.increment_block(StaticFileSegment::Transactions, block).unwrap();
+            for tx_num in block * 2..(block + 1) * 2 {
+                let tx = transactions[tx_num as usize].clone();
+                writer.append_transaction(tx_num, tx.into()).unwrap();
+            }
+        }
+        writer.commit().unwrap();
+
+        // the pruned transaction stays in the filter of the head file, so looking it up is a
+        // false positive of the filter, which the hash index resolves
+        writer.prune_transactions(2, 14).unwrap();
+        drop(writer);
+        let pruned = transactions[29].hash();
+        assert!(static_file_provider
+            .get_segment_provider_from_transaction(StaticFileSegment::Transactions, 20, None)
+            .unwrap()
+            .tx_hash_filter()
+            .unwrap()
+            .contains(&pruned));
+        assert_eq!(static_file_provider.transaction_by_hash(pruned).unwrap(), None);
+        for tx in &transactions[..28] {
+            assert_eq!(
+                static_file_provider.transaction_by_hash(tx.hash()).unwrap().as_ref(),
+                Some(tx)
+            );
+        }
+
+        // the completed static file is looked up through its persisted index
+        let index_path = static_file_provider
+            .directory()
+            .join(StaticFileSegment::Transactions.filename(&(0..=9).into()))
+            .with_extension("txi");
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        while !index_path.exists() && std::time::Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        let static_file_provider = StaticFileProvider::new(static_file_provider.directory())
+            .unwrap()
+            .with_blocks_per_file(10)
+            .unwrap();
+        assert_eq!(
+            static_file_provider.transaction_by_hash(transactions[5].hash()).unwrap().as_ref(),
+            Some(&transactions[5])
+        );
+        assert!(matches!(
+            static_file_provider
+                .get_segment_provider_from_transaction(StaticFileSegment::Transactions, 5, None)
+                .unwrap()
+                .hash_index(),
+            Some(HashIndex::Persisted(_))
+        ));
+    }
+
+    #[test]
+    fn test_par_fetch_range() {
+        let factory = create_test_provider_factory().with_static_files_blocks_per_file(10).unwrap();
+        let static_file_provider = factory.static_file_provider();
+        {
+            let mut writer =
+                static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+            for number in 0..35 {
+                let header = Header { number, ..Default::default() };
+                let hash = header.hash_slow();
+                writer.append_header(header, U256::ZERO, hash).unwrap();
+            }
+            writer.commit().unwrap();
+        }
+
+        let fetch = |range: Range<u64>, predicate: &(dyn Fn(&Header) -> bool + Sync)| {
+            let get_fn = |cursor: &mut StaticFileCursor<'_>, number: u64| {
+                cursor.get_one::<HeaderMask<Header>>(number.into())
+            };
+            let serial = static_file_provider
+                .fetch_range_with_predicate(
+                    StaticFileSegment::Headers,
+                    range.clone(),
+                    get_fn,
+                    predicate,
+                )
+                .unwrap();
+            let parallel = static_file_provider
+                .par_fetch_range_with_predicate(
+                    StaticFileSegment::Headers,
+                    range,
+                    get_fn,
+                    predicate,
+                )
+                .unwrap();
+            assert_eq!(serial, parallel);
+            parallel.into_iter().map(|header| header.number).collect::<Vec<_>>()
+        };
+
+        assert_eq!(fetch(3..35, &|_| true), (3..35).collect::<Vec<_>>());
+        assert_eq!(fetch(5..8, &|_| true), (5..8).collect::<Vec<_>>());
+        // the predicate stops the fetch within the third static file
+        assert_eq!(fetch(0..35, &|header| header.number != 23), (0..23).collect::<Vec<_>>());
+        assert!(static_file_provider
+            .par_fetch_range_with_predicate(
+                StaticFileSegment::Headers,
+                0..40,
+                |cursor, number| cursor.get_one::<HeaderMask<Header>>(number.into()),
+                |_| true,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_copy_to() {
+        let factory = create_test_provider_factory().with_static_files_blocks_per_file(10).unwrap();
+        let static_file_provider = factory.static_file_provider();
+        {
+            let mut writer =
+                static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+            for number in 0..25 {
+                let header = Header { number, ..Default::default() };
+                let hash = header.hash_slow();
+                writer.append_header(header, U256::from(number), hash).unwrap();
+            }
+            writer.commit().unwrap();
+        }
+
+        let target_path = tempfile::tempdir().unwrap();
+        let target =
+            StaticFileProvider::new(target_path.path()).unwrap().with_blocks_per_file(100).unwrap();
+        static_file_provider.copy_to(&target, |_| unreachable!()).unwrap();
+
+        let static_files = reth_db::static_file::iter_static_files(target_path.path()).unwrap();
+        assert_eq!(static_files[&StaticFileSegment::Headers].len(), 1);
+        assert_eq!(
+            target.headers_range(0..25).unwrap(),
+            static_file_provider.headers_range(0..25).unwrap()
+        );
+        assert_eq!(target.header_td_by_number(24).unwrap(), Some(U256::from(24)));
+    }
+
+    #[test]
+    fn test_copy_to_transactions_and_receipts() {
+        let factory = create_test_provider_factory().with_static_files_blocks_per_file(10).unwrap();
+        let static_file_provider = factory.static_file_provider();
+        let rng = &mut generators::rng();
+        let txs_per_block = 2;
+        let transactions = (0..25 * txs_per_block)
+            .map(|_| TransactionSignedNoHash::from(random_signed_tx(rng)))
+            .collect::<Vec<_>>();
+        let receipts = transactions
+            .iter()
+            .map(|tx| random_receipt(rng, &tx.clone().with_hash(), Some(0)))
+            .collect::<Vec<_>>();
+        let block_body_indices = |block: BlockNumber| {
+            Ok(Some(StoredBlockBodyIndices {
+                first_tx_num: block * txs_per_block,
+                tx_count: txs_per_block,
+            }))
+        };
+
+        {
+            let mut txs_writer =
+                static_file_provider.latest_writer(StaticFileSegment::Transactions).unwrap();
+            let mut receipts_writer =
+                static_file_provider.latest_writer(StaticFileSegment::Receipts).unwrap();
+            for block in 0..25 {
+                txs_writer.increment_block(StaticFileSegment::Transactions, block).unwrap();
+                receipts_writer.increment_block(StaticFileSegment::Receipts, block).unwrap();
+                for tx_num in block * txs_per_block..(block + 1) * txs_per_block {
+                    txs_writer
+                        .append_transaction(tx_num, transactions[tx_num as usize].clone())
+                        .unwrap();
+                    // The first static file of receipts has blocks, but no receipts
+                    if block >= 10 {
+                        receipts_writer
+                            .append_receipt(tx_num, receipts[tx_num as usize].clone())
+                            .unwrap();
+                    }
+                }
+            }
+            txs_writer.commit().unwrap();
+            receipts_writer.commit().unwrap();
+        }
+
+        let target_path = tempfile::tempdir().unwrap();
+        let target =
+            StaticFileProvider::new(target_path.path()).unwrap().with_blocks_per_file(100).unwrap();
+        static_file_provider.copy_to(&target, block_body_indices).unwrap();
+
+        let static_files = reth_db::static_file::iter_static_files(target_path.path()).unwrap();
+        assert_eq!(static_files[&StaticFileSegment::Transactions].len(), 1);
+        assert_eq!(static_files[&StaticFileSegment::Receipts].len(), 1);
+        assert_eq!(
+            static_files[&StaticFileSegment::Transactions][0],
+            (SegmentRangeInclusive::new(0, 24), Some(SegmentRangeInclusive::new(0, 49)))
+        );
+        assert_eq!(
+            static_files[&StaticFileSegment::Receipts][0],
+            (SegmentRangeInclusive::new(0, 24), Some(SegmentRangeInclusive::new(20, 49)))
+        );
+
+        assert_eq!(target.transactions_by_tx_range(0..50).unwrap(), transactions);
+        assert_eq!(target.receipts_by_tx_range(20..50).unwrap(), receipts[20..]);
+    }
+
+    #[test]
+    fn test_import_snapshot() {
+        let factory = create_test_provider_factory().with_static_files_blocks_per_file(10).unwrap();
+        let static_file_provider = factory.static_file_provider();
+        {
+            let mut writer =
+                static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+            for number in 0..25 {
+                let header = Header { number, ..Default::default() };
+                let hash = header.hash_slow();
+                writer.append_header(header, U256::from(number), hash).unwrap();
+            }
+            writer.commit().unwrap();
+        }
+
+        // the head file 20..=29 is never exported
+        let entries =
+            static_file_provider.snapshot_entries(&[StaticFileSegment::Headers], 5..=25).unwrap();
+        assert_eq!(
+            entries.iter().map(|entry| entry.block_range).collect::<Vec<_>>(),
+            vec![SegmentRangeInclusive::new(0, 9), SegmentRangeInclusive::new(10, 19)]
+        );
+
+        // Simulates extracting the snapshot
+        let source = tempfile::tempdir().unwrap();
+        let entries = entries
+            .into_iter()
+            .map(|mut entry| {
+                for file in &mut entry.files {
+                    let extracted = source.path().join(file.path.file_name().unwrap());
+                    std::fs::copy(&file.path, &extracted).unwrap();
+                    file.path = extracted;
+                }
+                entry
+            })
+            .collect::<Vec<_>>();
+
+        let target_path = tempfile::tempdir().unwrap();
+        let target =
+            StaticFileProvider::new(target_path.path()).unwrap().with_blocks_per_file(10).unwrap();
+
+        // Gaps, corrupted files, missing data files and headers that don't match their entry are
+        // rejected
+        assert!(target.import_snapshot(source.path(), &entries[1..]).is_err());
+        for file in 0..entries[0].files.len() {
+            let mut corrupted = entries.clone();
+            corrupted[0].files[file].checksum = B256::ZERO;
+            assert!(target.import_snapshot(source.path(), &corrupted).is_err());
+        }
+        let mut without_data = entries.clone();
+        without_data[0].files.retain(|file| file.path.extension().is_some());
+        assert!(target.import_snapshot(source.path(), &without_data).is_err());
+        let mut mismatched = entries.clone();
+        mismatched[0].tx_range = Some(SegmentRangeInclusive::new(0, 9));
+        assert!(target.import_snapshot(source.path(), &mismatched).is_err());
+        assert_eq!(target.get_highest_static_file_block(StaticFileSegment::Headers), None);
+
+        target.import_snapshot(source.path(), &entries).unwrap();
+        assert_eq!(target.get_highest_static_file_block(StaticFileSegment::Headers), Some(19));
+        assert_eq!(
+            target.headers_range(0..20).unwrap(),
+            static_file_provider.headers_range(0..20).unwrap()
+        );
+    }
 }
@@ -0,0 +1,271 @@
+use reth_interfaces::provider::ProviderResult;
+use reth_primitives::{fs::FsPathError, TxHash};
+use std::{
+    fs::OpenOptions,
+    io::{Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+/// Extension of the file holding the transaction hash filter of a static file.
+const TX_HASH_FILTER_FILE_EXTENSION: &str = "txf";
+
+/// Number of transaction hashes the first block of a [`TxHashFilter`] is sized for. Every
+/// following block doubles the capacity of the previous one, up to [`MAX_BLOCK_CAPACITY`].
+const INITIAL_BLOCK_CAPACITY: u64 = 1 << 16;
+
+/// Maximum number of transaction hashes of a single block of a [`TxHashFilter`]. Bounds the size
+/// of the block that is rewritten on every commit.
+const MAX_BLOCK_CAPACITY: u64 = 1 << 22;
+
+/// Number of filter bits per transaction hash.
+const BITS_PER_ENTRY: u64 = 16;
+
+/// Number of bits set per transaction hash.
+const NUM_HASHES: u64 = 11;
+
+/// Returns the path of the transaction hash filter of the static file at `data_path`.
+pub(crate) fn tx_hash_filter_path(data_path: &Path) -> PathBuf {
+    data_path.with_extension(TX_HASH_FILTER_FILE_EXTENSION)
+}
+
+/// A growable bloom filter of the transaction hashes stored in a transactions static file.
+///
+/// The filter is made of blocks with growing capacity, so that it doesn't need to be sized
+/// upfront. Only the last block receives new hashes, which allows persisting the filter by
+/// rewriting only the blocks that changed since the previous commit.
+///
+/// Rows that are pruned from the static file are not removed from the filter, which only results
+/// in additional false positives.
+#[derive(Debug, Default)]
+pub struct TxHashFilter {
+    blocks: Vec<FilterBlock>,
+    /// Number of leading blocks that are full and already persisted to disk.
+    persisted_blocks: usize,
+    /// Whether hashes were inserted since the filter was last persisted.
+    dirty: bool,
+}
+
+impl TxHashFilter {
+    /// Adds a transaction hash to the filter.
+    pub fn insert(&mut self, hash: &TxHash) {
+        if self.blocks.last().map_or(true, FilterBlock::is_full) {
+            let capacity = self
+                .blocks
+                .last()
+                .map_or(INITIAL_BLOCK_CAPACITY, |block| block.capacity * 2)
+                .min(MAX_BLOCK_CAPACITY);
+            self.blocks.push(FilterBlock::new(capacity));
+        }
+        self.blocks.last_mut().expect("qed").insert(hash);
+        self.dirty = true;
+    }
+
+    /// Returns `false` if the transaction hash is definitely not in the filter.
+    pub fn contains(&self, hash: &TxHash) -> bool {
+        self.blocks.iter().any(|block| block.contains(hash))
+    }
+
+    /// Loads the filter of the static file at `data_path`.
+    ///
+    /// Returns `None` if the static file has no filter, or if it's incomplete because writing it
+    /// was interrupted or corrupted. Block capacities are checked before allocating the blocks.
+    pub fn load(data_path: &Path) -> ProviderResult<Option<Self>> {
+        let path = tx_hash_filter_path(data_path);
+        if !path.exists() {
+            return Ok(None)
+        }
+
+        let data = reth_primitives::fs::read(&path)?;
+        if data.len() % 8 != 0 {
+            return Ok(None)
+        }
+        let mut words =
+            data.chunks_exact(8).map(|chunk| u64::from_le_bytes(chunk.try_into().expect("qed")));
+        let mut blocks = Vec::new();
+        while let Some(capacity) = words.next() {
+            let Some(len) = words.next() else { return Ok(None) };
+            if capacity == 0 ||
+                capacity > MAX_BLOCK_CAPACITY ||
+                (words.len() as u64) < FilterBlock::words(capacity)
+            {
+                return Ok(None)
+            }
+
+            let mut block = FilterBlock::new(capacity);
+            block.bits.iter_mut().zip(&mut words).for_each(|(word, value)| *word = value);
+            block.len = len;
+            blocks.push(block);
+        }
+
+        let persisted_blocks = blocks.len().saturating_sub(1);
+        Ok(Some(Self { blocks, persisted_blocks, dirty: false }))
+    }
+
+    /// Writes the filter next to the static file at `data_path`.
+    ///
+    /// Only the blocks that changed since the last call are written.
+    pub fn persist(&mut self, data_path: &Path) -> ProviderResult<()> {
+        if !self.dirty {
+            return Ok(())
+        }
+
+        let path = tx_hash_filter_path(data_path);
+        let offset = self.blocks[..self.persisted_blocks].iter().map(FilterBlock::size).sum();
+
+        let mut buf = Vec::new();
+        for block in &self.blocks[self.persisted_blocks..] {
+            block.encode(&mut buf);
+        }
+
+        let write = |path: &Path| -> std::io::Result<()> {
+            let mut file = OpenOptions::new().create(true).write(true).open(path)?;
+            file.set_len(offset)?;
+            file.seek(SeekFrom::Start(offset))?;
+            file.write_all(&buf)?;
+            file.sync_all()
+        };
+        write(&path).map_err(|err| FsPathError::write(err, &path))?;
+
+        self.persisted_blocks = self.blocks.len().saturating_sub(1);
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Deletes the filter of the static file at `data_path`, if there's any.
+    pub fn delete(data_path: &Path) -> ProviderResult<()> {
+        let path = tx_hash_filter_path(data_path);
+        if path.exists() {
+            reth_primitives::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+}
+
+/// A fixed-size bloom filter.
+#[derive(Debug)]
+struct FilterBlock {
+    /// Number of hashes the block is sized for.
+    capacity: u64,
+    /// Number of hashes inserted in the block.
+    len: u64,
+    bits: Vec<u64>,
+}
+
+impl FilterBlock {
+    fn new(capacity: u64) -> Self {
+        Self { capacity, len: 0, bits: vec![0; Self::words(capacity) as usize] }
+    }
+
+    /// Number of words of the bits of a block with the given capacity.
+    fn words(capacity: u64) -> u64 {
+        (capacity * BITS_PER_ENTRY).div_ceil(64).max(1)
+    }
+
+    fn is_full(&self) -> bool {
+        self.len >= self.capacity
+    }
+
+    /// Returns the bit positions of the hash.
+    ///
+    /// Transaction hashes are uniformly distributed, so their bytes are used directly as the two
+    /// base hashes of the double hashing scheme.
+    fn positions(&self, hash: &TxHash) -> impl Iterator<Item = u64> {
+        let num_bits = self.bits.len() as u64 * 64;
+        let h1 = u64::from_le_bytes(hash[..8].try_into().expect("qed"));
+        let h2 = u64::from_le_bytes(hash[8..16].try_into().expect("qed")) | 1;
+        (0..NUM_HASHES).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % num_bits)
+    }
+
+    fn insert(&mut self, hash: &TxHash) {
+        for position in self.positions(hash).collect::<Vec<_>>() {
+            self.bits[(position / 64) as usize] |= 1 << (position % 64);
+        }
+        self.len += 1;
+    }
+
+    fn contains(&self, hash: &TxHash) -> bool {
+        self.len > 0 &&
+            self.positions(hash).all(|position| {
+                self.bits[(position / 64) as usize] & (1 << (position % 64)) != 0
+            })
+    }
+
+    /// Size of the encoded block in bytes.
+    fn size(&self) -> u64 {
+        16 + self.bits.len() as u64 * 8
+    }
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.capacity.to_le_bytes());
+        buf.extend_from_slice(&self.len.to_le_bytes());
+        for word in &self.bits {
+            buf.extend_from_slice(&word.to_le_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn persists_growing_filter() {
+        let dir = tempfile::tempdir().unwrap();
+        let data_path = dir.path().join("static_file_transactions_0_499999");
+
+        let hashes = (0..INITIAL_BLOCK_CAPACITY * 2).map(|_| TxHash::random()).collect::<Vec<_>>();
+        let (first, second) = hashes.split_at(INITIAL_BLOCK_CAPACITY as usize + 1);
+
+        let mut filter = TxHashFilter::default();
+        first.iter().for_each(|hash| filter.insert(hash));
+        filter.persist(&data_path).unwrap();
+        assert_eq!(filter.blocks.len(), 2);
+        assert_eq!(filter.persisted_blocks, 1);
+
+        // only the last block is rewritten
+        second.iter().for_each(|hash| filter.insert(hash));
+        filter.persist(&data_path).unwrap();
+
+        let loaded = TxHashFilter::load(&data_path).unwrap().unwrap();
+        assert_eq!(loaded.blocks.len(), 2);
+        assert!(hashes.iter().all(|hash| loaded.contains(hash)));
+
+        let false_positives = (0..10_000).filter(|_| loaded.contains(&TxHash::random())).count();
+        assert!(false_positives < 200, "{false_positives}");
+
+        TxHashFilter::delete(&data_path).unwrap();
+        assert!(TxHashFilter::load(&data_path).unwrap().is_none());
+    }
+
+    #[test]
+    fn rejects_corrupted_filter() {
+        let dir = tempfile::tempdir().unwrap();
+        let data_path = dir.path().join("static_file_transactions_0_499999");
+        let path = tx_hash_filter_path(&data_path);
+
+        let mut filter = TxHashFilter::default();
+        filter.insert(&TxHash::random());
+        let mut buf = Vec::new();
+        filter.blocks[0].encode(&mut buf);
+        let with_header = |capacity: u64| {
+            let mut buf = buf.clone();
+            buf[..8].copy_from_slice(&capacity.to_le_bytes());
+            buf
+        };
+
+        reth_primitives::fs::write(&path, &buf).unwrap();
+        assert!(TxHashFilter::load(&data_path).unwrap().is_some());
+
+        // capacities that would allocate nothing, or far more than the file holds
+        for capacity in [0, MAX_BLOCK_CAPACITY + 1, u64::MAX] {
+            reth_primitives::fs::write(&path, with_header(capacity)).unwrap();
+            assert!(TxHashFilter::load(&data_path).unwrap().is_none(), "{capacity}");
+        }
+
+        // a valid capacity whose bits were cut off
+        reth_primitives::fs::write(&path, with_header(MAX_BLOCK_CAPACITY)).unwrap();
+        assert!(TxHashFilter::load(&data_path).unwrap().is_none());
+        reth_primitives::fs::write(&path, &buf[..buf.len() - 8]).unwrap();
+        assert!(TxHashFilter::load(&data_path).unwrap().is_none());
+    }
+}
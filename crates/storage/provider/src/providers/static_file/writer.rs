@@ -1,15 +1,16 @@
 use crate::providers::static_file::metrics::StaticFileProviderOperation;
 
 use super::{
-    manager::StaticFileProviderInner, metrics::StaticFileProviderMetrics, StaticFileProvider,
+    manager::StaticFileProviderInner, metrics::StaticFileProviderMetrics, HashIndex,
+    StaticFileJarProvider, StaticFileProvider, TxHashFilter,
 };
 use dashmap::mapref::one::RefMut;
 use reth_codecs::Compact;
-use reth_db::codecs::CompactU256;
+use reth_db::{codecs::CompactU256, static_file::TransactionMask};
 use reth_interfaces::provider::{ProviderError, ProviderResult};
 use reth_nippy_jar::{NippyJar, NippyJarError, NippyJarWriter};
 use reth_primitives::{
-    static_file::{find_fixed_range, SegmentHeader, SegmentRangeInclusive},
+    static_file::{SegmentHeader, SegmentRangeInclusive},
     BlockHash, BlockNumber, Header, Receipt, StaticFileSegment, TransactionSignedNoHash, TxNumber,
     U256,
 };
@@ -18,7 +19,7 @@ use std::{
     sync::{Arc, Weak},
     time::Instant,
 };
-use tracing::debug;
+use tracing::{debug, warn};
 
 /// Mutable reference to a dashmap element of [`StaticFileProviderRW`].
 pub type StaticFileProviderRWRefMut<'a> = RefMut<'a, StaticFileSegment, StaticFileProviderRW>;
@@ -33,6 +34,13 @@ pub struct StaticFileProviderRW {
     writer: NippyJarWriter<SegmentHeader>,
     data_path: PathBuf,
     buf: Vec<u8>,
+    /// Filter of the transaction hashes written to the current static file. Only set for the
+    /// transactions segment.
+    tx_hash_filter: Option<TxHashFilter>,
+    /// Number of rows appended since the last commit.
+    uncommitted_rows: u64,
+    /// Number of bytes appended since the last commit.
+    uncommitted_bytes: u64,
     metrics: Option<Arc<StaticFileProviderMetrics>>,
 }
 
@@ -44,8 +52,30 @@ impl StaticFileProviderRW {
         reader: Weak<StaticFileProviderInner>,
         metrics: Option<Arc<StaticFileProviderMetrics>>,
     ) -> ProviderResult<Self> {
-        let (writer, data_path) = Self::open(segment, block, reader.clone(), metrics.clone())?;
-        Ok(Self { writer, data_path, buf: Vec::with_capacity(100), reader, metrics })
+        let (writer, data_path, tx_hash_filter) =
+            Self::open(segment, block, reader.clone(), metrics.clone())?;
+
+        // Completed static files may be missing their hash index, e.g. if they were completed
+        // before indexes were written, or if the node stopped before writing it.
+        if segment != StaticFileSegment::Receipts {
+            let provider = Self::upgrade_provider_to_strong_reference(&reader);
+            rayon::spawn(move || {
+                if let Err(err) = provider.write_missing_hash_indexes(segment) {
+                    warn!(target: "provider::static_file", ?segment, %err, "Failed to write missing hash indexes");
+                }
+            });
+        }
+
+        Ok(Self {
+            writer,
+            data_path,
+            buf: Vec::with_capacity(100),
+            tx_hash_filter,
+            uncommitted_rows: 0,
+            uncommitted_bytes: 0,
+            reader,
+            metrics,
+        })
     }
 
     fn open(
@@ -53,31 +83,36 @@ impl StaticFileProviderRW {
         block: u64,
         reader: Weak<StaticFileProviderInner>,
         metrics: Option<Arc<StaticFileProviderMetrics>>,
-    ) -> ProviderResult<(NippyJarWriter<SegmentHeader>, PathBuf)> {
+    ) -> ProviderResult<(NippyJarWriter<SegmentHeader>, PathBuf, Option<TxHashFilter>)> {
         let start = Instant::now();
 
         let static_file_provider = Self::upgrade_provider_to_strong_reference(&reader);
 
-        let block_range = find_fixed_range(block);
-        let (jar, path) = match static_file_provider.get_segment_provider_from_block(
-            segment,
-            block_range.start(),
-            None,
-        ) {
+        let block_range = static_file_provider.find_fixed_range(block);
+        let (jar, path, tx_hash_filter) = match static_file_provider
+            .get_segment_provider_from_block(segment, block_range.start(), None)
+        {
             Ok(provider) => (
                 NippyJar::load(provider.data_path())
                     .map_err(|e| ProviderError::NippyJar(e.to_string()))?,
                 provider.data_path().into(),
+                (segment == StaticFileSegment::Transactions)
+                    .then(|| Self::load_tx_hash_filter(&provider))
+                    .transpose()?,
             ),
             Err(ProviderError::MissingStaticFileBlock(_, _)) => {
                 let path = static_file_provider.directory().join(segment.filename(&block_range));
-                (create_jar(segment, &path, block_range), path)
+                (
+                    create_jar(segment, &path, block_range),
+                    path,
+                    (segment == StaticFileSegment::Transactions).then(TxHashFilter::default),
+                )
             }
             Err(err) => return Err(err),
         };
 
         let result = match NippyJarWriter::new(jar) {
-            Ok(writer) => Ok((writer, path)),
+            Ok(writer) => Ok((writer, path, tx_hash_filter)),
             Err(NippyJarError::FrozenJar) => {
                 // This static file has been frozen, so we should
                 Err(ProviderError::FinalizedStaticFile(segment, block))
@@ -96,28 +131,44 @@ impl StaticFileProviderRW {
         Ok(result)
     }
 
+    /// Loads the transaction hash filter of an existing transactions static file.
+    ///
+    /// Static files written before filters existed have none, in which case it's built from all
+    /// their transactions.
+    fn load_tx_hash_filter(provider: &StaticFileJarProvider<'_>) -> ProviderResult<TxHashFilter> {
+        if let Some(filter) = TxHashFilter::load(provider.data_path())? {
+            return Ok(filter)
+        }
+
+        let mut filter = TxHashFilter::default();
+        if let Some(tx_range) = provider.user_header().tx_range() {
+            debug!(target: "provider::static_file", path = ?provider.data_path(), "Building transaction hash filter");
+
+            let mut cursor = provider.cursor()?;
+            for num in tx_range.start()..=tx_range.end() {
+                if let Some(tx) =
+                    cursor.get_one::<TransactionMask<TransactionSignedNoHash>>(num.into())?
+                {
+                    filter.insert(&tx.hash());
+                }
+            }
+        }
+        Ok(filter)
+    }
+
     /// Commits configuration changes to disk and updates the reader index with the new changes.
     pub fn commit(&mut self) -> ProviderResult<()> {
         let start = Instant::now();
 
+        // Persists the filter first, so that it covers every committed transaction
+        if let Some(filter) = &mut self.tx_hash_filter {
+            filter.persist(&self.data_path)?;
+        }
+
         // Commits offsets and new user_header to disk
         self.writer.commit().map_err(|e| ProviderError::NippyJar(e.to_string()))?;
 
-        if let Some(metrics) = &self.metrics {
-            metrics.record_segment_operation(
-                self.writer.user_header().segment(),
-                StaticFileProviderOperation::CommitWriter,
-                Some(start.elapsed()),
-            );
-        }
-
-        debug!(
-            target: "provider::static_file",
-            segment = ?self.writer.user_header().segment(),
-            path = ?self.data_path,
-            duration = ?start.elapsed(),
-            "Commit"
-        );
+        self.record_commit(start);
 
         self.update_index()?;
 
@@ -131,30 +182,69 @@ impl StaticFileProviderRW {
     pub fn commit_without_sync_all(&mut self) -> ProviderResult<()> {
         let start = Instant::now();
 
+        if let Some(filter) = &mut self.tx_hash_filter {
+            filter.persist(&self.data_path)?;
+        }
+
         // Commits offsets and new user_header to disk
         self.writer
             .commit_without_sync_all()
             .map_err(|e| ProviderError::NippyJar(e.to_string()))?;
 
+        self.record_commit(start);
+
+        self.update_index()?;
+
+        Ok(())
+    }
+
+    /// Records the metrics of a commit that started at `start`, and warns if it took longer than
+    /// the configured slow commit threshold.
+    ///
+    /// The files synced to disk since the previous commit are attributed to this one, which
+    /// includes the syncs of pruning rows and of healing the files when the writer was opened.
+    fn record_commit(&mut self, start: Instant) {
+        let duration = start.elapsed();
+        let fsyncs = self.writer.take_syncs();
+        let segment = self.writer.user_header().segment();
+        let threshold = self.reader().slow_commit_threshold();
+        let slow = duration > threshold;
+
         if let Some(metrics) = &self.metrics {
             metrics.record_segment_operation(
-                self.writer.user_header().segment(),
+                segment,
                 StaticFileProviderOperation::CommitWriter,
-                Some(start.elapsed()),
+                Some(duration),
             );
+            metrics.record_writer_commit(segment, self.uncommitted_bytes, fsyncs, slow);
         }
 
-        debug!(
-            target: "provider::static_file",
-            segment = ?self.writer.user_header().segment(),
-            path = ?self.data_path,
-            duration = ?start.elapsed(),
-            "Commit"
-        );
-
-        self.update_index()?;
+        if slow {
+            warn!(
+                target: "provider::static_file",
+                ?segment,
+                path = ?self.data_path,
+                ?duration,
+                ?threshold,
+                rows = self.uncommitted_rows,
+                bytes = self.uncommitted_bytes,
+                fsyncs,
+                file_size = ?reth_primitives::fs::metadata(&self.data_path).ok().map(|m| m.len()),
+                block_range = ?self.writer.user_header().block_range(),
+                "Slow static file commit, the disk may be too slow to keep up"
+            );
+        } else {
+            debug!(
+                target: "provider::static_file",
+                ?segment,
+                path = ?self.data_path,
+                ?duration,
+                "Commit"
+            );
+        }
 
-        Ok(())
+        self.uncommitted_rows = 0;
+        self.uncommitted_bytes = 0;
     }
 
     /// Updates the `self.reader` internal index.
@@ -196,17 +286,41 @@ impl StaticFileProviderRW {
         if let Some(last_block) = self.writer.user_header().block_end() {
             // We have finished the previous static file and must freeze it
             if last_block == self.writer.user_header().expected_block_end() {
+                // The static file is complete, so its contents won't change unless it's
+                // truncated. The checksum is computed while appending, and the content the file
+                // had when the writer was opened or truncated is hashed in the background, so
+                // this at most waits for that to finish instead of rehashing the file.
+                let checksum = self
+                    .writer
+                    .data_checksum()
+                    .map_err(|e| ProviderError::NippyJar(e.to_string()))?;
+                self.writer.user_header_mut().set_checksum(Some(checksum));
+
                 // Commits offsets and new user_header to disk
                 self.commit()?;
 
+                // The completed static file is indexed in the background, since reading all of
+                // its hashes would stall the commit. Until the index is written, lookups scan the
+                // static file.
+                if segment != StaticFileSegment::Receipts {
+                    let provider = self.reader();
+                    let data_path = self.data_path.clone();
+                    rayon::spawn(move || provider.write_hash_index(&data_path));
+                }
+
                 // Opens the new static file
-                let (writer, data_path) =
+                let (writer, data_path, tx_hash_filter) =
                     Self::open(segment, last_block + 1, self.reader.clone(), self.metrics.clone())?;
                 self.writer = writer;
                 self.data_path = data_path;
-
-                *self.writer.user_header_mut() =
-                    SegmentHeader::new(find_fixed_range(last_block + 1), None, None, segment);
+                self.tx_hash_filter = tx_hash_filter;
+
+                *self.writer.user_header_mut() = SegmentHeader::new(
+                    self.reader().find_fixed_range(last_block + 1),
+                    None,
+                    None,
+                    segment,
+                );
             }
         }
 
@@ -262,7 +376,14 @@ impl StaticFileProviderRW {
         mut num_rows: u64,
         last_block: Option<u64>,
     ) -> ProviderResult<()> {
+        let provider = self.reader();
+        let _truncation = provider.begin_truncation();
+
         while num_rows > 0 {
+            // The static file may have been completed before, and won't match its hash index
+            // anymore
+            HashIndex::delete(segment, &self.data_path)?;
+
             let len = match segment {
                 StaticFileSegment::Headers => {
                     self.writer.user_header().block_len().unwrap_or_default()
@@ -279,7 +400,7 @@ impl StaticFileProviderRW {
                 let block_start = self.writer.user_header().expected_block_start();
 
                 if block_start != 0 {
-                    let (writer, data_path) = Self::open(
+                    let (writer, data_path, tx_hash_filter) = Self::open(
                         segment,
                         self.writer.user_header().expected_block_start() - 1,
                         self.reader.clone(),
@@ -287,7 +408,9 @@ impl StaticFileProviderRW {
                     )?;
                     self.writer = writer;
                     self.data_path = data_path;
+                    self.tx_hash_filter = tx_hash_filter;
 
+                    TxHashFilter::delete(&previous_snap)?;
                     NippyJar::<SegmentHeader>::load(&previous_snap)
                         .map_err(|e| ProviderError::NippyJar(e.to_string()))?
                         .delete()
@@ -330,6 +453,7 @@ impl StaticFileProviderRW {
     fn append_column<T: Compact>(&mut self, column: T) -> ProviderResult<()> {
         self.buf.clear();
         column.to_compact(&mut self.buf);
+        self.uncommitted_bytes += self.buf.len() as u64;
 
         self.writer
             .append_column(Some(Ok(&self.buf)))
@@ -355,6 +479,7 @@ impl StaticFileProviderRW {
         }
 
         self.append_column(value)?;
+        self.uncommitted_rows += 1;
 
         Ok(self.writer.user_header().tx_end().expect("qed"))
     }
@@ -380,6 +505,7 @@ impl StaticFileProviderRW {
         self.append_column(header)?;
         self.append_column(CompactU256::from(terminal_difficulty))?;
         self.append_column(hash)?;
+        self.uncommitted_rows += 1;
 
         if let Some(metrics) = &self.metrics {
             metrics.record_segment_operation(
@@ -405,6 +531,9 @@ impl StaticFileProviderRW {
     ) -> ProviderResult<TxNumber> {
         let start = Instant::now();
 
+        if let Some(filter) = &mut self.tx_hash_filter {
+            filter.insert(&tx.hash());
+        }
         let result = self.append_with_tx_number(StaticFileSegment::Transactions, tx_num, tx)?;
 
         if let Some(metrics) = &self.metrics {
@@ -571,3 +700,77 @@ fn create_jar(
 
     jar
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::StaticFileWriter;
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder, Snapshotter};
+    use std::time::Duration;
+
+    /// Returns the value of the writer counter `name` of the headers segment, as recorded on the
+    /// current thread.
+    fn headers_writer_counter(name: &str) -> u64 {
+        let snapshot = Snapshotter::current_thread_snapshot().expect("metrics are recorded");
+        snapshot
+            .into_vec()
+            .into_iter()
+            .find_map(|(key, _, _, value)| {
+                let key = key.key();
+                (key.name() == format!("static_files.writer.{name}") &&
+                    key.labels().any(|label| label.value() == "headers"))
+                .then_some(value)
+            })
+            .map_or(0, |value| match value {
+                DebugValue::Counter(value) => value,
+                value => panic!("{name} is not a counter: {value:?}"),
+            })
+    }
+
+    /// Appends the header of the given block with a provider that has the given slow commit
+    /// threshold, and commits it.
+    fn commit_header(dir: &Path, number: BlockNumber, slow_commit_threshold: Duration) {
+        let provider = StaticFileProvider::new(dir)
+            .unwrap()
+            .with_metrics()
+            .with_slow_commit_threshold(slow_commit_threshold);
+        let mut writer = provider.latest_writer(StaticFileSegment::Headers).unwrap();
+        let header = Header { number, ..Default::default() };
+        let hash = header.hash_slow();
+        writer.append_header(header, U256::ZERO, hash).unwrap();
+        writer.commit().unwrap();
+    }
+
+    #[test]
+    fn commit_records_writer_metrics() {
+        // metrics are recorded per thread, so tests running in parallel don't interfere
+        let _ = DebuggingRecorder::per_thread().install();
+        let dir = tempfile::tempdir().unwrap();
+
+        // a new static file syncs its data and offsets files on commit
+        commit_header(dir.path(), 0, Duration::MAX);
+        let bytes_written = headers_writer_counter("bytes_written_total");
+        assert!(bytes_written > 0);
+        assert_eq!(headers_writer_counter("fsyncs_total"), 2);
+        assert_eq!(headers_writer_counter("slow_commits_total"), 0);
+
+        // reopening an existing static file heals and syncs it, which is counted with the commit
+        commit_header(dir.path(), 1, Duration::MAX);
+        assert!(headers_writer_counter("bytes_written_total") > bytes_written);
+        assert_eq!(headers_writer_counter("fsyncs_total"), 6);
+        assert_eq!(headers_writer_counter("slow_commits_total"), 0);
+    }
+
+    #[test]
+    fn slow_commit_is_counted() {
+        let _ = DebuggingRecorder::per_thread().install();
+        let dir = tempfile::tempdir().unwrap();
+
+        commit_header(dir.path(), 0, Duration::MAX);
+        assert_eq!(headers_writer_counter("slow_commits_total"), 0);
+
+        // every commit takes longer than a zero threshold, so it's reported as slow
+        commit_header(dir.path(), 1, Duration::ZERO);
+        assert_eq!(headers_writer_counter("slow_commits_total"), 1);
+    }
+}
@@ -1,8 +1,10 @@
 use crate::{
+    providers::StaticFileProvider,
     traits::{BlockSource, ReceiptProvider},
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    BundleStateDataProvider, ChainSpecProvider, ChangeSetReader, EvmEnvProvider, HeaderProvider,
-    ReceiptProviderIdExt, StateProvider, StateProviderBox, StateProviderFactory, StateRootProvider,
+    AccountHistoryReader, AccountReader, BlockHashReader, BlockIdReader, BlockNumReader,
+    BlockReader, BlockReaderIdExt, BundleStateDataProvider, ChainSpecProvider, ChangeSetReader,
+    EvmEnvProvider, HeaderProvider, ReadSnapshotFactory, ReceiptProviderIdExt, StateProvider,
+    StateProviderBox, StateProviderFactory, StateRootProvider, StaticFileProviderFactory,
     TransactionVariant, TransactionsProvider, WithdrawalsProvider,
 };
 use parking_lot::Mutex;
@@ -704,6 +706,31 @@ impl WithdrawalsProvider for MockEthProvider {
     }
 }
 
+impl AccountHistoryReader for MockEthProvider {
+    fn account_history(
+        &self,
+        _address: Address,
+        _range: RangeInclusive<BlockNumber>,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Option<Account>)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl StaticFileProviderFactory for MockEthProvider {
+    fn static_file_provider(&self) -> StaticFileProvider {
+        StaticFileProvider::default()
+    }
+}
+
+impl ReadSnapshotFactory for MockEthProvider {
+    type Snapshot = Self;
+
+    fn read_snapshot(&self) -> ProviderResult<Self::Snapshot> {
+        Ok(self.clone())
+    }
+}
+
 impl ChangeSetReader for MockEthProvider {
     fn account_block_changeset(
         &self,
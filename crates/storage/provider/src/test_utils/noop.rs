@@ -1,9 +1,11 @@
 use crate::{
+    providers::StaticFileProvider,
     traits::{BlockSource, ReceiptProvider},
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    ChainSpecProvider, ChangeSetReader, EvmEnvProvider, HeaderProvider, PruneCheckpointReader,
-    ReceiptProviderIdExt, StageCheckpointReader, StateProvider, StateProviderBox,
-    StateProviderFactory, StateRootProvider, TransactionVariant, TransactionsProvider,
+    AccountHistoryReader, AccountReader, BlockHashReader, BlockIdReader, BlockNumReader,
+    BlockReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader, EvmEnvProvider,
+    HeaderProvider, PruneCheckpointReader, ReadSnapshotFactory, ReceiptProviderIdExt,
+    StageCheckpointReader, StateProvider, StateProviderBox, StateProviderFactory,
+    StateRootProvider, StaticFileProviderFactory, TransactionVariant, TransactionsProvider,
     WithdrawalsProvider,
 };
 use reth_db::models::{AccountBeforeTx, StoredBlockBodyIndices};
@@ -277,6 +279,31 @@ impl AccountReader for NoopProvider {
     }
 }
 
+impl AccountHistoryReader for NoopProvider {
+    fn account_history(
+        &self,
+        _address: Address,
+        _range: RangeInclusive<BlockNumber>,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Option<Account>)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl StaticFileProviderFactory for NoopProvider {
+    fn static_file_provider(&self) -> StaticFileProvider {
+        StaticFileProvider::default()
+    }
+}
+
+impl ReadSnapshotFactory for NoopProvider {
+    type Snapshot = Self;
+
+    fn read_snapshot(&self) -> ProviderResult<Self::Snapshot> {
+        Ok(*self)
+    }
+}
+
 impl ChangeSetReader for NoopProvider {
     fn account_block_changeset(
         &self,
@@ -44,6 +44,21 @@ pub trait AccountExtReader: Send + Sync {
     ) -> ProviderResult<BTreeMap<Address, Vec<BlockNumber>>>;
 }
 
+/// Account history reader
+#[auto_impl(&, Arc, Box)]
+pub trait AccountHistoryReader: Send + Sync {
+    /// Returns the state of the account after every block in the given range in which it changed,
+    /// ordered by block number. At most `limit` entries are returned.
+    ///
+    /// The account is `None` if it didn't exist after the block.
+    fn account_history(
+        &self,
+        address: Address,
+        range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Option<Account>)>>;
+}
+
 /// AccountChange reader
 #[auto_impl(&, Arc, Box)]
 pub trait ChangeSetReader: Send + Sync {
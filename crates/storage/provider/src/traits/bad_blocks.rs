@@ -0,0 +1,21 @@
+use reth_interfaces::provider::ProviderResult;
+use reth_primitives::{SealedBlock, B256};
+use std::fmt::Debug;
+
+/// A block that was rejected by consensus or execution validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadBlock {
+    /// The rejected block.
+    pub block: SealedBlock,
+    /// The reason the block was rejected.
+    pub reason: String,
+    /// The hash of the transaction that caused the block to be rejected, if known.
+    pub offending_transaction: Option<B256>,
+}
+
+/// Client trait for fetching blocks that were rejected as invalid.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait BadBlockReader: Debug + Send + Sync {
+    /// Returns all recorded bad blocks, most recent first.
+    fn bad_blocks(&self) -> ProviderResult<Vec<BadBlock>>;
+}
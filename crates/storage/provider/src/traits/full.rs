@@ -1,8 +1,9 @@
 //! Helper provider traits to encapsulate all provider traits for simplicity.
 
 use crate::{
-    AccountReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader,
-    DatabaseProviderFactory, EvmEnvProvider, StateProviderFactory,
+    AccountHistoryReader, AccountReader, BlockReaderIdExt, CanonStateSubscriptions,
+    ChainSpecProvider, ChangeSetReader, DatabaseProviderFactory, EvmEnvProvider,
+    ReadSnapshotFactory, StateProviderFactory, StaticFileProviderFactory,
 };
 use reth_db::database::Database;
 
@@ -15,6 +16,9 @@ pub trait FullProvider<DB: Database>:
     + EvmEnvProvider
     + ChainSpecProvider
     + ChangeSetReader
+    + AccountHistoryReader
+    + StaticFileProviderFactory
+    + ReadSnapshotFactory
     + CanonStateSubscriptions
     + Clone
     + Unpin
@@ -30,6 +34,9 @@ impl<T, DB: Database> FullProvider<DB> for T where
         + EvmEnvProvider
         + ChainSpecProvider
         + ChangeSetReader
+        + AccountHistoryReader
+        + StaticFileProviderFactory
+        + ReadSnapshotFactory
         + CanonStateSubscriptions
         + Clone
         + Unpin
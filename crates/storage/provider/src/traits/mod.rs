@@ -1,7 +1,7 @@
 //! Collection of common provider traits.
 
 mod account;
-pub use account::{AccountExtReader, AccountReader, ChangeSetReader};
+pub use account::{AccountExtReader, AccountHistoryReader, AccountReader, ChangeSetReader};
 
 mod storage;
 pub use storage::StorageReader;
@@ -78,5 +78,14 @@ pub use database_provider::DatabaseProviderFactory;
 mod stats;
 pub use stats::StatsReader;
 
+mod bad_blocks;
+pub use bad_blocks::{BadBlock, BadBlockReader};
+
+mod static_file_provider;
+pub use static_file_provider::StaticFileProviderFactory;
+
+mod read_snapshot;
+pub use read_snapshot::ReadSnapshotFactory;
+
 mod full;
 pub use full::FullProvider;
@@ -0,0 +1,16 @@
+use crate::BlockReader;
+use auto_impl::auto_impl;
+use reth_interfaces::provider::ProviderResult;
+
+/// Opens read-only snapshots of the database for long-running scans.
+///
+/// Used together with a [`ReadSnapshotManager`](crate::providers::ReadSnapshotManager), which
+/// replaces the snapshot once its read transaction gets too old.
+#[auto_impl(&, Arc)]
+pub trait ReadSnapshotFactory: Send + Sync {
+    /// The provider of a single snapshot.
+    type Snapshot: BlockReader;
+
+    /// Opens a new read-only snapshot.
+    fn read_snapshot(&self) -> ProviderResult<Self::Snapshot>;
+}
@@ -0,0 +1,9 @@
+use crate::providers::StaticFileProvider;
+use auto_impl::auto_impl;
+
+/// Static file provider factory.
+#[auto_impl(&, Arc)]
+pub trait StaticFileProviderFactory: Send + Sync {
+    /// Returns the static file provider.
+    fn static_file_provider(&self) -> StaticFileProvider;
+}
@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use rolling_file::{RollingConditionBasic, RollingFileAppender};
 use tracing_appender::non_blocking::WorkerGuard;
-use tracing_subscriber::{filter::Directive, EnvFilter, Layer, Registry};
+use tracing_subscriber::{filter::Directive, reload, EnvFilter, Layer, Registry};
 
 use crate::formatter::LogFormat;
 
@@ -15,6 +15,9 @@ pub type FileWorkerGuard = tracing_appender::non_blocking::WorkerGuard;
 ///  A boxed tracing [Layer].
 pub(crate) type BoxedLayer<S> = Box<dyn Layer<S> + Send + Sync>;
 
+/// A handle to the [EnvFilter] of a layer that can be modified after the subscriber was installed.
+pub(crate) type FilterHandle = reload::Handle<EnvFilter, Registry>;
+
 const RETH_LOG_FILE_NAME: &str = "reth.log";
 
 /// Default [directives](Directive) for [EnvFilter] which disables high-frequency debug logs from
@@ -28,12 +31,19 @@ const DEFAULT_ENV_FILTER_DIRECTIVES: [&str; 3] =
 /// Each layer can be configured separately and then combined into a tracing subscriber.
 pub(crate) struct Layers {
     inner: Vec<BoxedLayer<Registry>>,
+    /// Handles to the filters of the stdout and file layers.
+    filter_handles: Vec<FilterHandle>,
 }
 
 impl Layers {
     /// Creates a new `Layers` instance.
     pub(crate) fn new() -> Self {
-        Self { inner: vec![] }
+        Self { inner: vec![], filter_handles: vec![] }
+    }
+
+    /// Takes the handles to the reloadable filters of the added layers.
+    pub(crate) fn take_filter_handles(&mut self) -> Vec<FilterHandle> {
+        std::mem::take(&mut self.filter_handles)
     }
 
     /// Consumes the `Layers` instance, returning the inner vector of layers.
@@ -75,7 +85,7 @@ impl Layers {
         filters: &str,
         color: Option<String>,
     ) -> eyre::Result<()> {
-        let filter = build_env_filter(Some(default_directive), filters)?;
+        let filter = self.reloadable(build_env_filter(Some(default_directive), filters)?);
         let layer = format.apply(filter, color, None);
         self.inner.push(layer.boxed());
         Ok(())
@@ -97,11 +107,18 @@ impl Layers {
         file_info: FileInfo,
     ) -> eyre::Result<FileWorkerGuard> {
         let (writer, guard) = file_info.create_log_writer();
-        let file_filter = build_env_filter(None, filter)?;
+        let file_filter = self.reloadable(build_env_filter(None, filter)?);
         let layer = format.apply(file_filter, None, Some(writer));
         self.inner.push(layer);
         Ok(guard)
     }
+
+    /// Wraps the filter, so its directives can be changed at runtime.
+    fn reloadable(&mut self, filter: EnvFilter) -> reload::Layer<EnvFilter, Registry> {
+        let (filter, handle) = reload::Layer::new(filter);
+        self.filter_handles.push(handle);
+        filter
+    }
 }
 
 /// Holds configuration information for file logging.
@@ -57,10 +57,14 @@ mod formatter;
 mod layers;
 mod test_tracer;
 
-use crate::layers::Layers;
+use crate::layers::{FilterHandle, Layers};
+use std::sync::OnceLock;
 use tracing::level_filters::LevelFilter;
 use tracing_appender::non_blocking::WorkerGuard;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use tracing_subscriber::{filter::Directive, layer::SubscriberExt, util::SubscriberInitExt};
+
+/// Handles to the filters of the layers installed by [RethTracer].
+static FILTER_HANDLES: OnceLock<Vec<FilterHandle>> = OnceLock::new();
 
 ///  Tracer for application logging.
 ///
@@ -210,13 +214,37 @@ impl Tracer for RethTracer {
             None
         };
 
+        let filter_handles = layers.take_filter_handles();
+
         // The error is returned if the global default subscriber is already set,
         // so it's safe to ignore it
-        let _ = tracing_subscriber::registry().with(layers.into_inner()).try_init();
+        if tracing_subscriber::registry().with(layers.into_inner()).try_init().is_ok() {
+            let _ = FILTER_HANDLES.set(filter_handles);
+        }
         Ok(file_guard)
     }
 }
 
+/// Adds a filter directive, e.g. `provider::static_file=debug`, to the stdout and file layers
+/// installed by [RethTracer].
+///
+/// The directive takes precedence over the existing directives for the same target, so this can
+/// be used to change the log level of a target at runtime.
+///
+/// Returns an error if the directive is invalid or if the logs weren't initialized by
+/// [RethTracer].
+pub fn add_log_directive(directive: &str) -> eyre::Result<()> {
+    let directive: Directive = directive.parse()?;
+    let handles = FILTER_HANDLES
+        .get()
+        .ok_or_else(|| eyre::eyre!("logging was not initialized with reloadable filters"))?;
+    for handle in handles {
+        handle
+            .modify(|filter| *filter = std::mem::take(filter).add_directive(directive.clone()))?;
+    }
+    Ok(())
+}
+
 ///  Initializes a tracing subscriber for tests.
 ///
 ///  The filter is configurable via `RUST_LOG`.